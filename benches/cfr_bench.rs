@@ -1,8 +1,11 @@
 //! Benchmarks for CFR solver.
 
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use rust_solver_poc::cfr::{CFRConfig, CFRSolver};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use rust_solver_poc::cfr::{CFRConfig, CFRSolver, StorageLayout};
 use rust_solver_poc::games::kuhn::KuhnPoker;
+use rust_solver_poc::games::preflop::{AbstractionConfig, Deck, SBvsBBConfig, SBvsBBFullGame};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 
 fn kuhn_iteration_benchmark(c: &mut Criterion) {
     let game = KuhnPoker::new();
@@ -23,10 +26,155 @@ fn kuhn_1000_iterations_benchmark(c: &mut Criterion) {
             let game = KuhnPoker::new();
             let config = CFRConfig::default().with_seed(42);
             let mut solver = CFRSolver::new(game, config);
-            solver.train(black_box(1000))
+            solver.train(black_box(1000)).iterations
         })
     });
 }
 
-criterion_group!(benches, kuhn_iteration_benchmark, kuhn_1000_iterations_benchmark);
+fn full_game_storage_layout_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("full_game_200_iterations");
+    group.sample_size(10);
+
+    // Cheap equity estimates so storage access, not bucketing, dominates
+    let game = SBvsBBFullGame::with_config(SBvsBBConfig {
+        abstraction: AbstractionConfig { equity_samples: 10, ..AbstractionConfig::fast() },
+        ..SBvsBBConfig::fast()
+    });
+
+    // Same seed for both layouts, so each run does identical work
+    for (name, layout) in [("map", StorageLayout::Map), ("arena", StorageLayout::Arena)] {
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                let config = CFRConfig::deterministic(42).with_storage_layout(layout);
+                let mut solver = CFRSolver::new(game.clone(), config);
+                solver.train(black_box(200));
+                black_box(solver.num_info_sets())
+            })
+        });
+    }
+    group.finish();
+}
+
+/// Regret-update modes compared by the throughput and exploitability groups.
+fn modes() -> [(&'static str, CFRConfig); 3] {
+    [
+        ("cfr_plus", CFRConfig::deterministic(42).with_cfr_plus(true).with_linear_cfr(false)),
+        ("vanilla", CFRConfig { seed: Some(42), num_threads: Some(1), ..CFRConfig::vanilla() }),
+        ("linear", CFRConfig::deterministic(42).with_cfr_plus(false).with_linear_cfr(true)),
+    ]
+}
+
+/// Small full-game configuration shared by the full-game groups.
+fn small_full_game() -> SBvsBBFullGame {
+    SBvsBBFullGame::with_config(SBvsBBConfig {
+        abstraction: AbstractionConfig {
+            flop_buckets: 8,
+            turn_buckets: 8,
+            river_buckets: 8,
+            equity_samples: 8,
+            ..AbstractionConfig::default()
+        },
+        ..SBvsBBConfig::fast()
+    })
+}
+
+fn kuhn_train_throughput_benchmark(c: &mut Criterion) {
+    const ITERATIONS: u64 = 1000;
+    let mut group = c.benchmark_group("kuhn_train");
+    group.throughput(Throughput::Elements(ITERATIONS));
+
+    for (name, config) in modes() {
+        group.bench_with_input(BenchmarkId::from_parameter(name), &config, |b, config| {
+            b.iter(|| {
+                let mut solver = CFRSolver::new(KuhnPoker::new(), config.clone());
+                solver.train(black_box(ITERATIONS)).iterations
+            })
+        });
+    }
+    group.finish();
+}
+
+fn full_game_train_throughput_benchmark(c: &mut Criterion) {
+    const ITERATIONS: u64 = 50;
+    let mut group = c.benchmark_group("full_game_train");
+    group.sample_size(10);
+    group.throughput(Throughput::Elements(ITERATIONS));
+
+    let game = small_full_game();
+    for (name, config) in modes() {
+        group.bench_with_input(BenchmarkId::from_parameter(name), &config, |b, config| {
+            b.iter(|| {
+                let mut solver = CFRSolver::new(game.clone(), config.clone());
+                solver.train(black_box(ITERATIONS)).iterations
+            })
+        });
+    }
+    group.finish();
+}
+
+fn exploitability_benchmark(c: &mut Criterion) {
+    const SAMPLES: usize = 1000;
+    let mut group = c.benchmark_group("exploitability");
+    group.sample_size(10);
+
+    // Measure against trained strategies so the walk sees realistic info-set counts
+    for (name, config) in modes() {
+        let mut kuhn = CFRSolver::new(KuhnPoker::new(), config.clone());
+        kuhn.train(1000);
+        group.bench_function(BenchmarkId::new("kuhn", name), |b| {
+            b.iter(|| kuhn.calculate_exploitability(black_box(SAMPLES)))
+        });
+
+        let mut full = CFRSolver::new(small_full_game(), config);
+        full.train(50);
+        group.bench_function(BenchmarkId::new("full_game", name), |b| {
+            b.iter(|| full.calculate_exploitability(black_box(SAMPLES)))
+        });
+    }
+    group.finish();
+}
+
+/// Dealing hole cards and a full board, one street at a time as `sample_chance` does.
+fn deal_runout_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("deal_runout");
+    // Cards dealt per street: both hands, flop, turn, river
+    const STREETS: [usize; 4] = [4, 3, 1, 1];
+
+    group.bench_function("shuffle_per_street", |b| {
+        let mut rng = StdRng::seed_from_u64(42);
+        b.iter(|| {
+            let mut deck = Deck::new();
+            for n in STREETS {
+                deck.shuffle(&mut rng);
+                black_box(deck.deal_n(n));
+            }
+            deck.remaining()
+        })
+    });
+
+    group.bench_function("swap_random_slot", |b| {
+        let mut rng = StdRng::seed_from_u64(42);
+        b.iter(|| {
+            let mut deck = Deck::new();
+            for n in STREETS {
+                for _ in 0..n {
+                    black_box(deck.deal_random(&mut rng));
+                }
+            }
+            deck.remaining()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    kuhn_iteration_benchmark,
+    kuhn_1000_iterations_benchmark,
+    full_game_storage_layout_benchmark,
+    kuhn_train_throughput_benchmark,
+    full_game_train_throughput_benchmark,
+    exploitability_benchmark,
+    deal_runout_benchmark
+);
 criterion_main!(benches);
@@ -158,6 +158,7 @@ fn create_default_config() -> PreflopConfig {
         equity_model: EquityModel {
             model_type: "ChipEV".to_string(),
             raked: false,
+            rake: None,
         },
         action_restrictions: ActionRestrictions {
             allowed_flats_per_raise: [0, 1, 1, 1, 0],
@@ -8,7 +8,7 @@ use std::fs::File;
 use std::io::Write;
 
 use rust_solver_poc::games::preflop_ranges::{
-    Position, Scenario, ActionType,
+    Position, Scenario,
     PreflopRangeConfig, PreflopRangeGame, solve_scenario,
     RangeOutput, ScenarioRange, generate_html,
 };
@@ -45,6 +45,9 @@ fn main() {
         Scenario::VsRFI { hero: Position::BB, villain: Position::BU },
         Scenario::VsRFI { hero: Position::BB, villain: Position::SB },
 
+        // BB option vs SB limp
+        Scenario::BBvsLimp { limper: Position::SB },
+
         // SB defense vs BU
         Scenario::VsRFI { hero: Position::SB, villain: Position::BU },
 
@@ -66,14 +69,7 @@ fn main() {
             i + 1, scenarios.len(), scenario.display_name());
 
         // Get actions for this scenario
-        let game = PreflopRangeGame::new(scenario.clone(), config.clone());
-        let actions: Vec<ActionType> = match scenario {
-            Scenario::RFI { .. } => vec![ActionType::Fold, ActionType::Raise],
-            Scenario::VsRFI { .. } => vec![ActionType::Fold, ActionType::Call, ActionType::Raise],
-            Scenario::Vs3Bet { .. } => vec![ActionType::Fold, ActionType::Call, ActionType::Raise],
-            Scenario::Vs4Bet { .. } => vec![ActionType::Fold, ActionType::Call, ActionType::AllIn],
-            _ => vec![ActionType::Fold, ActionType::Raise],
-        };
+        let actions = PreflopRangeGame::new(scenario.clone(), config.clone()).actions();
 
         // Solve
         let strategies = solve_scenario(scenario.clone(), &config, iterations);
@@ -0,0 +1,196 @@
+//! Contiguous arena layout for regrets and strategy sums.
+//!
+//! The map layout of [`RegretStorage`](super::RegretStorage) gives every info
+//! set its own heap `Vec<f64>`, so a traversal touching thousands of info sets
+//! chases pointers all over the heap. [`RegretArena`] instead keeps every
+//! regret in one `Vec<f64>` and every strategy sum in another, laid out
+//! identically: info set `i` owns `offsets[i]..offsets[i + 1]` in both. Slots
+//! are allocated on first touch of an interned info-set id.
+
+use rustc_hash::FxHashMap;
+use std::ops::Range;
+
+/// Regrets and strategy sums for all info sets in two flat buffers.
+#[derive(Debug, Clone)]
+pub(crate) struct RegretArena {
+    /// Interned info-set id -> slot index
+    slots: FxHashMap<u32, usize>,
+    /// Info-set id for each slot, in insertion order
+    ids: Vec<u32>,
+    /// Start of each slot's values; the last entry is the buffer length
+    offsets: Vec<usize>,
+    /// Whether each slot has received a regret update
+    has_regrets: Vec<bool>,
+    /// Whether each slot has received a strategy-sum update
+    has_sums: Vec<bool>,
+    /// Regret updates applied to each slot
+    visits: Vec<u64>,
+    /// Number of slots with regrets, i.e. the map layout's info-set count
+    num_with_regrets: usize,
+    regrets: Vec<f64>,
+    strategy_sums: Vec<f64>,
+}
+
+impl RegretArena {
+    /// Empty arena with room for `capacity` info sets.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut offsets = Vec::with_capacity(capacity + 1);
+        offsets.push(0);
+        Self {
+            slots: FxHashMap::with_capacity_and_hasher(capacity, Default::default()),
+            ids: Vec::with_capacity(capacity),
+            offsets,
+            has_regrets: Vec::with_capacity(capacity),
+            has_sums: Vec::with_capacity(capacity),
+            visits: Vec::with_capacity(capacity),
+            num_with_regrets: 0,
+            regrets: Vec::new(),
+            strategy_sums: Vec::new(),
+        }
+    }
+
+    /// Build an arena holding the given regrets and strategy sums.
+    pub fn from_entries<'a>(
+        regrets: impl IntoIterator<Item = (u32, &'a [f64])>,
+        strategy_sums: impl IntoIterator<Item = (u32, &'a [f64])>,
+    ) -> Self {
+        let mut arena = Self::with_capacity(0);
+        for (id, values) in regrets {
+            arena.regrets_mut(id, values.len()).copy_from_slice(values);
+        }
+        for (id, values) in strategy_sums {
+            arena.strategy_sums_mut(id, values.len()).copy_from_slice(values);
+        }
+        arena
+    }
+
+    fn range(&self, slot: usize) -> Range<usize> {
+        self.offsets[slot]..self.offsets[slot + 1]
+    }
+
+    fn slot_or_insert(&mut self, id: u32, num_actions: usize) -> usize {
+        if let Some(&slot) = self.slots.get(&id) {
+            debug_assert_eq!(
+                self.range(slot).len(),
+                num_actions,
+                "Action count mismatch for info set #{}",
+                id
+            );
+            return slot;
+        }
+
+        let slot = self.ids.len();
+        let end = self.regrets.len() + num_actions;
+        self.slots.insert(id, slot);
+        self.ids.push(id);
+        self.offsets.push(end);
+        self.has_regrets.push(false);
+        self.has_sums.push(false);
+        self.visits.push(0);
+        self.regrets.resize(end, 0.0);
+        self.strategy_sums.resize(end, 0.0);
+        slot
+    }
+
+    /// Regrets for `id`, if it has received a regret update.
+    pub fn regrets(&self, id: u32) -> Option<&[f64]> {
+        let slot = *self.slots.get(&id)?;
+        self.has_regrets[slot].then(|| &self.regrets[self.range(slot)])
+    }
+
+    /// Strategy sums for `id`, if it has received a strategy-sum update.
+    pub fn strategy_sums(&self, id: u32) -> Option<&[f64]> {
+        let slot = *self.slots.get(&id)?;
+        self.has_sums[slot].then(|| &self.strategy_sums[self.range(slot)])
+    }
+
+    /// Mutable regrets for `id`, allocating zeroed slots on first touch.
+    pub fn regrets_mut(&mut self, id: u32, num_actions: usize) -> &mut [f64] {
+        let slot = self.slot_or_insert(id, num_actions);
+        if !self.has_regrets[slot] {
+            self.has_regrets[slot] = true;
+            self.num_with_regrets += 1;
+        }
+        let range = self.range(slot);
+        &mut self.regrets[range]
+    }
+
+    /// Mutable strategy sums for `id`, allocating zeroed slots on first touch.
+    pub fn strategy_sums_mut(&mut self, id: u32, num_actions: usize) -> &mut [f64] {
+        let slot = self.slot_or_insert(id, num_actions);
+        self.has_sums[slot] = true;
+        let range = self.range(slot);
+        &mut self.strategy_sums[range]
+    }
+
+    /// Count one regret update for `id`, which must already have a slot.
+    pub fn record_visit(&mut self, id: u32) {
+        self.visits[self.slots[&id]] += 1;
+    }
+
+    /// Regret updates applied to `id`, if it has regrets.
+    pub fn visit_count(&self, id: u32) -> Option<u64> {
+        let slot = *self.slots.get(&id)?;
+        self.has_regrets[slot].then(|| self.visits[slot])
+    }
+
+    /// Overwrite the visit count of `id`; ignored if it has no slot.
+    pub fn set_visit_count(&mut self, id: u32, count: u64) {
+        if let Some(&slot) = self.slots.get(&id) {
+            self.visits[slot] = count;
+        }
+    }
+
+    /// Visit counts of info sets with regrets, in insertion order.
+    pub fn iter_visit_counts(&self) -> impl Iterator<Item = (u32, u64)> + '_ {
+        (0..self.ids.len())
+            .filter(|&slot| self.has_regrets[slot])
+            .map(|slot| (self.ids[slot], self.visits[slot]))
+    }
+
+    /// Info sets with regrets, in insertion order.
+    pub fn iter_regrets(&self) -> impl Iterator<Item = (u32, &[f64])> {
+        (0..self.ids.len())
+            .filter(|&slot| self.has_regrets[slot])
+            .map(|slot| (self.ids[slot], &self.regrets[self.range(slot)]))
+    }
+
+    /// Info sets with strategy sums, in insertion order.
+    pub fn iter_strategy_sums(&self) -> impl Iterator<Item = (u32, &[f64])> {
+        (0..self.ids.len())
+            .filter(|&slot| self.has_sums[slot])
+            .map(|slot| (self.ids[slot], &self.strategy_sums[self.range(slot)]))
+    }
+
+    /// Multiply every regret by `discount`.
+    pub fn discount_regrets(&mut self, discount: f64) {
+        self.regrets.iter_mut().for_each(|v| *v *= discount);
+    }
+
+    /// Multiply positive regrets by `positive` and negative ones by `negative`.
+    pub fn discount_regrets_signed(&mut self, positive: f64, negative: f64) {
+        self.regrets
+            .iter_mut()
+            .for_each(|v| *v *= if *v > 0.0 { positive } else { negative });
+    }
+
+    /// Multiply every strategy sum by `discount`.
+    pub fn discount_strategy_sums(&mut self, discount: f64) {
+        self.strategy_sums.iter_mut().for_each(|v| *v *= discount);
+    }
+
+    /// Number of info sets with regrets.
+    pub fn len(&self) -> usize {
+        self.num_with_regrets
+    }
+
+    /// Number of info sets the key table can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.slots.capacity()
+    }
+
+    /// Estimated bytes used by the values, counted like the map layout.
+    pub fn memory_usage(&self) -> usize {
+        (self.regrets.len() + self.strategy_sums.len()) * std::mem::size_of::<f64>()
+    }
+}
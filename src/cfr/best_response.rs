@@ -0,0 +1,179 @@
+//! Exact best-response exploitability for games small enough to traverse.
+//!
+//! [`CFRSolver::calculate_exploitability`] samples chance and lets the best
+//! responder see every card, which is cheap but noisy. This module instead
+//! enumerates chance through [`Game::chance_outcomes`] and restricts the best responder to one action per
+//! info set, giving the exact figure.
+
+use std::collections::HashMap;
+
+use crate::cfr::game::{Game, InfoState};
+use crate::cfr::solver::CFRSolver;
+use crate::cfr::storage::argmax_with_tiebreak;
+
+impl<G: Game> CFRSolver<G> {
+    /// Exact exploitability of the average strategy, in milli-big-blinds per game.
+    ///
+    /// Walks the entire tree, weighting each chance outcome by its
+    /// probability instead of sampling, so the result is deterministic and
+    /// reaches 0 exactly at an equilibrium. For every player it computes an
+    /// information-respecting best response to the others' average
+    /// strategies, and returns the sum over players of (best-response value -
+    /// average-strategy value) times 1000, reading one payoff unit as one big
+    /// blind. Only practical for small games such as Kuhn, Leduc and the
+    /// preflop range game.
+    ///
+    /// # Panics
+    /// If the game reaches a chance node whose outcomes it cannot list.
+    pub fn calculate_exploitability_exact(&self) -> f64 {
+        let root = self.game().initial_state();
+        let nash_conv: f64 = (0..self.game().num_players())
+            .map(|player| {
+                let mut best_response = BestResponse::new(self, player);
+                best_response.collect(&root, 1.0);
+                best_response.value(&root) - self.exact_strategy_value(&root, player)
+            })
+            .sum();
+        nash_conv * 1000.0
+    }
+
+    /// Expected payoff for `player` when everyone plays the average strategy.
+    fn exact_strategy_value(&self, state: &G::State, player: usize) -> f64 {
+        let game = self.game();
+        if game.is_terminal(state) {
+            return game.get_payoff(state, player);
+        }
+        if game.is_chance(state) {
+            return enumerate_chance(game, state)
+                .iter()
+                .map(|(outcome, p)| p * self.exact_strategy_value(outcome, player))
+                .sum();
+        }
+
+        let actions = game.available_actions(state);
+        let strategy = self.get_average_strategy(&game.info_state(state).key(), actions.len());
+        actions
+            .iter()
+            .zip(&strategy)
+            .map(|(action, p)| p * self.exact_strategy_value(&game.apply_action(state, action), player))
+            .sum()
+    }
+}
+
+/// Every outcome of the chance node `state` with its probability.
+///
+/// The single point where exact evaluation expands chance: both the
+/// best-response and the average-strategy walks go through it, so they weight
+/// each branch identically. Panics if [`Game::chance_outcomes`] returns no
+/// outcomes.
+pub(super) fn enumerate_chance<G: Game>(game: &G, state: &G::State) -> Vec<(G::State, f64)> {
+    let outcomes = game.chance_outcomes(state);
+    assert!(
+        !outcomes.is_empty(),
+        "exact exploitability needs Game::chance_outcomes, which is not implemented at {}",
+        game.state_description(state)
+    );
+    outcomes
+}
+
+/// One player's best response to everyone else's average strategy.
+struct BestResponse<'a, G: Game> {
+    solver: &'a CFRSolver<G>,
+    player: usize,
+    /// States in each of the player's info sets, with the probability that
+    /// chance and the other players reach them.
+    info_sets: HashMap<String, Vec<(G::State, f64)>>,
+    /// Action index chosen at each info set, filled in lazily.
+    choices: HashMap<String, usize>,
+}
+
+impl<'a, G: Game> BestResponse<'a, G> {
+    fn new(solver: &'a CFRSolver<G>, player: usize) -> Self {
+        Self {
+            solver,
+            player,
+            info_sets: HashMap::new(),
+            choices: HashMap::new(),
+        }
+    }
+
+    /// Record every state of the player's info sets below `state`.
+    fn collect(&mut self, state: &G::State, reach: f64) {
+        let game = self.solver.game();
+        if game.is_terminal(state) {
+            return;
+        }
+        if game.is_chance(state) {
+            for (outcome, p) in enumerate_chance(game, state) {
+                self.collect(&outcome, reach * p);
+            }
+            return;
+        }
+
+        let actions = game.available_actions(state);
+        let key = game.info_state(state).key();
+        if game.current_player(state) == Some(self.player) {
+            self.info_sets.entry(key).or_default().push((state.clone(), reach));
+            for action in &actions {
+                self.collect(&game.apply_action(state, action), reach);
+            }
+        } else {
+            let strategy = self.solver.get_average_strategy(&key, actions.len());
+            for (action, p) in actions.iter().zip(&strategy) {
+                self.collect(&game.apply_action(state, action), reach * p);
+            }
+        }
+    }
+
+    /// Value of `state` for the player when playing the best response.
+    fn value(&mut self, state: &G::State) -> f64 {
+        let game = self.solver.game();
+        if game.is_terminal(state) {
+            return game.get_payoff(state, self.player);
+        }
+        if game.is_chance(state) {
+            return enumerate_chance(game, state)
+                .iter()
+                .map(|(outcome, p)| p * self.value(outcome))
+                .sum();
+        }
+
+        let actions = game.available_actions(state);
+        let key = game.info_state(state).key();
+        if game.current_player(state) == Some(self.player) {
+            let choice = self.choose(&key, actions.len());
+            self.value(&game.apply_action(state, &actions[choice]))
+        } else {
+            let strategy = self.solver.get_average_strategy(&key, actions.len());
+            actions
+                .iter()
+                .zip(&strategy)
+                .map(|(action, p)| p * self.value(&game.apply_action(state, action)))
+                .sum()
+        }
+    }
+
+    /// Best action index at `key`, maximising reach-weighted value over its states.
+    ///
+    /// With perfect recall the player's later info sets lie strictly below
+    /// this one, so the recursion through [`value`](Self::value) terminates.
+    fn choose(&mut self, key: &str, num_actions: usize) -> usize {
+        if let Some(&choice) = self.choices.get(key) {
+            return choice;
+        }
+
+        let game = self.solver.game();
+        let states = self.info_sets.get(key).cloned().unwrap_or_default();
+        let mut action_values = vec![0.0; num_actions];
+        for (state, reach) in &states {
+            let actions = game.available_actions(state);
+            for (value, action) in action_values.iter_mut().zip(&actions) {
+                *value += reach * self.value(&game.apply_action(state, action));
+            }
+        }
+
+        let choice = argmax_with_tiebreak(&action_values).unwrap_or(0);
+        self.choices.insert(key.to_string(), choice);
+        choice
+    }
+}
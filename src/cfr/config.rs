@@ -3,7 +3,10 @@
 //! This module provides configuration structs that control the behavior
 //! of the CFR algorithm, including variants like CFR+ and Linear CFR.
 
+use super::storage::StorageLayout;
+use super::weighting::AverageStrategyWeighter;
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 
 /// Configuration for the CFR solver.
 ///
@@ -33,11 +36,97 @@ pub struct CFRConfig {
     /// the average strategy. This often improves convergence speed.
     pub use_linear_cfr: bool,
 
+    /// Window for normalized linear averaging (requires `use_linear_cfr`).
+    ///
+    /// Plain Linear CFR weights iteration `t` by `t`, so in very long runs
+    /// the most recent (noisiest) iterations dominate the average. With a
+    /// window `W`, the weight ramps linearly up to `W` and is then held flat,
+    /// and is expressed relative to `W` so accumulated sums stay bounded.
+    /// Each iteration's share of the total weight then falls off as `1/t`
+    /// instead of staying near `2/t`.
+    ///
+    /// Set to `None` for plain Linear CFR.
+    #[serde(default)]
+    pub linear_weight_window: Option<u64>,
+
+    /// Custom average-strategy weighting.
+    ///
+    /// When set, this overrides `use_linear_cfr` and `linear_weight_window`
+    /// for strategy-sum updates. Not serialized; reattach it after loading
+    /// a config from JSON.
+    #[serde(skip)]
+    pub strategy_weighter: Option<Arc<dyn AverageStrategyWeighter>>,
+
+    /// Number of initial iterations excluded from the average strategy.
+    ///
+    /// Regrets are updated from the first iteration, but strategy sums only
+    /// start accumulating once `iteration > average_delay`, so the near-
+    /// uniform early strategies never enter the average. 0 disables the delay.
+    #[serde(default)]
+    pub average_delay: u64,
+
+    /// Smoothing factor for the exponential moving average of strategies.
+    ///
+    /// When set, every strategy-sum update also moves a per-info-set EMA of
+    /// the current strategy this fraction of the way towards it (see
+    /// [`RegretStorage::get_ema_strategy`]). The EMA reacts faster than the
+    /// average strategy and is less noisy than the current one. Set to `None`
+    /// to skip the extra bookkeeping.
+    ///
+    /// [`RegretStorage::get_ema_strategy`]: crate::cfr::RegretStorage::get_ema_strategy
+    #[serde(default)]
+    pub strategy_ema: Option<f64>,
+
+    /// Cap on the absolute cumulative regret of any single action.
+    ///
+    /// When set, every regret update clamps each action's cumulative regret
+    /// to `[-cap, cap]`, after the CFR+ floor. This keeps regrets from
+    /// running away in games with large or adversarial payoffs, at the cost
+    /// of slower recovery for actions that were once far ahead or behind.
+    /// Set to `None` for unbounded regrets.
+    #[serde(default)]
+    pub regret_cap: Option<f64>,
+
+    /// Factor applied to imported regrets by [`CFRSolver::warm_start`].
+    ///
+    /// Strategy sums are always imported as-is, so the average strategy
+    /// picks up where the prior solve left off. Regrets encode how the prior
+    /// game's actions compared; 1.0 keeps them, smaller values let the new
+    /// solve overrule them sooner and 0.0 restarts regret matching from a
+    /// uniform strategy.
+    ///
+    /// [`CFRSolver::warm_start`]: crate::cfr::CFRSolver::warm_start
+    #[serde(default = "default_warm_start_regret_scale")]
+    pub warm_start_regret_scale: f64,
+
+    /// Track a running average of each action's counterfactual value.
+    ///
+    /// When set, every traverser decision also records the sampled value of
+    /// each action (see [`RegretStorage::get_action_values`]). Off by default
+    /// since it adds a map update per visited node.
+    ///
+    /// [`RegretStorage::get_action_values`]: crate::cfr::RegretStorage::get_action_values
+    #[serde(default)]
+    pub track_action_values: bool,
+
+    /// Check that every state sharing an info-set key has the same player and actions.
+    ///
+    /// Catches abstractions that merge genuinely different decision points
+    /// (e.g. a key that leaves out the street): training panics with both
+    /// states' descriptions on the first mismatch. Off by default since it
+    /// adds a map lookup and an action-list comparison per visited node.
+    #[serde(default)]
+    pub check_info_set_collisions: bool,
+
     /// Exploration probability for Monte Carlo sampling.
     ///
     /// When sampling opponent actions in MCCFR, this is the probability
     /// of choosing a random action instead of sampling from the current
     /// strategy. Higher values explore more but may slow convergence.
+    /// With [`SamplingScheme::Outcome`] it instead mixes uniform noise into
+    /// the traverser's own sampling, so actions the current strategy gives
+    /// zero probability still get explored; [`CFRConfig::validate`] rejects
+    /// 0 there.
     ///
     /// Recommended range: 0.3 - 0.6
     pub exploration: f64,
@@ -47,6 +136,7 @@ pub struct CFRConfig {
     /// If `use_cfr_plus` is false, regrets are floored at this value
     /// to prevent extreme negative regrets. Set to `f64::NEG_INFINITY`
     /// for true vanilla CFR.
+    #[serde(deserialize_with = "deserialize_regret_floor")]
     pub regret_floor: f64,
 
     /// Discount factor for regrets (for Discounted CFR).
@@ -64,17 +154,80 @@ pub struct CFRConfig {
     /// Set to `None` to disable discounting.
     pub strategy_discount: Option<f64>,
 
+    /// Discounted CFR schedule (Brown & Sandholm).
+    ///
+    /// Unlike the flat `regret_discount` and `strategy_discount`, the
+    /// discounts depend on the iteration and on the sign of each regret; see
+    /// [`DcfrParams`]. Set through [`CFRConfig::with_dcfr`]. Sequential
+    /// training only; parallel training rejects it (see
+    /// [`CFRConfig::validate_parallel`]). Set to `None` to disable.
+    #[serde(default)]
+    pub dcfr: Option<DcfrParams>,
+
+    /// Regret-based pruning of traverser actions.
+    ///
+    /// See [`RegretPruning`]. Set through [`CFRConfig::with_pruning`]. Only
+    /// affects sequential external sampling, and has no effect with CFR+,
+    /// whose regrets never go negative. Parallel training rejects it. Set to
+    /// `None` to disable.
+    #[serde(default)]
+    pub pruning: Option<RegretPruning>,
+
+    /// How regret updates are scheduled within an iteration.
+    ///
+    /// See [`UpdateMode`]. Parallel iterations always update as they go, so
+    /// parallel training rejects [`UpdateMode::Simultaneous`].
+    #[serde(default)]
+    pub update_mode: UpdateMode,
+
+    /// Which part of the tree each traversal samples.
+    ///
+    /// See [`SamplingScheme`]. Parallel iterations always use external
+    /// sampling, so parallel training rejects [`SamplingScheme::Outcome`].
+    #[serde(default)]
+    pub sampling: SamplingScheme,
+
+    /// Convergence indicator monitored by `train_until_converged`.
+    ///
+    /// See [`ConvergenceMetric`].
+    #[serde(default)]
+    pub convergence_metric: ConvergenceMetric,
+
+    /// Memory layout of the solver's regret storage.
+    ///
+    /// See [`StorageLayout`]. The arena layout trades the per-key map views
+    /// on [`RegretStorage`](crate::cfr::RegretStorage) for cache locality.
+    #[serde(default)]
+    pub storage_layout: StorageLayout,
+
     /// Number of threads to use for parallel MCCFR.
     ///
     /// Set to 0 or 1 for single-threaded execution.
     /// Set to `None` to use all available cores.
     pub num_threads: Option<usize>,
 
+    /// Default checkpoint interval for [`CFRSolver::train_with_checkpoints`].
+    ///
+    /// Used when `train_with_checkpoints` is called with an interval of 0.
+    /// Set to `None` to disable checkpointing by default.
+    ///
+    /// [`CFRSolver::train_with_checkpoints`]: crate::cfr::CFRSolver::train_with_checkpoints
+    #[serde(default)]
+    pub checkpoint_every: Option<u64>,
+
     /// Random seed for reproducibility.
     ///
     /// If set, the solver will use this seed for random number generation,
     /// making results reproducible. If `None`, a random seed is used.
     pub seed: Option<u64>,
+
+    /// Never fall back to entropy-seeded RNGs.
+    ///
+    /// When set (see [`CFRConfig::deterministic`]), solver clones continue the
+    /// original's RNG stream and each parallel iteration draws from an RNG
+    /// derived from `seed` and its iteration number.
+    #[serde(default)]
+    pub deterministic: bool,
 }
 
 impl Default for CFRConfig {
@@ -82,12 +235,28 @@ impl Default for CFRConfig {
         Self {
             use_cfr_plus: true,
             use_linear_cfr: true,
+            linear_weight_window: None,
+            strategy_weighter: None,
+            average_delay: 0,
+            strategy_ema: None,
+            regret_cap: None,
+            warm_start_regret_scale: 1.0,
+            track_action_values: false,
+            check_info_set_collisions: false,
             exploration: 0.0,  // Standard external sampling (no exploration)
             regret_floor: f64::NEG_INFINITY,
             regret_discount: None,
             strategy_discount: None,
+            dcfr: None,
+            pruning: None,
+            update_mode: UpdateMode::Alternating,
+            sampling: SamplingScheme::External,
+            convergence_metric: ConvergenceMetric::StrategyChange,
+            storage_layout: StorageLayout::Map,
             num_threads: None,
+            checkpoint_every: None,
             seed: None,
+            deterministic: false,
         }
     }
 }
@@ -144,6 +313,25 @@ impl CFRConfig {
         }
     }
 
+    /// Create a default configuration where the same seed gives the same strategies.
+    ///
+    /// Seeds the solver, pins training to a single thread and disables every
+    /// entropy-seeded fallback. Parallel training via
+    /// [`CFRSolver::run_parallel_iterations`] still applies updates in
+    /// scheduling order, so only sequential training is bit-identical. Every
+    /// bundled game is held to this (see the reproducibility section of
+    /// [`crate::games`]).
+    ///
+    /// [`CFRSolver::run_parallel_iterations`]: crate::cfr::CFRSolver::run_parallel_iterations
+    pub fn deterministic(seed: u64) -> Self {
+        Self {
+            num_threads: Some(1),
+            seed: Some(seed),
+            deterministic: true,
+            ..Default::default()
+        }
+    }
+
     /// Builder method: set whether to use CFR+.
     pub fn with_cfr_plus(mut self, enable: bool) -> Self {
         self.use_cfr_plus = enable;
@@ -156,30 +344,165 @@ impl CFRConfig {
         self
     }
 
+    /// Builder method: set the normalized linear averaging window.
+    ///
+    /// See [`CFRConfig::linear_weight_window`]. A window of 0 is treated as 1.
+    pub fn with_linear_weight_window(mut self, window: u64) -> Self {
+        self.linear_weight_window = Some(window.max(1));
+        self
+    }
+
+    /// Builder method: set a custom average-strategy weighter.
+    pub fn with_strategy_weighter<W: AverageStrategyWeighter + 'static>(mut self, weighter: W) -> Self {
+        self.strategy_weighter = Some(Arc::new(weighter));
+        self
+    }
+
+    /// Builder method: exclude the first `iterations` from the average strategy.
+    pub fn with_average_delay(mut self, iterations: u64) -> Self {
+        self.average_delay = iterations;
+        self
+    }
+
+    /// Builder method: enable strategy EMA tracking with the given smoothing factor.
+    pub fn with_strategy_ema(mut self, alpha: f64) -> Self {
+        self.strategy_ema = Some(alpha);
+        self
+    }
+
+    /// Builder method: clamp each action's cumulative regret to `[-cap, cap]`.
+    pub fn with_regret_cap(mut self, cap: f64) -> Self {
+        self.regret_cap = Some(cap);
+        self
+    }
+
+    /// Builder method: scale imported regrets by `scale` when warm-starting.
+    pub fn with_warm_start_regret_scale(mut self, scale: f64) -> Self {
+        self.warm_start_regret_scale = scale;
+        self
+    }
+
+    /// Builder method: set whether to track per-action counterfactual values.
+    pub fn with_action_values(mut self, enable: bool) -> Self {
+        self.track_action_values = enable;
+        self
+    }
+
+    /// Builder method: set whether to check info-set keys for collisions.
+    pub fn with_collision_check(mut self, enable: bool) -> Self {
+        self.check_info_set_collisions = enable;
+        self
+    }
+
     /// Builder method: set exploration probability.
     pub fn with_exploration(mut self, exploration: f64) -> Self {
         self.exploration = exploration.clamp(0.0, 1.0);
         self
     }
 
+    /// Builder method: use Discounted CFR with the given parameters.
+    ///
+    /// DCFR replaces both the CFR+ floor (negative regrets are discounted
+    /// instead) and linear averaging (strategy sums are discounted instead),
+    /// so this turns both off. [`DcfrParams::default`] holds the usual
+    /// α = 1.5, β = 0, γ = 2.
+    pub fn with_dcfr(mut self, alpha: f64, beta: f64, gamma: f64) -> Self {
+        self.dcfr = Some(DcfrParams { alpha, beta, gamma });
+        self.use_cfr_plus = false;
+        self.use_linear_cfr = false;
+        self
+    }
+
+    /// Builder method: prune actions whose cumulative regret is below `threshold`.
+    ///
+    /// Pruned subtrees are skipped with probability
+    /// [`RegretPruning::DEFAULT_PROBABILITY`]. Pair with
+    /// `with_cfr_plus(false)` or DCFR, since CFR+ floors regrets at zero.
+    pub fn with_pruning(mut self, threshold: f64) -> Self {
+        self.pruning = Some(RegretPruning {
+            threshold,
+            probability: RegretPruning::DEFAULT_PROBABILITY,
+        });
+        self
+    }
+
+    /// Builder method: set the regret update scheduling.
+    pub fn with_update_mode(mut self, mode: UpdateMode) -> Self {
+        self.update_mode = mode;
+        self
+    }
+
+    /// Builder method: set the MCCFR sampling scheme.
+    pub fn with_sampling(mut self, sampling: SamplingScheme) -> Self {
+        self.sampling = sampling;
+        self
+    }
+
+    /// Builder method: set the convergence indicator used by `train_until_converged`.
+    pub fn with_convergence_metric(mut self, metric: ConvergenceMetric) -> Self {
+        self.convergence_metric = metric;
+        self
+    }
+
+    /// Builder method: set the regret storage layout.
+    pub fn with_storage_layout(mut self, layout: StorageLayout) -> Self {
+        self.storage_layout = layout;
+        self
+    }
+
     /// Builder method: set number of threads.
     pub fn with_threads(mut self, threads: usize) -> Self {
         self.num_threads = Some(threads);
         self
     }
 
+    /// Builder method: set the default checkpoint interval.
+    pub fn with_checkpoint_every(mut self, interval: u64) -> Self {
+        self.checkpoint_every = Some(interval);
+        self
+    }
+
     /// Builder method: set random seed.
     pub fn with_seed(mut self, seed: u64) -> Self {
         self.seed = Some(seed);
         self
     }
 
+    /// Whether strategy sums are accumulated at `iteration` (see `average_delay`).
+    pub fn accumulates_average(&self, iteration: u64) -> bool {
+        iteration > self.average_delay
+    }
+
+    /// Weight applied to the strategy-sum update at `iteration` for a player
+    /// who reached the info set with probability `reach`.
+    pub fn strategy_weight(&self, iteration: u64, reach: f64) -> f64 {
+        if let Some(weighter) = &self.strategy_weighter {
+            return weighter.weight(iteration, reach);
+        }
+
+        if !self.use_linear_cfr {
+            return reach;
+        }
+
+        match self.linear_weight_window {
+            Some(window) => {
+                let window = window.max(1);
+                reach * iteration.min(window) as f64 / window as f64
+            }
+            None => reach * iteration as f64,
+        }
+    }
+
     /// Validate the configuration and return any errors.
     pub fn validate(&self) -> Result<(), ConfigError> {
         if self.exploration < 0.0 || self.exploration > 1.0 {
             return Err(ConfigError::InvalidExploration(self.exploration));
         }
 
+        if self.sampling == SamplingScheme::Outcome && self.exploration <= 0.0 {
+            return Err(ConfigError::OutcomeSamplingWithoutExploration(self.exploration));
+        }
+
         if let Some(discount) = self.regret_discount {
             if discount < 0.0 || discount > 1.0 {
                 return Err(ConfigError::InvalidDiscount("regret", discount));
@@ -192,10 +515,172 @@ impl CFRConfig {
             }
         }
 
+        if let Some(alpha) = self.strategy_ema {
+            if alpha <= 0.0 || alpha > 1.0 {
+                return Err(ConfigError::InvalidEma(alpha));
+            }
+        }
+
+        if let Some(cap) = self.regret_cap {
+            if cap <= 0.0 {
+                return Err(ConfigError::InvalidRegretCap(cap));
+            }
+        }
+
+        if !(self.warm_start_regret_scale >= 0.0 && self.warm_start_regret_scale.is_finite()) {
+            return Err(ConfigError::InvalidWarmStartScale(self.warm_start_regret_scale));
+        }
+
+        if let Some(pruning) = self.pruning {
+            if !(0.0..=1.0).contains(&pruning.probability) {
+                return Err(ConfigError::InvalidPruneProbability(pruning.probability));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validate the configuration for parallel training.
+    ///
+    /// On top of [`validate`](Self::validate), rejects the options only the
+    /// sequential iteration honours: DCFR, regret pruning, simultaneous
+    /// updates and outcome sampling. Parallel iterations run concurrently, so
+    /// there is no per-iteration point to discount or buffer updates at.
+    pub fn validate_parallel(&self) -> Result<(), ConfigError> {
+        self.validate()?;
+
+        if self.dcfr.is_some() {
+            return Err(ConfigError::SequentialOnly("DCFR"));
+        }
+        if self.pruning.is_some() {
+            return Err(ConfigError::SequentialOnly("regret pruning"));
+        }
+        if self.update_mode == UpdateMode::Simultaneous {
+            return Err(ConfigError::SequentialOnly("simultaneous updates"));
+        }
+        if self.sampling == SamplingScheme::Outcome {
+            return Err(ConfigError::SequentialOnly("outcome sampling"));
+        }
+
         Ok(())
     }
 }
 
+fn default_warm_start_regret_scale() -> f64 {
+    1.0
+}
+
+/// JSON has no infinities, so serde_json writes the default
+/// `f64::NEG_INFINITY` floor as `null`; read it back as "no floor".
+fn deserialize_regret_floor<'de, D>(deserializer: D) -> Result<f64, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Ok(Option::<f64>::deserialize(deserializer)?.unwrap_or(f64::NEG_INFINITY))
+}
+
+/// Scheduling of regret updates across players within one iteration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum UpdateMode {
+    /// Traverse for each player in turn, applying regrets immediately, so
+    /// later players respond to the strategies earlier players just updated.
+    #[default]
+    Alternating,
+    /// Traverse for every player against the strategy fixed at the start of
+    /// the iteration, buffering regret deltas and applying them all at the end.
+    Simultaneous,
+}
+
+/// Monte Carlo sampling scheme used by each MCCFR traversal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum SamplingScheme {
+    /// Sample chance and opponent actions but explore every traverser
+    /// action, so each traversal updates whole info sets.
+    #[default]
+    External,
+    /// Sample a single action at every node, walking one path per traversal
+    /// and importance-weighting regrets by the inverse of its sampling
+    /// probability. Much cheaper per iteration but noisier, so it needs more
+    /// iterations to converge. Action values are not tracked in this mode.
+    Outcome,
+}
+
+/// Parameters of Discounted CFR.
+///
+/// After iteration `t`, accumulated positive regrets are multiplied by
+/// `t^α / (t^α + 1)`, negative regrets by `t^β / (t^β + 1)` and strategy sums
+/// by `(t / (t + 1))^γ`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DcfrParams {
+    /// Exponent for positive regrets.
+    pub alpha: f64,
+    /// Exponent for negative regrets.
+    pub beta: f64,
+    /// Exponent for strategy sums.
+    pub gamma: f64,
+}
+
+impl Default for DcfrParams {
+    fn default() -> Self {
+        Self { alpha: 1.5, beta: 0.0, gamma: 2.0 }
+    }
+}
+
+impl DcfrParams {
+    /// Factors for positive and negative regrets after iteration `t`.
+    pub fn regret_factors(&self, t: u64) -> (f64, f64) {
+        let t = t as f64;
+        let factor = |exponent: f64| {
+            let weight = t.powf(exponent);
+            weight / (weight + 1.0)
+        };
+        (factor(self.alpha), factor(self.beta))
+    }
+
+    /// Factor for strategy sums after iteration `t`.
+    pub fn strategy_factor(&self, t: u64) -> f64 {
+        let t = t as f64;
+        (t / (t + 1.0)).powf(self.gamma)
+    }
+}
+
+/// Regret-based pruning.
+///
+/// At a traverser decision, an action the current strategy never plays and
+/// whose cumulative regret is below `threshold` is skipped with probability
+/// `probability`: its subtree is not traversed and its regret is left
+/// unchanged. Walking it the rest of the time lets its regret recover if the
+/// action becomes worth playing again.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RegretPruning {
+    /// Cumulative regret below which an action may be pruned.
+    pub threshold: f64,
+    /// Probability of skipping an action that qualifies for pruning.
+    pub probability: f64,
+}
+
+impl RegretPruning {
+    /// Skip probability used by [`CFRConfig::with_pruning`].
+    pub const DEFAULT_PROBABILITY: f64 = 0.95;
+}
+
+/// Convergence indicator (CI) that `train_until_converged` compares to its target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ConvergenceMetric {
+    /// How much the average strategy moved since the previous check
+    /// ([`RegretStorage::calculate_ci`]). Measures stability.
+    ///
+    /// [`RegretStorage::calculate_ci`]: crate::cfr::RegretStorage::calculate_ci
+    #[default]
+    StrategyChange,
+    /// Average positive regret per info set and iteration
+    /// ([`RegretStorage::calculate_exploitability_ci`]), which bounds how
+    /// exploitable the average strategy is. Measures quality.
+    ///
+    /// [`RegretStorage::calculate_exploitability_ci`]: crate::cfr::RegretStorage::calculate_exploitability_ci
+    Exploitability,
+}
+
 /// Errors that can occur when validating CFR configuration.
 #[derive(Debug, Clone)]
 pub enum ConfigError {
@@ -203,6 +688,18 @@ pub enum ConfigError {
     InvalidExploration(f64),
     /// Discount factor is out of range [0, 1].
     InvalidDiscount(&'static str, f64),
+    /// Strategy EMA smoothing factor is out of range (0, 1].
+    InvalidEma(f64),
+    /// Regret cap is not positive.
+    InvalidRegretCap(f64),
+    /// Warm-start regret scale is negative or not finite.
+    InvalidWarmStartScale(f64),
+    /// Pruning probability is out of range [0, 1].
+    InvalidPruneProbability(f64),
+    /// Outcome sampling with no exploration never samples zero-probability actions.
+    OutcomeSamplingWithoutExploration(f64),
+    /// An option parallel training does not support was set.
+    SequentialOnly(&'static str),
 }
 
 impl std::fmt::Display for ConfigError {
@@ -214,6 +711,24 @@ impl std::fmt::Display for ConfigError {
             ConfigError::InvalidDiscount(name, val) => {
                 write!(f, "{} discount {} is out of range [0, 1]", name, val)
             }
+            ConfigError::InvalidEma(val) => {
+                write!(f, "Strategy EMA factor {} is out of range (0, 1]", val)
+            }
+            ConfigError::InvalidRegretCap(val) => {
+                write!(f, "Regret cap {} must be positive", val)
+            }
+            ConfigError::InvalidWarmStartScale(val) => {
+                write!(f, "Warm-start regret scale {} must be non-negative", val)
+            }
+            ConfigError::InvalidPruneProbability(val) => {
+                write!(f, "Pruning probability {} is out of range [0, 1]", val)
+            }
+            ConfigError::OutcomeSamplingWithoutExploration(val) => {
+                write!(f, "Outcome sampling needs exploration above 0, got {}", val)
+            }
+            ConfigError::SequentialOnly(option) => {
+                write!(f, "{} is only supported by sequential training", option)
+            }
         }
     }
 }
@@ -240,6 +755,18 @@ pub struct CFRStats {
 
     /// History of exploitability measurements.
     pub exploitability_history: Vec<ExploitabilityPoint>,
+
+    /// Game-tree nodes visited by sequential traversal.
+    #[serde(default)]
+    pub nodes_visited: u64,
+
+    /// Game-tree nodes visited by exploitability calculations.
+    #[serde(default)]
+    pub exploitability_nodes_visited: u64,
+
+    /// Largest storage memory estimate seen at a stats update (in bytes).
+    #[serde(default)]
+    pub peak_memory_bytes: usize,
 }
 
 /// A single exploitability measurement at a specific iteration.
@@ -264,6 +791,29 @@ impl CFRStats {
         }
     }
 
+    /// Serialize the stats to pretty-printed JSON.
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Human-readable multi-line report of a finished solve.
+    pub fn summary(&self) -> String {
+        let mut out = String::new();
+        out.push_str(&format!("Iterations:     {}\n", self.iterations));
+        out.push_str(&format!("Info sets:      {}\n", self.info_sets));
+        out.push_str(&format!("Elapsed:        {:.2}s\n", self.elapsed_seconds));
+        out.push_str(&format!("Rate:           {:.1} it/s\n", self.iterations_per_second));
+        out.push_str(&format!("Nodes visited:  {}\n", self.nodes_visited));
+        out.push_str(&format!(
+            "Peak memory:    {:.2} MB\n",
+            self.peak_memory_bytes as f64 / (1024.0 * 1024.0)
+        ));
+        if let Some(exploitability) = self.exploitability {
+            out.push_str(&format!("Exploitability: {:.6}\n", exploitability));
+        }
+        out
+    }
+
     /// Record an exploitability measurement.
     pub fn record_exploitability(&mut self, iteration: u64, exploitability: f64) {
         self.exploitability = Some(exploitability);
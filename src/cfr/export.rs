@@ -0,0 +1,160 @@
+//! Writing solved strategies in the formats downstream tools read.
+//!
+//! [`CFRSolver::export_to_writer`] dispatches on a [`StrategyFormat`]:
+//!
+//! - [`StrategyFormat::Jsonl`]: one `{"key", "actions", "strategy"}` object
+//!   per info set, sorted by key. Read back with [`read_jsonl`].
+//! - [`StrategyFormat::Checkpoint`]: the full [`SolverState`] from
+//!   [`CFRSolver::export_state`] as JSON, which resumes training via
+//!   [`CFRSolver::import_state`]. Read back with [`read_checkpoint`].
+//!   JSON rather than bincode because the state relies on a self-describing
+//!   format: config fields added later are `#[serde(default)]` so older
+//!   checkpoints still load, and the regret floor is written as `null` when
+//!   unbounded. The price is size and speed: every regret and strategy sum
+//!   is written as decimal text under its full info-set key, so checkpoints
+//!   are larger and slower to write and parse than a binary encoding.
+//! - [`StrategyFormat::HandClassCsv`]: a `hand,<action>...` table with one row
+//!   per starting-hand class, for games that solve one decision per class
+//!   (see [`Game::hand_class_label`]). Read back with [`read_hand_class_csv`].
+
+use std::collections::BTreeMap;
+use std::io::{self, BufRead, Write};
+
+use serde::{Deserialize, Serialize};
+
+use crate::cfr::game::Game;
+use crate::cfr::solver::{CFRSolver, SolverState};
+
+/// Output format for [`CFRSolver::export_to_writer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StrategyFormat {
+    /// Average strategy of every info set, one JSON object per line.
+    Jsonl,
+    /// Everything needed to resume training, as one JSON document.
+    Checkpoint,
+    /// Average strategy per starting-hand class as CSV.
+    HandClassCsv,
+}
+
+/// One line of [`StrategyFormat::Jsonl`] output.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StrategyLine {
+    /// Info-set key.
+    pub key: String,
+    /// Action names, empty if the solver never recorded them.
+    pub actions: Vec<String>,
+    /// Average strategy, one probability per action.
+    pub strategy: Vec<f64>,
+}
+
+/// Parsed [`StrategyFormat::HandClassCsv`] output.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HandClassTable {
+    /// Action names from the header, in column order.
+    pub actions: Vec<String>,
+    /// Hand class name -> average strategy.
+    pub strategies: BTreeMap<String, Vec<f64>>,
+}
+
+impl<G: Game> CFRSolver<G> {
+    /// Write the solve to `writer` in `format`.
+    ///
+    /// Fails with [`io::ErrorKind::Unsupported`] when the format does not
+    /// apply to this game, e.g. [`StrategyFormat::HandClassCsv`] for a game
+    /// whose info sets are not one per hand class.
+    pub fn export_to_writer<W: Write>(&self, mut writer: W, format: StrategyFormat) -> io::Result<()> {
+        match format {
+            StrategyFormat::Jsonl => {
+                for (key, strategy) in self.report().strategies {
+                    let line = StrategyLine {
+                        actions: self.get_action_names(&key).unwrap_or_default(),
+                        key,
+                        strategy,
+                    };
+                    serde_json::to_writer(&mut writer, &line)?;
+                    writeln!(writer)?;
+                }
+            }
+            StrategyFormat::Checkpoint => {
+                serde_json::to_writer(&mut writer, &self.export_state())?;
+            }
+            StrategyFormat::HandClassCsv => self.write_hand_class_csv(&mut writer)?,
+        }
+        writer.flush()
+    }
+
+    fn write_hand_class_csv<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        let unsupported = |msg: String| io::Error::new(io::ErrorKind::Unsupported, msg);
+
+        let mut rows = Vec::new();
+        let mut header: Option<Vec<String>> = None;
+        for (key, strategy) in self.report().strategies {
+            let hand = self
+                .game()
+                .hand_class_label(&key)
+                .ok_or_else(|| unsupported(format!("info set '{}' is not a hand class", key)))?;
+            let actions = self.get_action_names(&key).unwrap_or_default();
+            match &header {
+                None => header = Some(actions),
+                Some(expected) if *expected != actions => {
+                    return Err(unsupported(format!("info set '{}' has different actions", key)));
+                }
+                Some(_) => {}
+            }
+            rows.push((hand, strategy));
+        }
+
+        let header = header.ok_or_else(|| unsupported("no info sets to export".to_string()))?;
+        writeln!(writer, "hand,{}", header.join(","))?;
+        for (hand, strategy) in rows {
+            let probs: Vec<String> = strategy.iter().map(|p| p.to_string()).collect();
+            writeln!(writer, "{},{}", hand, probs.join(","))?;
+        }
+        Ok(())
+    }
+}
+
+/// Parse [`StrategyFormat::Jsonl`] output.
+pub fn read_jsonl<R: BufRead>(reader: R) -> io::Result<Vec<StrategyLine>> {
+    let mut lines = Vec::new();
+    for line in reader.lines() {
+        let line = line?;
+        if !line.trim().is_empty() {
+            lines.push(serde_json::from_str(&line)?);
+        }
+    }
+    Ok(lines)
+}
+
+/// Parse [`StrategyFormat::Checkpoint`] output.
+pub fn read_checkpoint<R: io::Read>(reader: R) -> io::Result<SolverState> {
+    Ok(serde_json::from_reader(reader)?)
+}
+
+/// Parse [`StrategyFormat::HandClassCsv`] output.
+pub fn read_hand_class_csv<R: BufRead>(reader: R) -> io::Result<HandClassTable> {
+    let invalid = |msg: String| io::Error::new(io::ErrorKind::InvalidData, msg);
+
+    let mut lines = reader.lines();
+    let header = lines.next().ok_or_else(|| invalid("missing header".to_string()))??;
+    let mut columns = header.split(',');
+    if columns.next() != Some("hand") {
+        return Err(invalid(format!("unexpected header '{}'", header)));
+    }
+    let actions: Vec<String> = columns.map(str::to_string).collect();
+
+    let mut strategies = BTreeMap::new();
+    for line in lines {
+        let line = line?;
+        let mut fields = line.split(',');
+        let hand = fields.next().unwrap_or_default().to_string();
+        let strategy = fields
+            .map(|p| p.parse::<f64>().map_err(|e| invalid(format!("row '{}': {}", line, e))))
+            .collect::<io::Result<Vec<f64>>>()?;
+        if strategy.len() != actions.len() {
+            return Err(invalid(format!("row '{}' has {} probabilities", line, strategy.len())));
+        }
+        strategies.insert(hand, strategy);
+    }
+    Ok(HandClassTable { actions, strategies })
+}
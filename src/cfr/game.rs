@@ -23,6 +23,16 @@ pub trait InfoState: Clone + Eq + Hash + Debug + Send + Sync {
     /// Generate a unique string key for this information state.
     /// This key is used for storing regrets and strategies.
     fn key(&self) -> String;
+
+    /// Binary form of [`key`](InfoState::key) for storage backends that hash
+    /// byte keys.
+    ///
+    /// Must be unique per distinct info set, like `key`. The default is the
+    /// UTF-8 bytes of `key`; games with large trees can override it with a
+    /// compact encoding.
+    fn key_bytes(&self) -> Vec<u8> {
+        self.key().into_bytes()
+    }
 }
 
 /// Trait for game states.
@@ -49,7 +59,10 @@ pub trait GameState: Clone + Debug + Send + Sync {}
 ///     type Action = MyAction;
 ///     type InfoState = MyInfoState;
 ///
-///     // ... implement required methods
+///     // Required: initial_state, is_terminal, get_payoff, current_player,
+///     // num_players, available_actions, apply_action, info_state.
+///     // Everything else (chance handling, action_name, state_description,
+///     // ...) has a default.
 /// }
 /// ```
 pub trait Game: Clone + Send + Sync {
@@ -100,7 +113,9 @@ pub trait Game: Clone + Send + Sync {
     ///
     /// # Returns
     /// A vector of actions the current player can take.
-    /// Returns empty vector if state is terminal.
+    /// Returns empty vector if state is terminal. Every non-terminal,
+    /// non-chance state must offer at least one action; debug builds of the
+    /// solver panic with the state description otherwise.
     fn available_actions(&self, state: &Self::State) -> Vec<Self::Action>;
 
     /// Apply an action to a state and return the resulting new state.
@@ -156,6 +171,18 @@ pub trait Game: Clone + Send + Sync {
         state.clone()
     }
 
+    /// Every outcome of a chance node, with its probability.
+    ///
+    /// Lets [`CFRSolver::calculate_exploitability_exact`](crate::cfr::CFRSolver::calculate_exploitability_exact)
+    /// and [`CFRSolver::run_iteration_vanilla`](crate::cfr::CFRSolver::run_iteration_vanilla)
+    /// walk the whole tree instead of sampling. The probabilities must sum to
+    /// 1 and match the distribution of [`sample_chance`](Game::sample_chance).
+    /// The default empty list marks the game's chance nodes as too large to
+    /// enumerate, so vanilla CFR samples them instead.
+    fn chance_outcomes(&self, _state: &Self::State) -> Vec<(Self::State, f64)> {
+        Vec::new()
+    }
+
     /// Get a human-readable name for an action.
     ///
     /// Used for debugging and visualization.
@@ -169,6 +196,75 @@ pub trait Game: Clone + Send + Sync {
     fn state_description(&self, state: &Self::State) -> String {
         format!("{:?}", state)
     }
+
+    /// Get a human-readable description of an information state.
+    ///
+    /// Used to label info sets in debugging output and solution dumps. The
+    /// default is the compact [`InfoState::key`].
+    fn describe_info_state(&self, info: &Self::InfoState) -> String {
+        info.key()
+    }
+
+    /// Check game-specific consistency of a state (e.g. chip conservation).
+    ///
+    /// Used by [`assert_game_invariants`](crate::cfr::invariants::assert_game_invariants)
+    /// after every step of a random playout. Return `Err` with a short
+    /// explanation when the state is corrupt. The default accepts every state.
+    fn check_invariants(&self, _state: &Self::State) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Rough number of information sets the game has, if known.
+    ///
+    /// [`CFRSolver::new`](crate::cfr::CFRSolver::new) uses this to pre-size
+    /// storage so the maps don't rehash repeatedly early in training. An
+    /// overestimate only costs memory; the default `None` starts empty.
+    fn estimated_info_sets(&self) -> Option<usize> {
+        None
+    }
+
+    /// Longest path (in actions and chance events) from the root to a terminal.
+    ///
+    /// When set, training traversals panic with the offending state once they
+    /// go deeper than this, so a betting loop that never terminates becomes a
+    /// readable error instead of a stack overflow. The default `None` disables
+    /// the guard.
+    fn max_depth(&self) -> Option<usize> {
+        None
+    }
+
+    /// Whether this is a two-player zero-sum game.
+    ///
+    /// [`CFRSolver::calculate_exploitability`](crate::cfr::CFRSolver::calculate_exploitability)
+    /// averages every player's best-response gain into one number, which is
+    /// only a meaningful distance from equilibrium when one player's gain is
+    /// the other's loss. Games with more players, or where only some players
+    /// really decide, should return `false` and be measured with
+    /// [`CFRSolver::calculate_player_gains`](crate::cfr::CFRSolver::calculate_player_gains).
+    /// The default assumes a zero-sum game.
+    fn is_zero_sum(&self) -> bool {
+        true
+    }
+
+    /// Name of the starting-hand class (e.g. `"AKs"`) an info-set key is for.
+    ///
+    /// Games that solve one decision per hand class return it for every key,
+    /// which lets [`StrategyFormat::HandClassCsv`](crate::cfr::StrategyFormat::HandClassCsv)
+    /// export the solve as a hand-by-action table. The default `None` marks
+    /// the game as not exportable that way.
+    fn hand_class_label(&self, _info_key: &str) -> Option<String> {
+        None
+    }
+
+    /// Identify the abstraction that produced this game's info-set keys.
+    ///
+    /// Stored in checkpoints so a strategy built with one abstraction (e.g.
+    /// 1024 flop buckets) is not loaded into a game using another, where the
+    /// same keys would silently mean different things. Games without an
+    /// abstraction can keep the default empty string.
+    fn abstraction_fingerprint(&self) -> String {
+        String::new()
+    }
 }
 
 /// Macro to simplify implementing the Action trait for simple enums.
@@ -190,3 +286,151 @@ macro_rules! impl_game_state {
         impl $crate::cfr::game::GameState for $type {}
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cfr::{CFRConfig, CFRSolver};
+
+    /// One-shot guessing game implementing only the required methods.
+    #[derive(Clone)]
+    struct GuessGame;
+
+    #[derive(Clone, Debug)]
+    struct GuessState {
+        guess: Option<Guess>,
+    }
+    crate::impl_game_state!(GuessState);
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    enum Guess {
+        Low,
+        High,
+    }
+    crate::impl_action!(Guess);
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Start;
+    impl InfoState for Start {
+        fn key(&self) -> String {
+            "start".to_string()
+        }
+    }
+
+    impl Game for GuessGame {
+        type State = GuessState;
+        type Action = Guess;
+        type InfoState = Start;
+
+        fn initial_state(&self) -> GuessState {
+            GuessState { guess: None }
+        }
+        fn is_terminal(&self, state: &GuessState) -> bool {
+            state.guess.is_some()
+        }
+        fn get_payoff(&self, state: &GuessState, _player: usize) -> f64 {
+            if state.guess == Some(Guess::High) { 1.0 } else { 0.0 }
+        }
+        fn current_player(&self, state: &GuessState) -> Option<usize> {
+            state.guess.is_none().then_some(0)
+        }
+        fn num_players(&self) -> usize {
+            1
+        }
+        fn available_actions(&self, _state: &GuessState) -> Vec<Guess> {
+            vec![Guess::Low, Guess::High]
+        }
+        fn apply_action(&self, _state: &GuessState, action: &Guess) -> GuessState {
+            GuessState { guess: Some(*action) }
+        }
+        fn info_state(&self, _state: &GuessState) -> Start {
+            Start
+        }
+    }
+
+    #[test]
+    fn test_minimal_game_uses_defaults() {
+        let game = GuessGame;
+        assert_eq!(game.action_name(&Guess::High), "High");
+        assert_eq!(game.state_description(&game.initial_state()), "GuessState { guess: None }");
+        assert_eq!(game.describe_info_state(&Start), "start");
+        assert!(!game.is_chance(&game.initial_state()));
+
+        let mut solver = CFRSolver::new(game, CFRConfig::default().with_seed(0));
+        solver.train(100);
+        assert!(solver.get_average_strategy("start", 2)[1] > 0.9);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn test_verify_zero_sum() {
+        use crate::cfr::ZeroSumViolation;
+        use crate::games::kuhn::KuhnPoker;
+
+        let kuhn = CFRSolver::new(KuhnPoker::new(), CFRConfig::default());
+        assert!(kuhn.verify_zero_sum(1_000).is_ok());
+
+        // A lone player paid for guessing high breaks the invariant
+        let guess = CFRSolver::new(GuessGame, CFRConfig::default());
+        let violation = guess.verify_zero_sum(100).unwrap_err();
+        assert_eq!(
+            violation,
+            ZeroSumViolation::NonZeroTotal {
+                state: "GuessState { guess: Some(High) }".to_string(),
+                payoffs: vec![1.0],
+                total: 1.0,
+            }
+        );
+        assert!(violation.to_string().contains("sum to 1"));
+
+        // Broken games are reported instead of hanging or panicking
+        assert!(matches!(
+            crate::cfr::check_zero_sum(&BrokenGame { actions: vec![Guess::Low] }, 1),
+            Err(ZeroSumViolation::NonTerminating { .. })
+        ));
+        assert!(matches!(
+            crate::cfr::check_zero_sum(&BrokenGame { actions: vec![] }, 1),
+            Err(ZeroSumViolation::NoActions { .. })
+        ));
+
+        let raked = CFRSolver::new(crate::games::preflop_8max::Preflop8MaxGame::new(), CFRConfig::default());
+        assert_eq!(raked.verify_zero_sum(1).unwrap_err(), ZeroSumViolation::NotZeroSum);
+    }
+
+    /// Game that never ends, offering `actions` at every node.
+    #[derive(Clone)]
+    struct BrokenGame {
+        actions: Vec<Guess>,
+    }
+
+    impl Game for BrokenGame {
+        type State = GuessState;
+        type Action = Guess;
+        type InfoState = Start;
+
+        fn initial_state(&self) -> GuessState {
+            GuessState { guess: None }
+        }
+        fn is_terminal(&self, _state: &GuessState) -> bool {
+            false
+        }
+        fn get_payoff(&self, _state: &GuessState, _player: usize) -> f64 {
+            0.0
+        }
+        fn current_player(&self, _state: &GuessState) -> Option<usize> {
+            Some(0)
+        }
+        fn num_players(&self) -> usize {
+            1
+        }
+        fn available_actions(&self, _state: &GuessState) -> Vec<Guess> {
+            self.actions.clone()
+        }
+        fn apply_action(&self, state: &GuessState, _action: &Guess) -> GuessState {
+            state.clone()
+        }
+        fn info_state(&self, _state: &GuessState) -> Start {
+            Start
+        }
+    }
+}
@@ -0,0 +1,62 @@
+//! Interning of info-set keys to compact integer ids.
+//!
+//! Hashing and cloning a `String` key on every regret or strategy-sum update
+//! dominates storage cost in large games. [`KeyInterner`] assigns each
+//! distinct key a dense `u32` id on first sight, so
+//! [`RegretStorage`](super::RegretStorage) can key its tables by id and only
+//! turn ids back into strings for queries and export.
+
+use rustc_hash::FxHashMap;
+
+/// Bidirectional mapping between info-set keys and dense ids.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct KeyInterner {
+    /// Info-set key -> id
+    ids: FxHashMap<String, u32>,
+    /// Key for each id, in interning order
+    keys: Vec<String>,
+}
+
+impl KeyInterner {
+    /// Empty interner with room for `capacity` keys.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            ids: FxHashMap::with_capacity_and_hasher(capacity, Default::default()),
+            keys: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Id of `key`, if it has been interned.
+    pub fn get(&self, key: &str) -> Option<u32> {
+        self.ids.get(key).copied()
+    }
+
+    /// Id of `key`, assigning the next free id on first sight.
+    pub fn intern(&mut self, key: &str) -> u32 {
+        if let Some(id) = self.get(key) {
+            return id;
+        }
+        let id = u32::try_from(self.keys.len()).expect("more than u32::MAX info sets");
+        self.ids.insert(key.to_string(), id);
+        self.keys.push(key.to_string());
+        id
+    }
+
+    /// Key interned as `id`.
+    ///
+    /// # Panics
+    /// If `id` was not returned by this interner.
+    pub fn key(&self, id: u32) -> &str {
+        &self.keys[id as usize]
+    }
+
+    /// Number of interned keys.
+    pub fn len(&self) -> u32 {
+        self.keys.len() as u32
+    }
+
+    /// Total bytes of all interned keys.
+    pub fn key_bytes(&self) -> usize {
+        self.keys.iter().map(String::len).sum()
+    }
+}
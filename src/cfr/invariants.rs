@@ -0,0 +1,200 @@
+//! Random-playout checks for `Game` implementations.
+//!
+//! State-machine bugs in a game (a pot that drifts from the chips invested,
+//! a node with no legal actions, a hand that never ends) rarely crash; they
+//! just make the solver converge to nonsense. [`assert_game_invariants`]
+//! plays many random hands and fails loudly on the first violation, and
+//! [`check_zero_sum`] (debug builds only) reports terminal states whose
+//! payoffs don't net out.
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::cfr::game::Game;
+use crate::cfr::solver::CFRSolver;
+
+/// Longest playout (in actions and chance events) before it is treated as
+/// non-terminating.
+pub const MAX_PLAYOUT_STEPS: usize = 10_000;
+
+/// Largest payoff total [`CFRSolver::verify_zero_sum`] treats as zero.
+pub const ZERO_SUM_TOLERANCE: f64 = 1e-9;
+
+/// Play `iterations` random hands and panic on the first broken invariant.
+///
+/// From the initial state, chance nodes are sampled and a uniformly random
+/// legal action is taken at every decision node. After every step this
+/// asserts that:
+/// - [`Game::check_invariants`] accepts the state,
+/// - a non-terminal, non-chance state has at least one legal action,
+/// - the playout reaches a terminal state within [`MAX_PLAYOUT_STEPS`].
+///
+/// Playouts are seeded, so a failure reproduces on rerun.
+///
+/// # Panics
+/// On the first violation, with the offending state's description.
+pub fn assert_game_invariants<G: Game>(game: &G, iterations: usize) {
+    let mut rng = StdRng::seed_from_u64(0);
+
+    for playout in 0..iterations {
+        let mut state = game.initial_state();
+        let mut steps = 0;
+
+        loop {
+            if let Err(msg) = game.check_invariants(&state) {
+                panic!(
+                    "playout {} step {}: {}\n{}",
+                    playout,
+                    steps,
+                    msg,
+                    game.state_description(&state)
+                );
+            }
+
+            if game.is_terminal(&state) {
+                break;
+            }
+
+            assert!(
+                steps < MAX_PLAYOUT_STEPS,
+                "playout {} did not terminate within {} steps\n{}",
+                playout,
+                MAX_PLAYOUT_STEPS,
+                game.state_description(&state)
+            );
+            steps += 1;
+
+            if game.is_chance(&state) {
+                state = game.sample_chance(&state, &mut rng);
+                continue;
+            }
+
+            let actions = game.available_actions(&state);
+            assert!(
+                !actions.is_empty(),
+                "playout {} step {}: no actions at a non-terminal decision node\n{}",
+                playout,
+                steps,
+                game.state_description(&state)
+            );
+
+            let action = &actions[rng.gen_range(0..actions.len())];
+            state = game.apply_action(&state, action);
+        }
+    }
+}
+
+/// Check that payoffs sum to zero over all players at sampled terminal states.
+///
+/// Plays `num_samples` random hands the way [`assert_game_invariants`] does
+/// (seeded, sampling chance and picking uniformly random legal actions, with
+/// the same step cap) and sums [`Game::get_payoff`] over every player at each
+/// terminal state. Unlike [`CFRSolver::verify_zero_sum`] this ignores
+/// [`Game::is_zero_sum`], for games that are only zero-sum in some
+/// configurations (e.g. without rake). Debug builds only.
+///
+/// # Errors
+/// The first terminal state whose total exceeds [`ZERO_SUM_TOLERANCE`], or
+/// the first playout that never terminates or reaches a decision node
+/// without legal actions.
+#[cfg(debug_assertions)]
+pub fn check_zero_sum<G: Game>(game: &G, num_samples: usize) -> Result<(), ZeroSumViolation> {
+    let mut rng = StdRng::seed_from_u64(0);
+
+    for _ in 0..num_samples {
+        let mut state = game.initial_state();
+        let mut steps = 0;
+        while !game.is_terminal(&state) {
+            if steps == MAX_PLAYOUT_STEPS {
+                return Err(ZeroSumViolation::NonTerminating { state: game.state_description(&state) });
+            }
+            steps += 1;
+
+            state = if game.is_chance(&state) {
+                game.sample_chance(&state, &mut rng)
+            } else {
+                let actions = game.available_actions(&state);
+                if actions.is_empty() {
+                    return Err(ZeroSumViolation::NoActions { state: game.state_description(&state) });
+                }
+                game.apply_action(&state, &actions[rng.gen_range(0..actions.len())])
+            };
+        }
+
+        let payoffs: Vec<f64> = (0..game.num_players()).map(|p| game.get_payoff(&state, p)).collect();
+        let total: f64 = payoffs.iter().sum();
+        if total.abs() > ZERO_SUM_TOLERANCE {
+            return Err(ZeroSumViolation::NonZeroTotal {
+                state: game.state_description(&state),
+                payoffs,
+                total,
+            });
+        }
+    }
+    Ok(())
+}
+
+impl<G: Game> CFRSolver<G> {
+    /// Check that the solver's game is zero-sum at sampled terminal states.
+    ///
+    /// Runs [`check_zero_sum`] on the game. A development aid for custom
+    /// games, compiled only into debug builds: it leaves the solver untouched
+    /// and costs one playout per sample.
+    ///
+    /// # Errors
+    /// [`ZeroSumViolation::NotZeroSum`] without playing anything if
+    /// [`Game::is_zero_sum`] is `false` (e.g. raked games), otherwise the
+    /// errors of [`check_zero_sum`].
+    #[cfg(debug_assertions)]
+    pub fn verify_zero_sum(&self, num_samples: usize) -> Result<(), ZeroSumViolation> {
+        if !self.game().is_zero_sum() {
+            return Err(ZeroSumViolation::NotZeroSum);
+        }
+        check_zero_sum(self.game(), num_samples)
+    }
+}
+
+/// Why a zero-sum check failed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ZeroSumViolation {
+    /// A terminal state whose payoffs don't sum to zero
+    NonZeroTotal {
+        /// [`Game::state_description`] of the terminal state
+        state: String,
+        /// Payoff of each player
+        payoffs: Vec<f64>,
+        /// Sum of `payoffs`
+        total: f64,
+    },
+    /// A playout still running after [`MAX_PLAYOUT_STEPS`]
+    NonTerminating {
+        /// [`Game::state_description`] of the last state reached
+        state: String,
+    },
+    /// A non-terminal decision node without legal actions
+    NoActions {
+        /// [`Game::state_description`] of the stuck state
+        state: String,
+    },
+    /// The game reports [`Game::is_zero_sum`] as `false`, so nothing was checked
+    NotZeroSum,
+}
+
+impl std::fmt::Display for ZeroSumViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ZeroSumViolation::NonZeroTotal { state, payoffs, total } => {
+                write!(f, "payoffs {:?} sum to {} at {}", payoffs, total, state)
+            }
+            ZeroSumViolation::NonTerminating { state } => {
+                write!(f, "playout did not terminate within {} steps at {}", MAX_PLAYOUT_STEPS, state)
+            }
+            ZeroSumViolation::NoActions { state } => {
+                write!(f, "no actions at a non-terminal decision node {}", state)
+            }
+            ZeroSumViolation::NotZeroSum => write!(f, "game is not zero-sum"),
+        }
+    }
+}
+
+impl std::error::Error for ZeroSumViolation {}
@@ -73,13 +73,26 @@
 //! - Tammelin, O. "Solving Large Imperfect Information Games Using CFR+" (2014)
 //! - Brown, N., Sandholm, T. "Solving Imperfect-Information Games via Discounted Regret Minimization" (2019)
 
+mod arena;
+mod best_response;
+mod intern;
 pub mod config;
+pub mod export;
 pub mod game;
+pub mod invariants;
+pub mod policy;
 pub mod solver;
 pub mod storage;
+pub mod weighting;
 
 // Re-export main types for convenient access
-pub use config::{CFRConfig, CFRStats, ConfigError, ExploitabilityPoint};
+pub use config::{CFRConfig, CFRStats, ConfigError, ConvergenceMetric, DcfrParams, ExploitabilityPoint, RegretPruning, SamplingScheme, UpdateMode};
+pub use export::{read_checkpoint, read_hand_class_csv, read_jsonl, HandClassTable, StrategyFormat, StrategyLine};
 pub use game::{Action, Game, GameState, InfoState};
-pub use solver::{CFRSolver, ConvergenceResult, ConvergenceStats, SolverState};
-pub use storage::{RegretStorage, StorageExport, StrategySnapshot};
+pub use invariants::{assert_game_invariants, ZeroSumViolation, ZERO_SUM_TOLERANCE};
+#[cfg(debug_assertions)]
+pub use invariants::check_zero_sum;
+pub use policy::MmapPolicy;
+pub use solver::{CFRSolver, ConvergenceResult, ConvergenceStats, ImportError, RngState, SolveReport, SolverState};
+pub use storage::{argmax_with_tiebreak, ActionValueSums, RegretStorage, StorageExport, StorageLayout, StrategySnapshot};
+pub use weighting::{AverageStrategyWeighter, LinearWeighter, QuadraticWeighter, UniformWeighter};
@@ -0,0 +1,178 @@
+//! Memory-mapped average-strategy lookup for deployed policies.
+//!
+//! A solved policy for the full game can be far larger than a bot wants to
+//! keep resident. [`MmapPolicy::write`] serializes the average strategy of
+//! every info set into a flat file sorted by key, and [`MmapPolicy::open`]
+//! memory-maps it so lookups binary-search the on-disk index and only touch
+//! the pages they need.
+//!
+//! # File Format
+//!
+//! All integers and probabilities are little-endian.
+//!
+//! ```text
+//! magic        8 bytes   b"CFRPOL01"
+//! count        u64       number of info sets
+//! index        count x { key_offset: u64, key_len: u32, num_actions: u32, probs_offset: u64 }
+//!                        sorted by key bytes
+//! data         keys (UTF-8) and probabilities (f64), addressed by the index
+//! ```
+//!
+//! The layout is hand-rolled rather than bincode: a bincode file has to be
+//! deserialized as a whole before it can be searched, while fixed-width index
+//! entries let a lookup binary-search the mapping in place and read only the
+//! one record it needs.
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use memmap2::Mmap;
+
+use crate::cfr::storage::RegretStorage;
+
+const MAGIC: &[u8; 8] = b"CFRPOL01";
+const HEADER_LEN: usize = 16;
+const ENTRY_LEN: usize = 24;
+
+/// Read-only average strategy backed by a memory-mapped policy file.
+#[derive(Debug)]
+pub struct MmapPolicy {
+    mmap: Mmap,
+    count: usize,
+}
+
+impl MmapPolicy {
+    /// Write the average strategy of every info set in `storage` to `path`.
+    pub fn write<P: AsRef<Path>>(storage: &RegretStorage, path: P) -> io::Result<()> {
+        let mut entries: Vec<(String, Vec<f64>)> = Vec::with_capacity(storage.num_info_sets());
+        storage.for_each_average_strategy(|key, strategy| {
+            entries.push((key.to_string(), strategy.to_vec()));
+        });
+        entries.sort_by(|a, b| a.0.as_bytes().cmp(b.0.as_bytes()));
+
+        let mut writer = BufWriter::new(File::create(path)?);
+        writer.write_all(MAGIC)?;
+        writer.write_all(&(entries.len() as u64).to_le_bytes())?;
+
+        // Index first, with offsets into the data region that follows it
+        let mut offset = (HEADER_LEN + entries.len() * ENTRY_LEN) as u64;
+        for (key, strategy) in &entries {
+            let probs_offset = offset + key.len() as u64;
+            writer.write_all(&offset.to_le_bytes())?;
+            writer.write_all(&(key.len() as u32).to_le_bytes())?;
+            writer.write_all(&(strategy.len() as u32).to_le_bytes())?;
+            writer.write_all(&probs_offset.to_le_bytes())?;
+            offset = probs_offset + (strategy.len() * std::mem::size_of::<f64>()) as u64;
+        }
+
+        for (key, strategy) in &entries {
+            writer.write_all(key.as_bytes())?;
+            for prob in strategy {
+                writer.write_all(&prob.to_le_bytes())?;
+            }
+        }
+
+        writer.flush()
+    }
+
+    /// Memory-map a policy file written by [`MmapPolicy::write`].
+    ///
+    /// Only the header is checked here: the magic bytes, and that the whole
+    /// index fits in the file, failing with [`io::ErrorKind::InvalidData`]
+    /// otherwise. Index entries are bounds-checked as lookups reach them, so
+    /// opening touches no pages past the header. The file must not be
+    /// modified while it is mapped.
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let file = File::open(path)?;
+        // SAFETY: the mapping is read-only and policy files are written once
+        // and never modified in place.
+        let mmap = unsafe { Mmap::map(&file)? };
+
+        if mmap.len() < HEADER_LEN || &mmap[..8] != MAGIC {
+            return Err(invalid_data("not a policy file"));
+        }
+        let count = read_u64(&mmap, 8) as usize;
+        let index_end = count.checked_mul(ENTRY_LEN).and_then(|len| len.checked_add(HEADER_LEN));
+        if index_end.is_none_or(|end| mmap.len() < end) {
+            return Err(invalid_data("truncated policy index"));
+        }
+
+        Ok(Self { mmap, count })
+    }
+
+    /// Number of info sets in the policy.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Whether the policy has no info sets.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Average strategy for `info_key`, or `None` if the key is not in the policy.
+    ///
+    /// Binary search over the sorted index: O(log n) page touches per lookup.
+    /// An entry addressing bytes outside the file also gives `None`, as does
+    /// a search thrown off by an index that is not sorted.
+    pub fn get_average_strategy(&self, info_key: &str) -> Option<Vec<f64>> {
+        let target = info_key.as_bytes();
+        let (mut lo, mut hi) = (0, self.count);
+
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            let entry = HEADER_LEN + mid * ENTRY_LEN;
+
+            match self.entry_key(entry)?.cmp(target) {
+                std::cmp::Ordering::Less => lo = mid + 1,
+                std::cmp::Ordering::Greater => hi = mid,
+                std::cmp::Ordering::Equal => {
+                    let probs = self.entry_probs(entry)?;
+                    return Some(
+                        probs
+                            .chunks_exact(std::mem::size_of::<f64>())
+                            .map(|bytes| f64::from_le_bytes(bytes.try_into().unwrap()))
+                            .collect(),
+                    );
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Key bytes of the index entry at `entry`, if they lie inside the file.
+    fn entry_key(&self, entry: usize) -> Option<&[u8]> {
+        let offset = usize::try_from(read_u64(&self.mmap, entry)).ok()?;
+        slice_at(&self.mmap, offset, read_u32(&self.mmap, entry + 8) as usize)
+    }
+
+    /// Probability bytes of the index entry at `entry`, if they lie inside the file.
+    fn entry_probs(&self, entry: usize) -> Option<&[u8]> {
+        let offset = usize::try_from(read_u64(&self.mmap, entry + 16)).ok()?;
+        let len = (read_u32(&self.mmap, entry + 12) as usize).checked_mul(std::mem::size_of::<f64>())?;
+        slice_at(&self.mmap, offset, len)
+    }
+}
+
+fn invalid_data<E: Into<Box<dyn std::error::Error + Send + Sync>>>(error: E) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, error)
+}
+
+/// The `len` bytes at `offset`, or `None` if they run past the end.
+fn slice_at(bytes: &[u8], offset: usize, len: usize) -> Option<&[u8]> {
+    bytes.get(offset..offset.checked_add(len)?)
+}
+
+fn read_array<const N: usize>(bytes: &[u8], offset: usize) -> [u8; N] {
+    bytes[offset..offset + N].try_into().unwrap()
+}
+
+fn read_u64(bytes: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(read_array(bytes, offset))
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(read_array(bytes, offset))
+}
@@ -4,7 +4,8 @@
 //! - **Vanilla CFR**: Original algorithm with full tree traversal
 //! - **CFR+**: Floors negative regrets to zero for faster convergence
 //! - **Linear CFR**: Weights later iterations more heavily
-//! - **MCCFR**: Monte Carlo sampling for large games
+//! - **MCCFR**: Monte Carlo sampling for large games, with external or
+//!   outcome sampling (see [`SamplingScheme`])
 //! - **Parallel MCCFR**: Multi-threaded version using rayon
 //!
 //! The solver is generic over any game that implements the `Game` trait.
@@ -12,15 +13,24 @@
 use std::marker::PhantomData;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
-use rand::rngs::StdRng;
 use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha12Rng;
 use rayon::prelude::*;
+use rustc_hash::FxHashMap;
 
-use crate::cfr::config::{CFRConfig, CFRStats};
-use crate::cfr::game::{Game, InfoState};
-use crate::cfr::storage::RegretStorage;
+use crate::cfr::config::{CFRConfig, CFRStats, ConvergenceMetric, SamplingScheme, UpdateMode};
+use crate::cfr::game::{Action, Game, InfoState};
+use crate::cfr::storage::{argmax_with_tiebreak, RegretStorage};
+
+/// ChaCha stream of the configured seed that exploitability estimates sample
+/// chance from; the training RNG stays on stream 0.
+const EVALUATION_STREAM: u64 = 1;
+
+/// First ChaCha stream handed to parallel iterations; iteration `t` samples
+/// from stream `PARALLEL_STREAM_BASE + t` of its batch seed.
+const PARALLEL_STREAM_BASE: u64 = EVALUATION_STREAM + 1;
 
 /// The main CFR solver.
 ///
@@ -60,8 +70,14 @@ pub struct CFRSolver<G: Game> {
     /// Statistics tracking.
     stats: CFRStats,
 
-    /// Random number generator.
-    rng: StdRng,
+    /// Random number generator for training. This is the generator behind
+    /// `StdRng`, named directly so checkpoints can record its position.
+    /// Exploitability estimates use their own RNG (see
+    /// [`calculate_exploitability`](Self::calculate_exploitability)).
+    rng: ChaCha12Rng,
+
+    /// Regret deltas held back until the end of a simultaneous-update iteration.
+    regret_buffer: FxHashMap<u32, Vec<f64>>,
 
     /// Phantom data for type safety.
     _phantom: PhantomData<G>,
@@ -70,22 +86,31 @@ pub struct CFRSolver<G: Game> {
 impl<G: Game> CFRSolver<G> {
     /// Create a new CFR solver for the given game.
     ///
+    /// Storage is pre-sized from [`Game::estimated_info_sets`] when the game
+    /// provides a hint.
+    ///
     /// # Arguments
     /// * `game` - The game to solve
     /// * `config` - Configuration options for the solver
     pub fn new(game: G, config: CFRConfig) -> Self {
+        if let Some(capacity) = game.estimated_info_sets() {
+            return Self::with_capacity(game, config, capacity);
+        }
+
         let rng = match config.seed {
-            Some(seed) => StdRng::seed_from_u64(seed),
-            None => StdRng::from_entropy(),
+            Some(seed) => ChaCha12Rng::seed_from_u64(seed),
+            None => ChaCha12Rng::from_entropy(),
         };
+        let storage = RegretStorage::with_layout(config.storage_layout, 0);
 
         Self {
             game,
             config,
-            storage: RegretStorage::new(),
+            storage,
             iteration: 0,
             stats: CFRStats::new(),
             rng,
+            regret_buffer: FxHashMap::default(),
             _phantom: PhantomData,
         }
     }
@@ -96,17 +121,19 @@ impl<G: Game> CFRSolver<G> {
     /// to avoid reallocations during training.
     pub fn with_capacity(game: G, config: CFRConfig, capacity: usize) -> Self {
         let rng = match config.seed {
-            Some(seed) => StdRng::seed_from_u64(seed),
-            None => StdRng::from_entropy(),
+            Some(seed) => ChaCha12Rng::seed_from_u64(seed),
+            None => ChaCha12Rng::from_entropy(),
         };
+        let storage = RegretStorage::with_layout(config.storage_layout, capacity);
 
         Self {
             game,
             config,
-            storage: RegretStorage::with_capacity(capacity),
+            storage,
             iteration: 0,
             stats: CFRStats::new(),
             rng,
+            regret_buffer: FxHashMap::default(),
             _phantom: PhantomData,
         }
     }
@@ -114,11 +141,58 @@ impl<G: Game> CFRSolver<G> {
     /// Run a single iteration of MCCFR.
     ///
     /// This traverses the game tree once for each player, updating regrets
-    /// and strategy sums along the way.
+    /// and strategy sums along the way, using the configured
+    /// [`SamplingScheme`]. With [`UpdateMode::Simultaneous`] the regret
+    /// updates are deferred until every player has been traversed.
     pub fn run_iteration(&mut self) {
+        self.begin_iteration();
+
+        // Traverse for each player
+        for player in 0..self.game.num_players() {
+            let initial_state = self.game.initial_state();
+            let reach_probs = vec![1.0; self.game.num_players()];
+
+            match self.config.sampling {
+                SamplingScheme::External => {
+                    self.traverse(&initial_state, player, reach_probs, 0);
+                }
+                SamplingScheme::Outcome => {
+                    self.traverse_outcome(&initial_state, player, reach_probs, 1.0, 0);
+                }
+            }
+        }
+
+        self.flush_regret_buffer();
+    }
+
+    /// Run a single iteration of vanilla CFR over the full game tree.
+    ///
+    /// Instead of sampling, chance nodes are expanded through
+    /// [`Game::chance_outcomes`] and every action of every player is
+    /// explored, so each iteration updates every info set with its exact
+    /// counterfactual regret and never touches the RNG. Each iteration costs
+    /// a full tree walk per player, so this is only practical for small games
+    /// such as Kuhn and Leduc, where it converges in far fewer iterations than
+    /// MCCFR. `exploration` and the [`SamplingScheme`] are ignored.
+    ///
+    /// Chance nodes the game cannot enumerate are sampled instead, which
+    /// turns the iteration into chance-sampled CFR below them.
+    pub fn run_iteration_vanilla(&mut self) {
+        self.begin_iteration();
+
+        for player in 0..self.game.num_players() {
+            let initial_state = self.game.initial_state();
+            let reach_probs = vec![1.0; self.game.num_players()];
+            self.traverse_vanilla(&initial_state, player, reach_probs, 1.0, 0);
+        }
+
+        self.flush_regret_buffer();
+    }
+
+    /// Advance the iteration counter and apply discounting if configured.
+    fn begin_iteration(&mut self) {
         self.iteration += 1;
 
-        // Apply discounting if configured
         if let Some(discount) = self.config.regret_discount {
             self.storage.discount_regrets(discount);
         }
@@ -126,12 +200,24 @@ impl<G: Game> CFRSolver<G> {
             self.storage.discount_strategy_sums(discount);
         }
 
-        // Traverse for each player
-        for player in 0..self.game.num_players() {
-            let initial_state = self.game.initial_state();
-            let reach_probs = vec![1.0; self.game.num_players()];
+        // DCFR discounts what the previous iteration left behind
+        let completed = self.iteration - 1;
+        if let (Some(dcfr), true) = (self.config.dcfr, completed > 0) {
+            let (positive, negative) = dcfr.regret_factors(completed);
+            self.storage.discount_regrets_signed(positive, negative);
+            self.storage.discount_strategy_sums(dcfr.strategy_factor(completed));
+        }
+    }
 
-            self.traverse(&initial_state, player, reach_probs);
+    /// Simultaneous updates: apply every player's regrets at once.
+    fn flush_regret_buffer(&mut self) {
+        for (info_id, deltas) in self.regret_buffer.drain() {
+            self.storage.update_regrets_by_id(
+                info_id,
+                &deltas,
+                self.config.use_cfr_plus,
+                self.config.regret_cap,
+            );
         }
     }
 
@@ -140,22 +226,58 @@ impl<G: Game> CFRSolver<G> {
     /// # Arguments
     /// * `iterations` - Number of iterations to run
     ///
+    /// Calling this repeatedly continues the same run: the iteration count
+    /// and elapsed time in the returned stats are cumulative.
+    ///
     /// # Returns
     /// Statistics from the training run.
     pub fn train(&mut self, iterations: u64) -> &CFRStats {
-        let start_time = Instant::now();
+        let start_time = self.resume_clock();
 
         for _ in 0..iterations {
             self.run_iteration();
         }
 
         // Update stats
+        self.refresh_stats(start_time);
+
+        &self.stats
+    }
+
+    /// Clock start for a training call, backdated by the time already spent
+    /// in earlier calls so `stats().elapsed_seconds` stays cumulative.
+    fn resume_clock(&self) -> Instant {
+        let now = Instant::now();
+        now.checked_sub(Duration::from_secs_f64(self.stats.elapsed_seconds))
+            .unwrap_or(now)
+    }
+
+    /// Bring the stats up to date after training since `start_time`.
+    fn refresh_stats(&mut self, start_time: Instant) {
         self.stats.iterations = self.iteration;
         self.stats.info_sets = self.storage.num_info_sets();
         self.stats.elapsed_seconds = start_time.elapsed().as_secs_f64();
         self.stats.update_rate();
+        self.stats.peak_memory_bytes = self.stats.peak_memory_bytes.max(self.storage.memory_usage());
+    }
 
-        &self.stats
+    /// Reseed the solver's RNG, then train for `iterations`.
+    ///
+    /// The same RNG drives chance sampling and opponent action sampling, so a
+    /// given seed fully determines the run from the current solver state.
+    /// Useful for variance studies: clone a base solver and call this with a
+    /// different seed on each clone, without rebuilding the config.
+    pub fn train_seeded(&mut self, iterations: u64, seed: u64) -> &CFRStats {
+        self.reseed(seed);
+        self.train(iterations)
+    }
+
+    /// Reseed the solver's training RNG.
+    ///
+    /// Exploitability estimates sample from a separate evaluation RNG derived
+    /// from `config.seed`, so reseeding does not change them.
+    pub fn reseed(&mut self, seed: u64) {
+        self.rng = ChaCha12Rng::seed_from_u64(seed);
     }
 
     /// Train with a callback for progress tracking.
@@ -173,33 +295,110 @@ impl<G: Game> CFRSolver<G> {
     where
         F: FnMut(&CFRStats),
     {
-        let start_time = Instant::now();
+        let start_time = self.resume_clock();
 
         for i in 0..iterations {
             self.run_iteration();
 
             if (i + 1) % callback_interval == 0 {
-                self.stats.iterations = self.iteration;
-                self.stats.info_sets = self.storage.num_info_sets();
-                self.stats.elapsed_seconds = start_time.elapsed().as_secs_f64();
-                self.stats.update_rate();
+                self.refresh_stats(start_time);
                 callback(&self.stats);
             }
         }
 
         // Final stats update
-        self.stats.iterations = self.iteration;
-        self.stats.info_sets = self.storage.num_info_sets();
-        self.stats.elapsed_seconds = start_time.elapsed().as_secs_f64();
-        self.stats.update_rate();
+        self.refresh_stats(start_time);
+
+        &self.stats
+    }
+
+    /// Train until `budget` of wall-clock time has passed.
+    ///
+    /// Iterations run in batches of `callback_interval` (at least 1); the
+    /// clock is only read between batches, so the call overshoots the budget
+    /// by up to one batch. After every batch the stats are refreshed and
+    /// passed to the callback. The budget counts from the start of this
+    /// call, while the returned stats stay cumulative like [`train`](Self::train).
+    ///
+    /// # Arguments
+    /// * `budget` - Wall-clock time to train for
+    /// * `callback_interval` - Iterations between clock checks and callbacks
+    /// * `callback` - Function called after every batch
+    pub fn train_for_duration<F>(
+        &mut self,
+        budget: Duration,
+        callback_interval: u64,
+        mut callback: F,
+    ) -> &CFRStats
+    where
+        F: FnMut(&CFRStats),
+    {
+        let call_start = Instant::now();
+        let start_time = self.resume_clock();
+        let batch = callback_interval.max(1);
+
+        while call_start.elapsed() < budget {
+            for _ in 0..batch {
+                self.run_iteration();
+            }
+            self.refresh_stats(start_time);
+            callback(&self.stats);
+        }
+
+        // Final stats update
+        self.refresh_stats(start_time);
+
+        &self.stats
+    }
+
+    /// Train while periodically handing the exportable state to a callback.
+    ///
+    /// Every `interval` iterations the callback receives the same
+    /// [`SolverState`] that [`export_state`](Self::export_state) produces, so
+    /// it can be persisted for crash recovery and later passed to
+    /// [`import_state`](Self::import_state). An `interval` of 0 falls back to
+    /// `config.checkpoint_every`; if that is unset, no checkpoints are taken.
+    ///
+    /// # Arguments
+    /// * `iterations` - Number of iterations to run
+    /// * `interval` - Iterations between checkpoints (0 = use config)
+    /// * `callback` - Function called with each checkpoint
+    pub fn train_with_checkpoints<F>(
+        &mut self,
+        iterations: u64,
+        interval: u64,
+        mut callback: F,
+    ) -> &CFRStats
+    where
+        F: FnMut(&SolverState),
+    {
+        let interval = match interval {
+            0 => self.config.checkpoint_every.unwrap_or(0),
+            n => n,
+        };
+        let start_time = self.resume_clock();
+
+        for i in 0..iterations {
+            self.run_iteration();
+
+            if interval > 0 && (i + 1) % interval == 0 {
+                self.refresh_stats(start_time);
+                callback(&self.export_state());
+            }
+        }
+
+        // Final stats update
+        self.refresh_stats(start_time);
 
         &self.stats
     }
 
     /// Train until the Convergence Indicator (CI) reaches the target value.
     ///
-    /// CI measures how much strategies have changed during recent iterations.
-    /// Lower CI means better convergence:
+    /// By default CI measures how much strategies have changed during recent
+    /// iterations; set `CFRConfig::convergence_metric` to
+    /// [`ConvergenceMetric::Exploitability`] to monitor the regret-based
+    /// exploitability bound instead. Lower CI means better convergence:
     /// - CI < 10: bare minimum for a usable solution
     /// - CI ~ 1: close to fully converged (Nash equilibrium)
     ///
@@ -223,9 +422,10 @@ impl<G: Game> CFRSolver<G> {
     {
         use crate::cfr::storage::StrategySnapshot;
 
-        let start_time = Instant::now();
+        let start_time = self.resume_clock();
         let mut snapshot: Option<StrategySnapshot> = None;
         let mut current_ci = f64::INFINITY;
+        let mut ci_trend = CiTrend::default();
 
         // Minimum iterations before first CI check (need enough data to be meaningful)
         // CI can be misleadingly low early on when info sets haven't been visited enough
@@ -237,18 +437,14 @@ impl<G: Game> CFRSolver<G> {
                 self.run_iteration();
             }
 
-            let elapsed = start_time.elapsed().as_secs_f64();
-            let iters_per_sec = if elapsed > 0.0 {
-                self.iteration as f64 / elapsed
-            } else {
-                0.0
-            };
+            self.refresh_stats(start_time);
+            let elapsed = self.stats.elapsed_seconds;
+            let iters_per_sec = self.stats.iterations_per_second;
 
             // Check convergence after warmup
             if self.iteration >= warmup_iterations {
-                // Take snapshot if we don't have one
-                if snapshot.is_none() {
-                    snapshot = Some(self.storage.snapshot_strategies());
+                // The first strategy-change check only takes a snapshot
+                let Some(ci) = self.measure_ci(&mut snapshot) else {
                     // Still report progress (CI will show as infinity/warming)
                     let conv_stats = ConvergenceStats {
                         iteration: self.iteration,
@@ -256,15 +452,14 @@ impl<G: Game> CFRSolver<G> {
                         info_sets: self.storage.num_info_sets(),
                         elapsed_seconds: elapsed,
                         iterations_per_second: iters_per_sec,
+                        eta_seconds: None,
                     };
                     if let Some(ref mut cb) = callback {
                         cb(&conv_stats);
                     }
                     continue;
-                }
-
-                // Calculate CI
-                current_ci = self.storage.calculate_ci(snapshot.as_ref().unwrap());
+                };
+                current_ci = ci;
 
                 // Update stats and callback
                 let conv_stats = ConvergenceStats {
@@ -273,6 +468,7 @@ impl<G: Game> CFRSolver<G> {
                     info_sets: self.storage.num_info_sets(),
                     elapsed_seconds: elapsed,
                     iterations_per_second: iters_per_sec,
+                    eta_seconds: ci_trend.record(elapsed, current_ci, ci_target),
                 };
 
                 if let Some(ref mut cb) = callback {
@@ -288,9 +484,6 @@ impl<G: Game> CFRSolver<G> {
                         elapsed_seconds: elapsed,
                     };
                 }
-
-                // Take new snapshot for next CI measurement
-                snapshot = Some(self.storage.snapshot_strategies());
             } else {
                 // During warmup, still report progress
                 let conv_stats = ConvergenceStats {
@@ -299,6 +492,7 @@ impl<G: Game> CFRSolver<G> {
                     info_sets: self.storage.num_info_sets(),
                     elapsed_seconds: elapsed,
                     iterations_per_second: iters_per_sec,
+                    eta_seconds: None,
                 };
                 if let Some(ref mut cb) = callback {
                     cb(&conv_stats);
@@ -311,12 +505,29 @@ impl<G: Game> CFRSolver<G> {
                     converged: false,
                     final_ci: current_ci,
                     iterations: self.iteration,
-                    elapsed_seconds: start_time.elapsed().as_secs_f64(),
+                    elapsed_seconds: elapsed,
                 };
             }
         }
     }
 
+    /// Measure the configured convergence indicator.
+    ///
+    /// For [`ConvergenceMetric::StrategyChange`] this compares against
+    /// `snapshot` and replaces it with the current strategies, returning
+    /// `None` when there was no earlier snapshot to compare against.
+    fn measure_ci(&self, snapshot: &mut Option<crate::cfr::storage::StrategySnapshot>) -> Option<f64> {
+        match self.config.convergence_metric {
+            ConvergenceMetric::StrategyChange => {
+                let previous = snapshot.replace(self.storage.snapshot_strategies())?;
+                Some(self.storage.calculate_ci(&previous))
+            }
+            ConvergenceMetric::Exploitability => {
+                Some(self.storage.calculate_exploitability_ci(self.iteration))
+            }
+        }
+    }
+
     /// Get current CI (Convergence Indicator) compared to a snapshot.
     ///
     /// Use `snapshot_strategies()` to take a snapshot, then call this after
@@ -325,6 +536,16 @@ impl<G: Game> CFRSolver<G> {
         self.storage.calculate_ci(snapshot)
     }
 
+    /// Fold training so far into the average strategy and reset regrets.
+    ///
+    /// A restart for solves stuck on a plateau: see
+    /// [`RegretStorage::merge_into_average`]. Training continues from the
+    /// returned baseline.
+    pub fn merge_into_average(&mut self, strategy_scale: f64) -> crate::cfr::storage::StrategySnapshot {
+        self.regret_buffer.clear();
+        self.storage.merge_into_average(strategy_scale)
+    }
+
     /// Take a snapshot of current average strategies for CI calculation.
     pub fn snapshot_strategies(&self) -> crate::cfr::storage::StrategySnapshot {
         self.storage.snapshot_strategies()
@@ -334,7 +555,10 @@ impl<G: Game> CFRSolver<G> {
     ///
     /// This recursively traverses the game tree, computing counterfactual values
     /// and updating regrets. Uses external sampling for opponent actions.
-    fn traverse(&mut self, state: &G::State, traverser: usize, reach_probs: Vec<f64>) -> f64 {
+    fn traverse(&mut self, state: &G::State, traverser: usize, reach_probs: Vec<f64>, depth: usize) -> f64 {
+        self.stats.nodes_visited += 1;
+        check_depth(&self.game, state, depth);
+
         // Terminal node: return payoff
         if self.game.is_terminal(state) {
             return self.game.get_payoff(state, traverser);
@@ -343,7 +567,7 @@ impl<G: Game> CFRSolver<G> {
         // Chance node: sample outcome and continue
         if self.game.is_chance(state) {
             let new_state = self.game.sample_chance(state, &mut self.rng);
-            return self.traverse(&new_state, traverser, reach_probs);
+            return self.traverse(&new_state, traverser, reach_probs, depth + 1);
         }
 
         // Get current player and available actions
@@ -351,31 +575,52 @@ impl<G: Game> CFRSolver<G> {
             Some(p) => p,
             None => return self.game.get_payoff(state, traverser),
         };
+        debug_assert!(
+            current_player < reach_probs.len(),
+            "current_player {} out of range for a {}-player game",
+            current_player,
+            reach_probs.len()
+        );
 
         let actions = self.game.available_actions(state);
         let num_actions = actions.len();
+        check_actions(&self.game, state, num_actions);
 
         if num_actions == 0 {
             return self.game.get_payoff(state, traverser);
         }
 
         // Get information state and current strategy
-        let info_state = self.game.info_state(state);
-        let info_key = info_state.key();
-        let strategy = self.storage.get_current_strategy(&info_key, num_actions);
+        let (info_key, info_id) = self.info_set_at(state, current_player, &actions);
+        let strategy = self.storage.get_current_strategy_by_id(info_id, num_actions);
 
         if current_player == traverser {
             // Traverser: explore all actions, update regrets
-            self.traverse_player(state, traverser, &reach_probs, &actions, &strategy, &info_key)
+            self.traverse_player(state, traverser, &reach_probs, &actions, &strategy, &info_key, info_id, depth)
         } else {
             // Opponent: sample one action according to strategy
-            self.traverse_opponent(state, traverser, reach_probs, &actions, &strategy, current_player)
+            self.traverse_opponent(state, traverser, reach_probs, &actions, &strategy, current_player, depth)
+        }
+    }
+
+    /// Key and interned id of the info set at `state`, checking it for
+    /// collisions if configured.
+    fn info_set_at(&mut self, state: &G::State, current_player: usize, actions: &[G::Action]) -> (String, u32) {
+        let info_key = self.game.info_state(state).key();
+        if self.config.check_info_set_collisions {
+            let names: Vec<String> = actions.iter().map(Action::to_string).collect();
+            self.storage.check_info_set_signature(&info_key, current_player, &names, || {
+                self.game.state_description(state)
+            });
         }
+        let info_id = self.storage.intern(&info_key);
+        (info_key, info_id)
     }
 
     /// Handle traversal when it's the traversing player's turn.
     ///
     /// Explores all actions and updates regrets based on counterfactual values.
+    #[allow(clippy::too_many_arguments)]
     fn traverse_player(
         &mut self,
         state: &G::State,
@@ -384,19 +629,25 @@ impl<G: Game> CFRSolver<G> {
         actions: &[G::Action],
         strategy: &[f64],
         info_key: &str,
+        info_id: u32,
+        depth: usize,
     ) -> f64 {
         let num_actions = actions.len();
         let mut action_values = vec![0.0; num_actions];
+        let pruned = self.pruned_actions(info_id, strategy);
 
-        // Explore all actions
+        // Explore all actions that survive pruning
         for (i, action) in actions.iter().enumerate() {
+            if pruned[i] {
+                continue;
+            }
             let new_state = self.game.apply_action(state, action);
 
             // Update reach probabilities
             let mut new_reach = reach_probs.to_vec();
             new_reach[traverser] *= strategy[i];
 
-            action_values[i] = self.traverse(&new_state, traverser, new_reach);
+            action_values[i] = self.traverse(&new_state, traverser, new_reach, depth + 1);
         }
 
         // Compute node value (expected value over strategy)
@@ -406,33 +657,185 @@ impl<G: Game> CFRSolver<G> {
             .map(|(&s, &v)| s * v)
             .sum();
 
+        // Pruned actions are never played, so they don't affect node_value;
+        // valuing them at node_value leaves their regret unchanged.
+        for (value, _) in action_values.iter_mut().zip(&pruned).filter(|(_, &p)| p) {
+            *value = node_value;
+        }
+
         // Compute regret updates: regret[a] = value[a] - node_value
         let regret_updates: Vec<f64> = action_values.iter().map(|&v| v - node_value).collect();
 
-        // Update regrets in storage
-        self.storage
-            .update_regrets(info_key, &regret_updates, self.config.use_cfr_plus);
+        self.apply_regrets(info_id, &regret_updates);
+        self.record_traverser_visit(actions, strategy, info_key, info_id, reach_probs[traverser], &action_values);
 
+        node_value
+    }
+
+    /// Which actions to skip at `info_id` this traversal under
+    /// [`CFRConfig::pruning`]: those with zero probability in `strategy` and
+    /// cumulative regret below the threshold, each with the configured
+    /// probability.
+    fn pruned_actions(&mut self, info_id: u32, strategy: &[f64]) -> Vec<bool> {
+        let mut pruned = vec![false; strategy.len()];
+        let Some(pruning) = self.config.pruning else {
+            return pruned;
+        };
+        let Some(regrets) = self.storage.get_regrets_by_id(info_id) else {
+            return pruned;
+        };
+        for ((skip, &regret), &p) in pruned.iter_mut().zip(&regrets).zip(strategy) {
+            *skip = p == 0.0 && regret < pruning.threshold && self.rng.gen::<f64>() < pruning.probability;
+        }
+        pruned
+    }
+
+    /// Bookkeeping shared by every traverser decision: action names, the
+    /// average strategy (weighted by the traverser's own `reach`), the EMA
+    /// and action values.
+    fn record_traverser_visit(
+        &mut self,
+        actions: &[G::Action],
+        strategy: &[f64],
+        info_key: &str,
+        info_id: u32,
+        reach: f64,
+        action_values: &[f64],
+    ) {
         // Store action names (only stored once per info set)
-        let action_names: Vec<String> = actions.iter()
-            .map(|a| self.game.action_name(a))
-            .collect();
-        self.storage.set_action_names(info_key, action_names);
+        if !self.storage.has_action_names(info_key) {
+            let action_names: Vec<String> = actions.iter()
+                .map(|a| self.game.action_name(a))
+                .collect();
+            self.storage.set_action_names(info_key, action_names);
+        }
 
         // Update strategy sum for average strategy computation
-        let weight = if self.config.use_linear_cfr {
-            reach_probs[traverser] * self.iteration as f64
-        } else {
-            reach_probs[traverser]
+        if self.config.accumulates_average(self.iteration) {
+            let weight = self.config.strategy_weight(self.iteration, reach);
+            self.storage.update_strategy_sum_by_id(info_id, strategy, weight);
+        }
+        if let Some(alpha) = self.config.strategy_ema {
+            self.storage.update_ema_strategy(info_key, strategy, alpha);
+        }
+        if self.config.track_action_values {
+            self.storage.update_action_values(info_key, action_values);
+        }
+    }
+
+    /// Full-tree vanilla CFR traversal.
+    ///
+    /// Returns the traverser's expected value at `state` under the current
+    /// strategies. `chance_reach` is the probability of chance reaching
+    /// `state`; together with the opponents' entries of `reach_probs` it
+    /// weights the traverser's regrets by the counterfactual reach.
+    fn traverse_vanilla(
+        &mut self,
+        state: &G::State,
+        traverser: usize,
+        reach_probs: Vec<f64>,
+        chance_reach: f64,
+        depth: usize,
+    ) -> f64 {
+        self.stats.nodes_visited += 1;
+        check_depth(&self.game, state, depth);
+
+        if self.game.is_terminal(state) {
+            return self.game.get_payoff(state, traverser);
+        }
+
+        if self.game.is_chance(state) {
+            let outcomes = self.game.chance_outcomes(state);
+            if outcomes.is_empty() {
+                let new_state = self.game.sample_chance(state, &mut self.rng);
+                return self.traverse_vanilla(&new_state, traverser, reach_probs, chance_reach, depth + 1);
+            }
+            return outcomes
+                .iter()
+                .map(|(outcome, p)| {
+                    p * self.traverse_vanilla(outcome, traverser, reach_probs.clone(), chance_reach * p, depth + 1)
+                })
+                .sum();
+        }
+
+        let current_player = match self.game.current_player(state) {
+            Some(p) => p,
+            None => return self.game.get_payoff(state, traverser),
         };
-        self.storage.update_strategy_sum(info_key, strategy, weight);
+
+        let actions = self.game.available_actions(state);
+        let num_actions = actions.len();
+        check_actions(&self.game, state, num_actions);
+
+        if num_actions == 0 {
+            return self.game.get_payoff(state, traverser);
+        }
+
+        let (info_key, info_id) = self.info_set_at(state, current_player, &actions);
+        let strategy = self.storage.get_current_strategy_by_id(info_id, num_actions);
+
+        let mut action_values = vec![0.0; num_actions];
+        for (i, action) in actions.iter().enumerate() {
+            let new_state = self.game.apply_action(state, action);
+            let mut new_reach = reach_probs.clone();
+            new_reach[current_player] *= strategy[i];
+            action_values[i] = self.traverse_vanilla(&new_state, traverser, new_reach, chance_reach, depth + 1);
+        }
+        let node_value: f64 = strategy.iter().zip(&action_values).map(|(&s, &v)| s * v).sum();
+
+        if current_player == traverser {
+            let counterfactual_reach: f64 = chance_reach
+                * reach_probs
+                    .iter()
+                    .enumerate()
+                    .filter(|&(p, _)| p != traverser)
+                    .map(|(_, &r)| r)
+                    .product::<f64>();
+            let regret_updates: Vec<f64> = action_values
+                .iter()
+                .map(|&v| counterfactual_reach * (v - node_value))
+                .collect();
+            self.apply_regrets(info_id, &regret_updates);
+            self.record_traverser_visit(
+                &actions,
+                &strategy,
+                &info_key,
+                info_id,
+                reach_probs[traverser],
+                &action_values,
+            );
+        }
 
         node_value
     }
 
+    /// Update regrets in storage, or hold them until the iteration ends.
+    fn apply_regrets(&mut self, info_id: u32, regret_updates: &[f64]) {
+        match self.config.update_mode {
+            UpdateMode::Alternating => {
+                self.storage.update_regrets_by_id(
+                    info_id,
+                    regret_updates,
+                    self.config.use_cfr_plus,
+                    self.config.regret_cap,
+                );
+            }
+            UpdateMode::Simultaneous => {
+                let buffered = self
+                    .regret_buffer
+                    .entry(info_id)
+                    .or_insert_with(|| vec![0.0; regret_updates.len()]);
+                for (b, &update) in buffered.iter_mut().zip(regret_updates.iter()) {
+                    *b += update;
+                }
+            }
+        }
+    }
+
     /// Handle traversal when it's an opponent's turn.
     ///
     /// Samples one action using external sampling with exploration.
+    #[allow(clippy::too_many_arguments)]
     fn traverse_opponent(
         &mut self,
         state: &G::State,
@@ -441,6 +844,7 @@ impl<G: Game> CFRSolver<G> {
         actions: &[G::Action],
         strategy: &[f64],
         current_player: usize,
+        depth: usize,
     ) -> f64 {
         // External sampling with exploration
         let action_idx = if self.rng.gen::<f64>() < self.config.exploration {
@@ -457,7 +861,115 @@ impl<G: Game> CFRSolver<G> {
         // Update reach probability for opponent
         reach_probs[current_player] *= strategy[action_idx];
 
-        self.traverse(&new_state, traverser, reach_probs)
+        self.traverse(&new_state, traverser, reach_probs, depth + 1)
+    }
+
+    /// Outcome-sampling MCCFR traversal.
+    ///
+    /// Follows a single sampled path: chance and opponents sample from their
+    /// current strategies, while the traverser mixes `exploration` uniform
+    /// noise into its own so that no action is starved. `sample_reach` is the
+    /// probability of having sampled the path to `state` (chance cancels out
+    /// and is left out, as it is from `reach_probs`). Returns the
+    /// importance-weighted estimate of the traverser's value at `state`.
+    fn traverse_outcome(
+        &mut self,
+        state: &G::State,
+        traverser: usize,
+        reach_probs: Vec<f64>,
+        sample_reach: f64,
+        depth: usize,
+    ) -> f64 {
+        self.stats.nodes_visited += 1;
+        check_depth(&self.game, state, depth);
+
+        if self.game.is_terminal(state) {
+            return self.game.get_payoff(state, traverser);
+        }
+
+        if self.game.is_chance(state) {
+            let new_state = self.game.sample_chance(state, &mut self.rng);
+            return self.traverse_outcome(&new_state, traverser, reach_probs, sample_reach, depth + 1);
+        }
+
+        let current_player = match self.game.current_player(state) {
+            Some(p) => p,
+            None => return self.game.get_payoff(state, traverser),
+        };
+
+        let actions = self.game.available_actions(state);
+        let num_actions = actions.len();
+        check_actions(&self.game, state, num_actions);
+
+        if num_actions == 0 {
+            return self.game.get_payoff(state, traverser);
+        }
+
+        let (info_key, info_id) = self.info_set_at(state, current_player, &actions);
+        let strategy = self.storage.get_current_strategy_by_id(info_id, num_actions);
+        if !self.storage.has_action_names(&info_key) {
+            let action_names: Vec<String> = actions.iter()
+                .map(|a| self.game.action_name(a))
+                .collect();
+            self.storage.set_action_names(&info_key, action_names);
+        }
+
+        let sample_probs: Vec<f64> = if current_player == traverser {
+            let epsilon = self.config.exploration;
+            strategy
+                .iter()
+                .map(|&p| epsilon / num_actions as f64 + (1.0 - epsilon) * p)
+                .collect()
+        } else {
+            strategy.clone()
+        };
+        let action_idx = self.sample_action(&sample_probs);
+        let sample_prob = sample_probs[action_idx];
+
+        let new_state = self.game.apply_action(state, &actions[action_idx]);
+        let mut new_reach = reach_probs.clone();
+        new_reach[current_player] *= strategy[action_idx];
+
+        // Unsampled actions have an estimated value of 0
+        let sampled_value = self.traverse_outcome(
+            &new_state,
+            traverser,
+            new_reach,
+            sample_reach * sample_prob,
+            depth + 1,
+        ) / sample_prob;
+        let node_value = strategy[action_idx] * sampled_value;
+
+        if current_player == traverser {
+            let opponent_reach: f64 = reach_probs
+                .iter()
+                .enumerate()
+                .filter(|&(p, _)| p != traverser)
+                .map(|(_, &r)| r)
+                .product();
+            let weight = opponent_reach / sample_reach;
+            let regret_updates: Vec<f64> = (0..num_actions)
+                .map(|i| {
+                    let value = if i == action_idx { sampled_value } else { 0.0 };
+                    weight * (value - node_value)
+                })
+                .collect();
+            self.apply_regrets(info_id, &regret_updates);
+        } else {
+            // Stochastically weighted averaging: the opponent's own reach,
+            // corrected for how likely this path was to be sampled
+            if self.config.accumulates_average(self.iteration) {
+                let weight = self
+                    .config
+                    .strategy_weight(self.iteration, reach_probs[current_player] / sample_reach);
+                self.storage.update_strategy_sum_by_id(info_id, &strategy, weight);
+            }
+            if let Some(alpha) = self.config.strategy_ema {
+                self.storage.update_ema_strategy(&info_key, &strategy, alpha);
+            }
+        }
+
+        node_value
     }
 
     /// Sample an action index according to a probability distribution.
@@ -472,8 +984,9 @@ impl<G: Game> CFRSolver<G> {
             }
         }
 
-        // Fallback to last action (handles floating point imprecision)
-        strategy.len() - 1
+        // Fallback to the last action that can be chosen (handles floating
+        // point imprecision)
+        strategy.iter().rposition(|&p| p > 0.0).unwrap_or(strategy.len() - 1)
     }
 
     /// Get the current strategy for an information set.
@@ -490,6 +1003,64 @@ impl<G: Game> CFRSolver<G> {
         self.storage.get_average_strategy(info_key, num_actions)
     }
 
+    /// Number of actions stored for an information set, or `None` if it has
+    /// not been visited yet.
+    ///
+    /// Strategy queries prefer this count over the `num_actions` argument,
+    /// which only sizes the uniform strategy of unvisited info sets.
+    pub fn num_actions_for(&self, info_key: &str) -> Option<usize> {
+        self.storage.num_actions_for(info_key)
+    }
+
+    /// Get the exponential moving average of the current strategy.
+    ///
+    /// Requires `CFRConfig::strategy_ema`; otherwise returns a uniform strategy.
+    pub fn get_ema_strategy(&self, info_key: &str, num_actions: usize) -> Vec<f64> {
+        self.storage.get_ema_strategy(info_key, num_actions)
+    }
+
+    /// Average counterfactual value of each action at an information set.
+    ///
+    /// Requires `CFRConfig::track_action_values`; returns `None` otherwise or
+    /// if the info set was never visited by its acting player.
+    pub fn action_values(&self, info_key: &str) -> Option<Vec<f64>> {
+        self.storage.get_action_values(info_key)
+    }
+
+    /// Get the purified average strategy for an information set.
+    ///
+    /// Puts all weight on the most likely action, breaking ties towards the
+    /// lowest action index.
+    pub fn get_purified_strategy(&self, info_key: &str, num_actions: usize) -> Vec<f64> {
+        self.storage.get_purified_strategy(info_key, num_actions)
+    }
+
+    /// The `n` highest-probability actions of the average strategy.
+    ///
+    /// Returns `(action index, probability)` pairs sorted by probability,
+    /// descending. Empty if the info set has not been visited.
+    pub fn top_actions(&self, info_key: &str, n: usize) -> Vec<(usize, f64)> {
+        self.storage.top_actions(info_key, n)
+    }
+
+    /// Largest L1 distance between the average strategy and a reference.
+    ///
+    /// Compares every info set in `reference` (key -> action probabilities)
+    /// and returns the worst-case sum of absolute differences, so 0 means an
+    /// exact match and 2 means completely disjoint strategies.
+    pub fn max_strategy_error(&self, reference: &std::collections::HashMap<String, Vec<f64>>) -> f64 {
+        reference
+            .iter()
+            .map(|(key, expected)| {
+                self.get_average_strategy(key, expected.len())
+                    .iter()
+                    .zip(expected.iter())
+                    .map(|(a, b)| (a - b).abs())
+                    .sum::<f64>()
+            })
+            .fold(0.0, f64::max)
+    }
+
     /// Get the current iteration count.
     pub fn iteration(&self) -> u64 {
         self.iteration
@@ -522,7 +1093,21 @@ impl<G: Game> CFRSolver<G> {
 
     /// Get all information set keys discovered during training.
     pub fn info_set_keys(&self) -> Vec<String> {
-        self.storage.regrets().keys().cloned().collect()
+        self.storage.info_set_keys()
+    }
+
+    /// Average strategy of every visited information set, keyed by info key.
+    ///
+    /// The strategies are snapshotted under a single read lock of the storage,
+    /// so bulk exports avoid one lock per [`get_average_strategy`](Self::get_average_strategy)
+    /// call. Each vector is sized by the action count stored for its info set;
+    /// iteration order is unspecified.
+    pub fn iter_average_strategies(&self) -> impl Iterator<Item = (String, Vec<f64>)> {
+        let mut strategies = Vec::with_capacity(self.storage.num_info_sets());
+        self.storage.for_each_average_strategy(|key, strategy| {
+            strategies.push((key.to_string(), strategy.to_vec()));
+        });
+        strategies.into_iter()
     }
 
     /// Get action names for an information set.
@@ -540,35 +1125,132 @@ impl<G: Game> CFRSolver<G> {
     ///
     /// # Returns
     /// Estimated exploitability (value the best response gains over current strategy)
+    ///
+    /// # Reproducibility
+    /// Chance is sampled from an evaluation RNG that is separate from the
+    /// training RNG and restarted on every call. With `config.seed` set it
+    /// is derived from the seed, so repeated calls on the same solver
+    /// return identical values, and measuring never shifts the training
+    /// stream: interleaving these calls with `train` leaves the trained
+    /// strategy unchanged. Without a seed each call draws fresh entropy.
+    /// The same applies to every sampled exploitability and player-gain method.
+    ///
+    /// # Panics
+    /// In debug builds, if [`Game::is_zero_sum`] is false; use
+    /// [`calculate_player_gains`](Self::calculate_player_gains) for those games.
     pub fn calculate_exploitability(&mut self, num_samples: usize) -> f64 {
-        let mut total_exploitability = 0.0;
+        self.calculate_exploitability_pruned(num_samples, 0.0)
+    }
 
-        for _ in 0..num_samples {
-            for exploiter in 0..self.game.num_players() {
-                let initial_state = self.game.initial_state();
+    /// Calculate exploitability, skipping lines the average strategy rarely reaches.
+    ///
+    /// When walking the tree with every player on the average strategy, any
+    /// branch whose reach probability (product of all players' action
+    /// probabilities, excluding chance) falls below `min_reach` is not
+    /// explored and contributes 0. This biases the strategy value towards 0
+    /// by at most `pruned reach mass * max |payoff|`, so the estimate stays
+    /// close for small thresholds while skipping most near-impossible lines
+    /// in large games. A `min_reach` of 0.0 disables pruning.
+    ///
+    /// # Arguments
+    /// * `num_samples` - Number of samples for Monte Carlo estimation
+    /// * `min_reach` - Reach probability below which a branch is pruned
+    pub fn calculate_exploitability_pruned(&mut self, num_samples: usize, min_reach: f64) -> f64 {
+        let initial_state = self.game.initial_state();
+        self.exploitability_from(&initial_state, num_samples, min_reach)
+    }
+
+    /// Calculate exploitability of the subtree rooted at `root`.
+    ///
+    /// Runs the same best-response and strategy-value walks as
+    /// [`calculate_exploitability`](Self::calculate_exploitability), but
+    /// starting from an arbitrary (typically mid-hand, already dealt) state,
+    /// so it only measures how much can be gained by deviating below `root`.
+    /// Everything above `root` is taken as given. Much cheaper than the
+    /// global figure for checking one line of a large game.
+    ///
+    /// # Arguments
+    /// * `root` - State to anchor both walks at
+    /// * `num_samples` - Number of samples for chance nodes below `root`
+    pub fn calculate_exploitability_for_subtree(&mut self, root: &G::State, num_samples: usize) -> f64 {
+        self.exploitability_from(root, num_samples, 0.0)
+    }
+
+    /// Each player's best-response gain over the average strategy.
+    ///
+    /// Entry `p` is how much player `p` could win on average by switching to
+    /// a best response while everyone else keeps playing the average
+    /// strategy. Unlike [`calculate_exploitability`](Self::calculate_exploitability)
+    /// this stays meaningful for games that are not two-player zero-sum
+    /// (see [`Game::is_zero_sum`]): the profile is an equilibrium when every
+    /// entry is 0.
+    ///
+    /// # Arguments
+    /// * `num_samples` - Number of samples for Monte Carlo estimation
+    pub fn calculate_player_gains(&mut self, num_samples: usize) -> Vec<f64> {
+        let initial_state = self.game.initial_state();
+        self.player_gains_from(&initial_state, num_samples, 0.0)
+    }
+
+    /// Average best-response gain over the average strategy, from `root`.
+    fn exploitability_from(&mut self, root: &G::State, num_samples: usize, min_reach: f64) -> f64 {
+        debug_assert!(
+            self.game.is_zero_sum(),
+            "scalar exploitability is not meaningful for a game that is not zero-sum; \
+             use calculate_player_gains instead"
+        );
+
+        let gains = self.player_gains_from(root, num_samples, min_reach);
+        gains.iter().sum::<f64>() / gains.len() as f64
+    }
 
+    /// Fresh RNG for sampling chance in exploitability estimates.
+    ///
+    /// Seeded configs get stream [`EVALUATION_STREAM`] of the training seed,
+    /// which never overlaps the training RNG's stream.
+    fn evaluation_rng(&self) -> ChaCha12Rng {
+        match self.config.seed {
+            Some(seed) => {
+                let mut rng = ChaCha12Rng::seed_from_u64(seed);
+                rng.set_stream(EVALUATION_STREAM);
+                rng
+            }
+            None => ChaCha12Rng::from_entropy(),
+        }
+    }
+
+    /// Per-player best-response gain over the average strategy, from `root`.
+    fn player_gains_from(&mut self, root: &G::State, num_samples: usize, min_reach: f64) -> Vec<f64> {
+        let mut gains = vec![0.0; self.game.num_players()];
+        let mut rng = self.evaluation_rng();
+
+        for _ in 0..num_samples {
+            for (exploiter, gain) in gains.iter_mut().enumerate() {
                 // Value when exploiter plays best response
-                let br_value = self.best_response_value(&initial_state, exploiter);
+                let br_value = self.best_response_value(root, exploiter, &mut rng);
 
                 // Value when exploiter plays current strategy
-                let strategy_value = self.strategy_value(&initial_state, exploiter);
+                let strategy_value = self.strategy_value(root, exploiter, 1.0, min_reach, &mut rng);
 
-                total_exploitability += br_value - strategy_value;
+                *gain += br_value - strategy_value;
             }
         }
 
-        total_exploitability / (num_samples as f64 * self.game.num_players() as f64)
+        gains.iter_mut().for_each(|gain| *gain /= num_samples as f64);
+        gains
     }
 
     /// Compute value when a player plays best response against fixed opponents.
-    fn best_response_value(&mut self, state: &G::State, exploiter: usize) -> f64 {
+    fn best_response_value(&mut self, state: &G::State, exploiter: usize, rng: &mut ChaCha12Rng) -> f64 {
+        self.stats.exploitability_nodes_visited += 1;
+
         if self.game.is_terminal(state) {
             return self.game.get_payoff(state, exploiter);
         }
 
         if self.game.is_chance(state) {
-            let new_state = self.game.sample_chance(state, &mut self.rng);
-            return self.best_response_value(&new_state, exploiter);
+            let new_state = self.game.sample_chance(state, rng);
+            return self.best_response_value(&new_state, exploiter, rng);
         }
 
         let current_player = match self.game.current_player(state) {
@@ -582,14 +1264,15 @@ impl<G: Game> CFRSolver<G> {
         }
 
         if current_player == exploiter {
-            // Exploiter: choose best action
-            let mut best_value = f64::NEG_INFINITY;
-            for action in &actions {
-                let new_state = self.game.apply_action(state, action);
-                let value = self.best_response_value(&new_state, exploiter);
-                best_value = best_value.max(value);
-            }
-            best_value
+            // Exploiter: choose best action (lowest index on ties)
+            let values: Vec<f64> = actions
+                .iter()
+                .map(|action| {
+                    let new_state = self.game.apply_action(state, action);
+                    self.best_response_value(&new_state, exploiter, rng)
+                })
+                .collect();
+            argmax_with_tiebreak(&values).map_or(f64::NEG_INFINITY, |best| values[best])
         } else {
             // Opponent: play according to average strategy
             let info_state = self.game.info_state(state);
@@ -598,7 +1281,7 @@ impl<G: Game> CFRSolver<G> {
             let mut expected_value = 0.0;
             for (i, action) in actions.iter().enumerate() {
                 let new_state = self.game.apply_action(state, action);
-                let value = self.best_response_value(&new_state, exploiter);
+                let value = self.best_response_value(&new_state, exploiter, rng);
                 expected_value += strategy[i] * value;
             }
             expected_value
@@ -606,14 +1289,29 @@ impl<G: Game> CFRSolver<G> {
     }
 
     /// Compute value when all players play according to current strategy.
-    fn strategy_value(&mut self, state: &G::State, player: usize) -> f64 {
+    ///
+    /// `reach` is the probability of the strategies reaching `state`; branches
+    /// below `min_reach` are cut off and valued at 0.
+    fn strategy_value(
+        &mut self,
+        state: &G::State,
+        player: usize,
+        reach: f64,
+        min_reach: f64,
+        rng: &mut ChaCha12Rng,
+    ) -> f64 {
+        if reach < min_reach {
+            return 0.0;
+        }
+        self.stats.exploitability_nodes_visited += 1;
+
         if self.game.is_terminal(state) {
             return self.game.get_payoff(state, player);
         }
 
         if self.game.is_chance(state) {
-            let new_state = self.game.sample_chance(state, &mut self.rng);
-            return self.strategy_value(&new_state, player);
+            let new_state = self.game.sample_chance(state, rng);
+            return self.strategy_value(&new_state, player, reach, min_reach, rng);
         }
 
         let _current_player = match self.game.current_player(state) {
@@ -632,26 +1330,82 @@ impl<G: Game> CFRSolver<G> {
         let mut expected_value = 0.0;
         for (i, action) in actions.iter().enumerate() {
             let new_state = self.game.apply_action(state, action);
-            let value = self.strategy_value(&new_state, player);
+            let value = self.strategy_value(&new_state, player, reach * strategy[i], min_reach, rng);
             expected_value += strategy[i] * value;
         }
         expected_value
     }
 
     /// Export solver state for checkpointing.
+    ///
+    /// The export includes the sampling RNG's position. With a seeded
+    /// config, `train(a)`, export, import into a fresh solver, then
+    /// `train(b)` yields exactly the same strategy as `train(a + b)`.
     pub fn export_state(&self) -> SolverState {
         SolverState {
             iteration: self.iteration,
             storage: self.storage.export(),
             stats: self.stats.clone(),
+            abstraction_fingerprint: self.game.abstraction_fingerprint(),
+            rng: Some(RngState::capture(&self.rng)),
+        }
+    }
+
+    /// Bundle the config, stats, abstraction fingerprint and full average
+    /// strategy table into a [`SolveReport`].
+    pub fn report(&self) -> SolveReport {
+        let mut strategies = std::collections::BTreeMap::new();
+        self.storage.for_each_average_strategy(|key, strategy| {
+            strategies.insert(key.to_string(), strategy.to_vec());
+        });
+
+        SolveReport {
+            config: self.config.clone(),
+            stats: self.stats.clone(),
+            abstraction_fingerprint: self.game.abstraction_fingerprint(),
+            strategies,
         }
     }
 
     /// Import solver state from checkpoint.
-    pub fn import_state(&mut self, state: SolverState) {
+    ///
+    /// Fails if the checkpoint was produced under a different abstraction
+    /// (see [`Game::abstraction_fingerprint`]). Checkpoints written before
+    /// fingerprints existed carry an empty one and are accepted as-is.
+    pub fn import_state(&mut self, state: SolverState) -> Result<(), ImportError> {
+        let expected = self.game.abstraction_fingerprint();
+        if !state.abstraction_fingerprint.is_empty() && state.abstraction_fingerprint != expected {
+            return Err(ImportError::AbstractionMismatch {
+                expected,
+                found: state.abstraction_fingerprint,
+            });
+        }
+
         self.iteration = state.iteration;
         self.storage.import(state.storage);
         self.stats = state.stats;
+        if let Some(rng) = state.rng {
+            self.rng = rng.restore();
+        }
+        Ok(())
+    }
+
+    /// Seed the solver from a prior solve of a similar game.
+    ///
+    /// Imports the checkpoint like [`import_state`](Self::import_state),
+    /// including its iteration count so iteration weighting carries on, then
+    /// scales the imported regrets by
+    /// [`CFRConfig::warm_start_regret_scale`]. The solver keeps its own RNG.
+    /// Meant for re-solving after a small change such as a tweaked bet size:
+    /// info sets the change leaves alone start near equilibrium, while new
+    /// ones start fresh. An info set whose key survives must keep its number
+    /// of actions.
+    pub fn warm_start(&mut self, state: SolverState) -> Result<(), ImportError> {
+        self.import_state(SolverState { rng: None, ..state })?;
+        if self.config.warm_start_regret_scale != 1.0 {
+            self.storage.discount_regrets(self.config.warm_start_regret_scale);
+        }
+        Ok(())
     }
 
     /// Reset the solver to initial state.
@@ -670,6 +1424,9 @@ impl<G: Game> CFRSolver<G> {
     /// # Arguments
     /// * `num_iterations` - Total number of iterations to run
     /// * `num_threads` - Number of threads (0 = auto-detect)
+    ///
+    /// # Panics
+    /// If the config fails [`CFRConfig::validate_parallel`].
     pub fn run_parallel_iterations(&mut self, num_iterations: u64, num_threads: usize)
     where
         G: Send + Sync,
@@ -682,7 +1439,54 @@ impl<G: Game> CFRSolver<G> {
                 .ok(); // Ignore error if pool already built
         }
 
-        let actual_threads = rayon::current_num_threads();
+        let base_seed = self.config.seed.filter(|_| self.config.deterministic);
+        self.parallel_batch(num_iterations, base_seed);
+    }
+
+    /// Train for `iterations` external-sampling iterations spread over a
+    /// dedicated pool of `num_threads` threads (0 = one per core).
+    ///
+    /// Like [`train`](Self::train), stats are cumulative across calls. Each
+    /// iteration traverses with its own RNG, seeded from the solver's RNG,
+    /// and writes regret and strategy updates through the storage locks as
+    /// it goes. Unlike [`run_parallel_iterations`](Self::run_parallel_iterations)
+    /// the pool is private to this call, so `num_threads` is honoured even
+    /// after rayon's global pool has been set up.
+    ///
+    /// Threads interleave their updates, so a seeded run is not bit-for-bit
+    /// reproducible, but it converges like serial training.
+    ///
+    /// # Panics
+    /// If the config fails [`CFRConfig::validate_parallel`].
+    pub fn train_parallel(&mut self, iterations: u64, num_threads: usize) -> &CFRStats
+    where
+        G: Send + Sync,
+    {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(num_threads)
+            .build()
+            .expect("failed to build training thread pool");
+
+        let start_time = self.resume_clock();
+        let base_seed = self.rng.gen();
+        pool.install(|| self.parallel_batch(iterations, Some(base_seed)));
+        self.refresh_stats(start_time);
+
+        &self.stats
+    }
+
+    /// Run `num_iterations` iterations on the current rayon pool.
+    ///
+    /// Iteration `t` samples from its own ChaCha stream of `base_seed`, so
+    /// nearby seeds and iterations never share a stream. Iterations draw from
+    /// entropy when `base_seed` is `None`.
+    fn parallel_batch(&mut self, num_iterations: u64, base_seed: Option<u64>)
+    where
+        G: Send + Sync,
+    {
+        if let Err(err) = self.config.validate_parallel() {
+            panic!("invalid parallel config: {}", err);
+        }
 
         // Shared references for parallel access
         let storage = &self.storage;
@@ -692,12 +1496,19 @@ impl<G: Game> CFRSolver<G> {
 
         // Run parallel iterations
         (0..num_iterations).into_par_iter().for_each(|_| {
-            // Thread-local RNG
-            let mut rng = StdRng::from_entropy();
-
             // Increment iteration counter
             let iter = iteration_counter.fetch_add(1, Ordering::Relaxed) + 1;
 
+            // Thread-local RNG
+            let mut rng = match base_seed {
+                Some(seed) => {
+                    let mut rng = ChaCha12Rng::seed_from_u64(seed);
+                    rng.set_stream(PARALLEL_STREAM_BASE.wrapping_add(iter));
+                    rng
+                }
+                None => ChaCha12Rng::from_entropy(),
+            };
+
             // Traverse for each player
             for player in 0..game.num_players() {
                 let initial_state = game.initial_state();
@@ -712,6 +1523,7 @@ impl<G: Game> CFRSolver<G> {
                     player,
                     reach_probs,
                     iter,
+                    0,
                 );
             }
         });
@@ -723,6 +1535,9 @@ impl<G: Game> CFRSolver<G> {
     /// Train in parallel until convergence.
     ///
     /// Like `train_until_converged` but uses all CPU cores.
+    ///
+    /// # Panics
+    /// If the config fails [`CFRConfig::validate_parallel`].
     pub fn train_parallel_until_converged<F>(
         &mut self,
         ci_target: f64,
@@ -737,9 +1552,10 @@ impl<G: Game> CFRSolver<G> {
     {
         use crate::cfr::storage::StrategySnapshot;
 
-        let start_time = Instant::now();
+        let start_time = self.resume_clock();
         let mut snapshot: Option<StrategySnapshot> = None;
         let mut current_ci = f64::INFINITY;
+        let mut ci_trend = CiTrend::default();
 
         // Minimum iterations before checking convergence
         let warmup_iterations = batch_size.max(1000);
@@ -748,32 +1564,27 @@ impl<G: Game> CFRSolver<G> {
             // Run a batch of parallel iterations
             self.run_parallel_iterations(batch_size, num_threads);
 
-            let elapsed = start_time.elapsed().as_secs_f64();
-            let iters_per_sec = if elapsed > 0.0 {
-                self.iteration as f64 / elapsed
-            } else {
-                0.0
-            };
+            self.refresh_stats(start_time);
+            let elapsed = self.stats.elapsed_seconds;
+            let iters_per_sec = self.stats.iterations_per_second;
 
             // Check convergence after warmup
             if self.iteration >= warmup_iterations {
-                if snapshot.is_none() {
-                    snapshot = Some(self.storage.snapshot_strategies());
+                let Some(ci) = self.measure_ci(&mut snapshot) else {
                     let conv_stats = ConvergenceStats {
                         iteration: self.iteration,
                         ci: current_ci,
                         info_sets: self.storage.num_info_sets(),
                         elapsed_seconds: elapsed,
                         iterations_per_second: iters_per_sec,
+                        eta_seconds: None,
                     };
                     if let Some(ref mut cb) = callback {
                         cb(&conv_stats);
                     }
                     continue;
-                }
-
-                // Calculate CI
-                current_ci = self.storage.calculate_ci(snapshot.as_ref().unwrap());
+                };
+                current_ci = ci;
 
                 let conv_stats = ConvergenceStats {
                     iteration: self.iteration,
@@ -781,6 +1592,7 @@ impl<G: Game> CFRSolver<G> {
                     info_sets: self.storage.num_info_sets(),
                     elapsed_seconds: elapsed,
                     iterations_per_second: iters_per_sec,
+                    eta_seconds: ci_trend.record(elapsed, current_ci, ci_target),
                 };
 
                 if let Some(ref mut cb) = callback {
@@ -796,9 +1608,6 @@ impl<G: Game> CFRSolver<G> {
                         elapsed_seconds: elapsed,
                     };
                 }
-
-                // Take new snapshot
-                snapshot = Some(self.storage.snapshot_strategies());
             } else {
                 // During warmup, still report progress
                 let conv_stats = ConvergenceStats {
@@ -807,6 +1616,7 @@ impl<G: Game> CFRSolver<G> {
                     info_sets: self.storage.num_info_sets(),
                     elapsed_seconds: elapsed,
                     iterations_per_second: iters_per_sec,
+                    eta_seconds: None,
                 };
                 if let Some(ref mut cb) = callback {
                     cb(&conv_stats);
@@ -819,7 +1629,7 @@ impl<G: Game> CFRSolver<G> {
                     converged: false,
                     final_ci: current_ci,
                     iterations: self.iteration,
-                    elapsed_seconds: start_time.elapsed().as_secs_f64(),
+                    elapsed_seconds: elapsed,
                 };
             }
         }
@@ -827,16 +1637,20 @@ impl<G: Game> CFRSolver<G> {
 }
 
 /// Parallel traversal function (used by run_parallel_iterations).
+#[allow(clippy::too_many_arguments)]
 fn parallel_traverse<G: Game>(
     game: &G,
     storage: &RegretStorage,
     config: &CFRConfig,
-    rng: &mut StdRng,
+    rng: &mut ChaCha12Rng,
     state: &G::State,
     traverser: usize,
     reach_probs: Vec<f64>,
     iteration: u64,
+    depth: usize,
 ) -> f64 {
+    check_depth(game, state, depth);
+
     // Terminal node
     if game.is_terminal(state) {
         return game.get_payoff(state, traverser);
@@ -845,7 +1659,7 @@ fn parallel_traverse<G: Game>(
     // Chance node
     if game.is_chance(state) {
         let new_state = game.sample_chance(state, rng);
-        return parallel_traverse(game, storage, config, rng, &new_state, traverser, reach_probs, iteration);
+        return parallel_traverse(game, storage, config, rng, &new_state, traverser, reach_probs, iteration, depth + 1);
     }
 
     // Get current player
@@ -853,9 +1667,16 @@ fn parallel_traverse<G: Game>(
         Some(p) => p,
         None => return game.get_payoff(state, traverser),
     };
+    debug_assert!(
+        current_player < reach_probs.len(),
+        "current_player {} out of range for a {}-player game",
+        current_player,
+        reach_probs.len()
+    );
 
     let actions = game.available_actions(state);
     let num_actions = actions.len();
+    check_actions(game, state, num_actions);
 
     if num_actions == 0 {
         return game.get_payoff(state, traverser);
@@ -864,7 +1685,12 @@ fn parallel_traverse<G: Game>(
     // Get info state and strategy
     let info_state = game.info_state(state);
     let info_key = info_state.key();
-    let strategy = storage.get_current_strategy(&info_key, num_actions);
+    if config.check_info_set_collisions {
+        let names: Vec<String> = actions.iter().map(Action::to_string).collect();
+        storage.check_info_set_signature(&info_key, current_player, &names, || game.state_description(state));
+    }
+    let info_id = storage.intern(&info_key);
+    let strategy = storage.get_current_strategy_by_id(info_id, num_actions);
 
     if current_player == traverser {
         // Traverser: explore all actions
@@ -874,7 +1700,7 @@ fn parallel_traverse<G: Game>(
             let new_state = game.apply_action(state, action);
             let mut new_reach = reach_probs.clone();
             new_reach[traverser] *= strategy[i];
-            action_values[i] = parallel_traverse(game, storage, config, rng, &new_state, traverser, new_reach, iteration);
+            action_values[i] = parallel_traverse(game, storage, config, rng, &new_state, traverser, new_reach, iteration, depth + 1);
         }
 
         // Compute node value
@@ -882,19 +1708,25 @@ fn parallel_traverse<G: Game>(
 
         // Compute and update regrets
         let regret_updates: Vec<f64> = action_values.iter().map(|&v| v - node_value).collect();
-        storage.update_regrets(&info_key, &regret_updates, config.use_cfr_plus);
+        storage.update_regrets_by_id(info_id, &regret_updates, config.use_cfr_plus, config.regret_cap);
 
         // Store action names
-        let action_names: Vec<String> = actions.iter().map(|a| game.action_name(a)).collect();
-        storage.set_action_names(&info_key, action_names);
+        if !storage.has_action_names(&info_key) {
+            let action_names: Vec<String> = actions.iter().map(|a| game.action_name(a)).collect();
+            storage.set_action_names(&info_key, action_names);
+        }
 
         // Update strategy sum
-        let weight = if config.use_linear_cfr {
-            reach_probs[traverser] * iteration as f64
-        } else {
-            reach_probs[traverser]
-        };
-        storage.update_strategy_sum(&info_key, &strategy, weight);
+        if config.accumulates_average(iteration) {
+            let weight = config.strategy_weight(iteration, reach_probs[traverser]);
+            storage.update_strategy_sum_by_id(info_id, &strategy, weight);
+        }
+        if let Some(alpha) = config.strategy_ema {
+            storage.update_ema_strategy(&info_key, &strategy, alpha);
+        }
+        if config.track_action_values {
+            storage.update_action_values(&info_key, &action_values);
+        }
 
         node_value
     } else {
@@ -911,12 +1743,37 @@ fn parallel_traverse<G: Game>(
         let mut new_reach = reach_probs;
         new_reach[current_player] *= strategy[action_idx];
 
-        parallel_traverse(game, storage, config, rng, &new_state, traverser, new_reach, iteration)
+        parallel_traverse(game, storage, config, rng, &new_state, traverser, new_reach, iteration, depth + 1)
+    }
+}
+
+/// Panic with a readable message once a traversal goes deeper than the
+/// game's [`Game::max_depth`], instead of overflowing the stack.
+fn check_depth<G: Game>(game: &G, state: &G::State, depth: usize) {
+    if let Some(max_depth) = game.max_depth() {
+        assert!(
+            depth <= max_depth,
+            "traversal exceeded max depth {}; the game may not terminate\n{}",
+            max_depth,
+            game.state_description(state)
+        );
     }
 }
 
+/// Panic in debug builds when a decision node offers no actions.
+///
+/// Non-terminal, non-chance states must give the acting player at least one
+/// action. Release builds fall back to treating such a state as terminal.
+fn check_actions<G: Game>(game: &G, state: &G::State, num_actions: usize) {
+    debug_assert!(
+        num_actions > 0,
+        "non-terminal, non-chance state has no available actions\n{}",
+        game.state_description(state)
+    );
+}
+
 /// Sample action from strategy distribution.
-fn sample_action_from_strategy(rng: &mut StdRng, strategy: &[f64]) -> usize {
+fn sample_action_from_strategy(rng: &mut ChaCha12Rng, strategy: &[f64]) -> usize {
     let r: f64 = rng.gen();
     let mut cumsum = 0.0;
 
@@ -931,6 +1788,10 @@ fn sample_action_from_strategy(rng: &mut StdRng, strategy: &[f64]) -> usize {
 }
 
 /// Serializable solver state for checkpointing.
+///
+/// Holds everything training reads, including the RNG position, so a seeded
+/// solve resumed from a checkpoint (in memory or round-tripped through JSON)
+/// matches an uninterrupted one bit for bit.
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct SolverState {
     /// Current iteration.
@@ -939,8 +1800,83 @@ pub struct SolverState {
     pub storage: crate::cfr::storage::StorageExport,
     /// Statistics.
     pub stats: CFRStats,
+    /// Abstraction the info-set keys were built with.
+    #[serde(default)]
+    pub abstraction_fingerprint: String,
+    /// Position of the sampling RNG. Checkpoints written before this was
+    /// recorded leave it `None`, and importing them keeps the solver's RNG.
+    #[serde(default)]
+    pub rng: Option<RngState>,
+}
+
+/// Serializable position of the solver's sampling RNG.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RngState {
+    /// Key the generator was seeded with.
+    pub seed: [u8; 32],
+    /// Stream number.
+    pub stream: u64,
+    /// Offset into the stream, in 32-bit words.
+    pub word_pos: u128,
+}
+
+impl RngState {
+    fn capture(rng: &ChaCha12Rng) -> Self {
+        Self {
+            seed: rng.get_seed(),
+            stream: rng.get_stream(),
+            word_pos: rng.get_word_pos(),
+        }
+    }
+
+    fn restore(&self) -> ChaCha12Rng {
+        let mut rng = ChaCha12Rng::from_seed(self.seed);
+        rng.set_stream(self.stream);
+        rng.set_word_pos(self.word_pos);
+        rng
+    }
 }
 
+/// Serializable summary of a finished solve, produced by [`CFRSolver::report`].
+///
+/// Unlike [`SolverState`] this carries only what's needed to inspect or
+/// compare solves, not the regrets needed to resume training.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SolveReport {
+    /// Config the solve was run with.
+    pub config: CFRConfig,
+    /// Final statistics.
+    pub stats: CFRStats,
+    /// Abstraction the info-set keys were built with.
+    pub abstraction_fingerprint: String,
+    /// Average strategy for every info set, keyed (and ordered) by info-set key.
+    pub strategies: std::collections::BTreeMap<String, Vec<f64>>,
+}
+
+/// Errors that can occur when importing a checkpoint.
+#[derive(Debug, Clone)]
+pub enum ImportError {
+    /// The checkpoint was built with a different abstraction than the game.
+    AbstractionMismatch {
+        /// Fingerprint of the current game.
+        expected: String,
+        /// Fingerprint stored in the checkpoint.
+        found: String,
+    },
+}
+
+impl std::fmt::Display for ImportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ImportError::AbstractionMismatch { expected, found } => {
+                write!(f, "Checkpoint abstraction '{}' does not match game abstraction '{}'", found, expected)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ImportError {}
+
 impl<G: Game> Clone for CFRSolver<G> {
     fn clone(&self) -> Self {
         Self {
@@ -949,7 +1885,12 @@ impl<G: Game> Clone for CFRSolver<G> {
             storage: self.storage.clone(),
             iteration: self.iteration,
             stats: self.stats.clone(),
-            rng: StdRng::from_entropy(), // Fresh RNG for clone
+            rng: if self.config.deterministic {
+                self.rng.clone()
+            } else {
+                ChaCha12Rng::from_entropy() // Fresh RNG for clone
+            },
+            regret_buffer: FxHashMap::default(),
             _phantom: PhantomData,
         }
     }
@@ -968,6 +1909,60 @@ pub struct ConvergenceStats {
     pub elapsed_seconds: f64,
     /// Current solve speed.
     pub iterations_per_second: f64,
+    /// Rough seconds until CI reaches the target, extrapolated from the
+    /// recent CI trend. `None` during warmup or while CI is not decreasing.
+    pub eta_seconds: Option<f64>,
+}
+
+/// Recent (elapsed, CI) measurements used to extrapolate an ETA.
+#[derive(Debug, Default)]
+struct CiTrend {
+    points: Vec<(f64, f64)>,
+}
+
+impl CiTrend {
+    /// Number of recent measurements the fit uses.
+    const WINDOW: usize = 5;
+
+    /// Record a CI measurement and estimate the seconds left until `target`.
+    ///
+    /// Fits `ln(ci)` against elapsed time by least squares over the last
+    /// [`Self::WINDOW`] points; CI tends to decay geometrically, so the
+    /// log-linear fit gives a usable straight-line extrapolation.
+    fn record(&mut self, elapsed: f64, ci: f64, target: f64) -> Option<f64> {
+        if !ci.is_finite() || ci <= 0.0 {
+            return None;
+        }
+        if ci <= target {
+            return Some(0.0);
+        }
+        self.points.push((elapsed, ci.ln()));
+        if self.points.len() > Self::WINDOW {
+            self.points.remove(0);
+        }
+        if self.points.len() < 2 || target <= 0.0 {
+            return None;
+        }
+
+        let n = self.points.len() as f64;
+        let mean_t = self.points.iter().map(|&(t, _)| t).sum::<f64>() / n;
+        let mean_y = self.points.iter().map(|&(_, y)| y).sum::<f64>() / n;
+        let (mut cov, mut var) = (0.0, 0.0);
+        for &(t, y) in &self.points {
+            cov += (t - mean_t) * (y - mean_y);
+            var += (t - mean_t) * (t - mean_t);
+        }
+        if var <= 0.0 {
+            return None;
+        }
+        let slope = cov / var;
+        if slope >= 0.0 {
+            return None;
+        }
+
+        let eta = (target.ln() - ci.ln()) / slope;
+        if eta.is_finite() { Some(eta.max(0.0)) } else { None }
+    }
 }
 
 /// Result of convergence-based training.
@@ -982,3 +1977,117 @@ pub struct ConvergenceResult {
     /// Total elapsed time in seconds.
     pub elapsed_seconds: f64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cfr::game::{Action, GameState, InfoState};
+
+    /// A game whose single player can pass forever.
+    #[derive(Clone)]
+    struct EndlessGame;
+
+    #[derive(Clone, Debug)]
+    struct EndlessState(usize);
+    impl GameState for EndlessState {}
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Pass;
+    impl Action for Pass {
+        fn to_string(&self) -> String {
+            "p".to_string()
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Root;
+    impl InfoState for Root {
+        fn key(&self) -> String {
+            "root".to_string()
+        }
+    }
+
+    impl Game for EndlessGame {
+        type State = EndlessState;
+        type Action = Pass;
+        type InfoState = Root;
+
+        fn initial_state(&self) -> EndlessState {
+            EndlessState(0)
+        }
+        fn is_terminal(&self, _state: &EndlessState) -> bool {
+            false
+        }
+        fn get_payoff(&self, _state: &EndlessState, _player: usize) -> f64 {
+            0.0
+        }
+        fn current_player(&self, _state: &EndlessState) -> Option<usize> {
+            Some(0)
+        }
+        fn num_players(&self) -> usize {
+            1
+        }
+        fn available_actions(&self, _state: &EndlessState) -> Vec<Pass> {
+            vec![Pass]
+        }
+        fn apply_action(&self, state: &EndlessState, _action: &Pass) -> EndlessState {
+            EndlessState(state.0 + 1)
+        }
+        fn info_state(&self, _state: &EndlessState) -> Root {
+            Root
+        }
+        fn max_depth(&self) -> Option<usize> {
+            Some(64)
+        }
+    }
+
+    /// A game that forgets to end: its only state is a decision node with no actions.
+    #[derive(Clone)]
+    struct StuckGame;
+
+    impl Game for StuckGame {
+        type State = EndlessState;
+        type Action = Pass;
+        type InfoState = Root;
+
+        fn initial_state(&self) -> EndlessState {
+            EndlessState(0)
+        }
+        fn is_terminal(&self, _state: &EndlessState) -> bool {
+            false
+        }
+        fn get_payoff(&self, _state: &EndlessState, _player: usize) -> f64 {
+            0.0
+        }
+        fn current_player(&self, _state: &EndlessState) -> Option<usize> {
+            Some(0)
+        }
+        fn num_players(&self) -> usize {
+            1
+        }
+        fn available_actions(&self, _state: &EndlessState) -> Vec<Pass> {
+            Vec::new()
+        }
+        fn apply_action(&self, state: &EndlessState, _action: &Pass) -> EndlessState {
+            state.clone()
+        }
+        fn info_state(&self, _state: &EndlessState) -> Root {
+            Root
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "non-terminal, non-chance state has no available actions")]
+    fn test_empty_actions_guard() {
+        let mut solver = CFRSolver::new(StuckGame, CFRConfig::default().with_seed(0));
+        solver.run_iteration();
+    }
+
+    #[test]
+    #[should_panic(expected = "traversal exceeded max depth 64")]
+    fn test_max_depth_guard() {
+        let mut solver = CFRSolver::new(EndlessGame, CFRConfig::default().with_seed(0));
+        solver.run_iteration();
+    }
+}
@@ -5,7 +5,40 @@
 
 use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
-use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::sync::{RwLock, RwLockReadGuard};
+
+use super::arena::RegretArena;
+use super::intern::KeyInterner;
+
+/// Index of the largest value, with ties broken towards the lowest index.
+///
+/// A later entry only wins if it is strictly greater, so `[0.5, 0.5]` always
+/// yields `Some(0)` regardless of how the values were produced. NaN entries
+/// are never selected. Returns `None` for an empty (or all-NaN) slice.
+pub fn argmax_with_tiebreak(values: &[f64]) -> Option<usize> {
+    let mut best: Option<(usize, f64)> = None;
+    for (i, &v) in values.iter().enumerate() {
+        if v.is_nan() {
+            continue;
+        }
+        match best {
+            Some((_, best_v)) if v <= best_v => {}
+            _ => best = Some((i, v)),
+        }
+    }
+    best.map(|(i, _)| i)
+}
+
+/// How [`RegretStorage`] lays out regrets and strategy sums in memory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum StorageLayout {
+    /// A hash map from info-set key to a separate `Vec<f64>` per info set.
+    #[default]
+    Map,
+    /// Two contiguous buffers (regrets, strategy sums) indexed by interned
+    /// info-set slot, for better cache locality during traversal.
+    Arena,
+}
 
 /// Thread-safe storage for regrets and strategy sums.
 ///
@@ -15,19 +48,48 @@ use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
 ///
 /// The storage uses interior mutability with `RwLock` to allow concurrent
 /// reads and exclusive writes, which is important for parallel CFR.
+///
+/// Regrets and strategy sums use the [`StorageLayout`] chosen at
+/// construction. With [`StorageLayout::Arena`] the per-id maps below stay
+/// empty and the arena holds the values instead.
+///
+/// Both layouts key regrets and strategy sums by a `u32` id that
+/// [`intern`](RegretStorage::intern) assigns to each info-set key, so the
+/// training loop hashes a key once per visit instead of once per table. The
+/// `&str` methods look the id up internally.
+///
+/// Methods that hold more than one lock at a time take them in field
+/// declaration order: `interner` first, `arena` last. With writers queued on
+/// a `RwLock`, two threads taking the same pair of locks in opposite orders
+/// can deadlock.
 #[derive(Debug)]
 pub struct RegretStorage {
-    /// Cumulative regrets: info_key -> [regret per action]
-    regrets: RwLock<FxHashMap<String, Vec<f64>>>,
+    /// Info-set key <-> id mapping shared by every id-keyed table
+    interner: RwLock<KeyInterner>,
+
+    /// Cumulative regrets: info-set id -> [regret per action]
+    regrets: RwLock<FxHashMap<u32, Vec<f64>>>,
 
-    /// Cumulative strategy sums: info_key -> [strategy weight per action]
-    strategy_sums: RwLock<FxHashMap<String, Vec<f64>>>,
+    /// Cumulative strategy sums: info-set id -> [strategy weight per action]
+    strategy_sums: RwLock<FxHashMap<u32, Vec<f64>>>,
 
-    /// Action counts for each info set (to verify consistency)
-    action_counts: RwLock<FxHashMap<String, usize>>,
+    /// Action and visit counts for each info set, updated with its regrets
+    info_set_counts: RwLock<FxHashMap<u32, InfoSetCounts>>,
 
     /// Action names for each info set: info_key -> [action name per action]
     action_names: RwLock<FxHashMap<String, Vec<String>>>,
+
+    /// Exponential moving average of the current strategy: info_key -> [probability per action]
+    ema_strategies: RwLock<FxHashMap<String, Vec<f64>>>,
+
+    /// Running totals of sampled action values: info_key -> sums and visit count
+    action_values: RwLock<FxHashMap<String, ActionValueSums>>,
+
+    /// First player, actions and state seen at each info set (collision checks only)
+    signatures: RwLock<FxHashMap<String, InfoSetSignature>>,
+
+    /// Regrets and strategy sums when using [`StorageLayout::Arena`]
+    arena: Option<RwLock<RegretArena>>,
 }
 
 impl Default for RegretStorage {
@@ -40,16 +102,22 @@ impl RegretStorage {
     /// Create new empty storage.
     pub fn new() -> Self {
         Self {
+            interner: RwLock::new(KeyInterner::default()),
             regrets: RwLock::new(FxHashMap::default()),
             strategy_sums: RwLock::new(FxHashMap::default()),
-            action_counts: RwLock::new(FxHashMap::default()),
+            info_set_counts: RwLock::new(FxHashMap::default()),
             action_names: RwLock::new(FxHashMap::default()),
+            ema_strategies: RwLock::new(FxHashMap::default()),
+            action_values: RwLock::new(FxHashMap::default()),
+            signatures: RwLock::new(FxHashMap::default()),
+            arena: None,
         }
     }
 
     /// Create storage with pre-allocated capacity.
     pub fn with_capacity(capacity: usize) -> Self {
         Self {
+            interner: RwLock::new(KeyInterner::with_capacity(capacity)),
             regrets: RwLock::new(FxHashMap::with_capacity_and_hasher(
                 capacity,
                 Default::default(),
@@ -58,7 +126,7 @@ impl RegretStorage {
                 capacity,
                 Default::default(),
             )),
-            action_counts: RwLock::new(FxHashMap::with_capacity_and_hasher(
+            info_set_counts: RwLock::new(FxHashMap::with_capacity_and_hasher(
                 capacity,
                 Default::default(),
             )),
@@ -66,6 +134,31 @@ impl RegretStorage {
                 capacity,
                 Default::default(),
             )),
+            ema_strategies: RwLock::new(FxHashMap::default()),
+            action_values: RwLock::new(FxHashMap::default()),
+            signatures: RwLock::new(FxHashMap::default()),
+            arena: None,
+        }
+    }
+
+    /// Create storage with the given layout and pre-allocated capacity.
+    pub fn with_layout(layout: StorageLayout, capacity: usize) -> Self {
+        match layout {
+            StorageLayout::Map => Self::with_capacity(capacity),
+            StorageLayout::Arena => Self {
+                interner: RwLock::new(KeyInterner::with_capacity(capacity)),
+                arena: Some(RwLock::new(RegretArena::with_capacity(capacity))),
+                ..Self::new()
+            },
+        }
+    }
+
+    /// Memory layout of regrets and strategy sums.
+    pub fn layout(&self) -> StorageLayout {
+        if self.arena.is_some() {
+            StorageLayout::Arena
+        } else {
+            StorageLayout::Map
         }
     }
 
@@ -76,31 +169,55 @@ impl RegretStorage {
     ///
     /// # Arguments
     /// * `info_key` - The information set key
-    /// * `num_actions` - Number of available actions
+    /// * `num_actions` - Number of available actions, used only for info sets
+    ///   without stored regrets
     ///
     /// # Returns
     /// A vector of action probabilities summing to 1.0
     pub fn get_current_strategy(&self, info_key: &str, num_actions: usize) -> Vec<f64> {
+        match self.lookup(info_key) {
+            Some(id) => self.get_current_strategy_by_id(id, num_actions),
+            None => regret_matching(None, num_actions),
+        }
+    }
+
+    /// [`get_current_strategy`](Self::get_current_strategy) for an interned id.
+    pub fn get_current_strategy_by_id(&self, id: u32, num_actions: usize) -> Vec<f64> {
+        if let Some(arena) = &self.arena {
+            return regret_matching(arena.read().unwrap().regrets(id), num_actions);
+        }
+
         let regrets = self.regrets.read().unwrap();
+        regret_matching(regrets.get(&id).map(|r| r.as_slice()), num_actions)
+    }
 
-        match regrets.get(info_key) {
-            Some(r) => {
-                // Regret matching: strategy proportional to positive regrets
-                let positive: Vec<f64> = r.iter().map(|&x| x.max(0.0)).collect();
-                let sum: f64 = positive.iter().sum();
-
-                if sum > 0.0 {
-                    positive.iter().map(|&x| x / sum).collect()
-                } else {
-                    // Uniform if no positive regrets
-                    vec![1.0 / num_actions as f64; num_actions]
-                }
-            }
-            None => {
-                // Uniform for unseen info sets
-                vec![1.0 / num_actions as f64; num_actions]
-            }
+    /// Cumulative regrets stored for an interned id, if any.
+    pub fn get_regrets_by_id(&self, id: u32) -> Option<Vec<f64>> {
+        if let Some(arena) = &self.arena {
+            return arena.read().unwrap().regrets(id).map(<[f64]>::to_vec);
         }
+        self.regrets.read().unwrap().get(&id).cloned()
+    }
+
+    /// Id of `info_key`, assigning a new one the first time a key is seen.
+    ///
+    /// Ids are dense, start at 0 and stay valid until [`clear`](Self::clear).
+    pub fn intern(&self, info_key: &str) -> u32 {
+        if let Some(id) = self.lookup(info_key) {
+            return id;
+        }
+        self.interner.write().unwrap().intern(info_key)
+    }
+
+    /// Id of `info_key`, if it has been interned.
+    pub fn lookup(&self, info_key: &str) -> Option<u32> {
+        self.interner.read().unwrap().get(info_key)
+    }
+
+    /// Info-set key interned as `id`, if any.
+    pub fn key_of(&self, id: u32) -> Option<String> {
+        let interner = self.interner.read().unwrap();
+        (id < interner.len()).then(|| interner.key(id).to_string())
     }
 
     /// Get average strategy for an info set (Nash equilibrium approximation).
@@ -110,25 +227,121 @@ impl RegretStorage {
     ///
     /// # Arguments
     /// * `info_key` - The information set key
-    /// * `num_actions` - Number of available actions
+    /// * `num_actions` - Number of available actions, used only for info sets
+    ///   never updated (see [`RegretStorage::num_actions_for`])
     ///
     /// # Returns
     /// A vector of action probabilities summing to 1.0
     pub fn get_average_strategy(&self, info_key: &str, num_actions: usize) -> Vec<f64> {
+        let Some(id) = self.lookup(info_key) else {
+            return normalize_sums(None, num_actions);
+        };
+        let num_actions = self.num_actions_for_id(id).unwrap_or(num_actions);
+        if let Some(arena) = &self.arena {
+            return normalize_sums(arena.read().unwrap().strategy_sums(id), num_actions);
+        }
+
         let strategy_sums = self.strategy_sums.read().unwrap();
+        normalize_sums(strategy_sums.get(&id).map(|s| s.as_slice()), num_actions)
+    }
 
-        match strategy_sums.get(info_key) {
-            Some(sums) => {
-                let total: f64 = sums.iter().sum();
-                if total > 0.0 {
-                    sums.iter().map(|&x| x / total).collect()
-                } else {
-                    vec![1.0 / num_actions as f64; num_actions]
-                }
+    /// Get the exponential moving average of the current strategy for an info set.
+    ///
+    /// Only maintained when `CFRConfig::strategy_ema` is set; info sets
+    /// without an EMA get a uniform strategy over their stored action count,
+    /// or `num_actions` if they have never been updated.
+    pub fn get_ema_strategy(&self, info_key: &str, num_actions: usize) -> Vec<f64> {
+        if let Some(ema) = self.ema_strategies.read().unwrap().get(info_key) {
+            return ema.clone();
+        }
+
+        let num_actions = self.num_actions_for(info_key).unwrap_or(num_actions);
+        vec![1.0 / num_actions as f64; num_actions]
+    }
+
+    /// Get the purified (pure) average strategy for an info set.
+    ///
+    /// All probability goes to the most likely action; ties go to the lowest
+    /// action index (see [`argmax_with_tiebreak`]), so the result is
+    /// reproducible even when the average is still uniform.
+    pub fn get_purified_strategy(&self, info_key: &str, num_actions: usize) -> Vec<f64> {
+        let average = self.get_average_strategy(info_key, num_actions);
+        let mut pure = vec![0.0; average.len()];
+        if let Some(best) = argmax_with_tiebreak(&average) {
+            pure[best] = 1.0;
+        }
+        pure
+    }
+
+    /// The `n` most likely actions of the average strategy, most likely first.
+    ///
+    /// Returns `(action index, probability)` pairs; ties go to the lower
+    /// index as in [`argmax_with_tiebreak`]. Unvisited info sets yield an
+    /// empty vector since their action count is unknown.
+    pub fn top_actions(&self, info_key: &str, n: usize) -> Vec<(usize, f64)> {
+        match self.num_actions_for(info_key) {
+            Some(num_actions) => top_n(&self.get_average_strategy(info_key, num_actions), n),
+            None => Vec::new(),
+        }
+    }
+
+    /// Number of actions stored for an info set, or `None` if it has never
+    /// been updated.
+    pub fn num_actions_for(&self, info_key: &str) -> Option<usize> {
+        self.num_actions_for_id(self.lookup(info_key)?)
+    }
+
+    fn num_actions_for_id(&self, id: u32) -> Option<usize> {
+        if let Some(arena) = &self.arena {
+            let arena = arena.read().unwrap();
+            return arena.strategy_sums(id).or_else(|| arena.regrets(id)).map(<[f64]>::len);
+        }
+
+        if let Some(counts) = self.info_set_counts.read().unwrap().get(&id) {
+            return Some(counts.actions);
+        }
+        self.strategy_sums.read().unwrap().get(&id).map(Vec::len)
+    }
+
+    /// Visit the average strategy of every info set without cloning.
+    ///
+    /// The normalized strategy is written into a single scratch buffer that is
+    /// reused across info sets, so streaming exporters don't allocate a vector
+    /// per entry. The storage tables and the key interner are read-locked for
+    /// the whole walk; the callback must not call back into methods that take
+    /// a write lock.
+    pub fn for_each_average_strategy<F>(&self, mut f: F)
+    where
+        F: FnMut(&str, &[f64]),
+    {
+        let mut buffer: Vec<f64> = Vec::new();
+        let mut visit = |key: &str, num_actions: usize, sums: Option<&[f64]>| {
+            buffer.clear();
+
+            let sums = sums.unwrap_or(&[]);
+            let total: f64 = sums.iter().sum();
+            if total > 0.0 {
+                buffer.extend(sums.iter().map(|&x| x / total));
+            } else {
+                buffer.resize(num_actions, 1.0 / num_actions as f64);
             }
-            None => {
-                vec![1.0 / num_actions as f64; num_actions]
+
+            f(key, &buffer);
+        };
+
+        let interner = self.interner.read().unwrap();
+        if let Some(arena) = &self.arena {
+            let arena = arena.read().unwrap();
+            for (id, regret) in arena.iter_regrets() {
+                visit(interner.key(id), regret.len(), arena.strategy_sums(id));
             }
+            return;
+        }
+
+        let regrets = self.regrets.read().unwrap();
+        let strategy_sums = self.strategy_sums.read().unwrap();
+        for (&id, regret) in regrets.iter() {
+            visit(interner.key(id), regret.len(), strategy_sums.get(&id).map(|s| s.as_slice()));
         }
     }
 
@@ -139,35 +352,72 @@ impl RegretStorage {
     /// * `regret_updates` - Regret delta for each action (action_value - node_value)
     /// * `use_cfr_plus` - If true, floor negative regrets to 0
     pub fn update_regrets(&self, info_key: &str, regret_updates: &[f64], use_cfr_plus: bool) {
+        self.update_regrets_capped(info_key, regret_updates, use_cfr_plus, None);
+    }
+
+    /// Update regrets for an info set, clamping each action's cumulative
+    /// regret to `[-cap, cap]` after the CFR+ floor when `cap` is set.
+    pub fn update_regrets_capped(
+        &self,
+        info_key: &str,
+        regret_updates: &[f64],
+        use_cfr_plus: bool,
+        cap: Option<f64>,
+    ) {
+        self.update_regrets_by_id(self.intern(info_key), regret_updates, use_cfr_plus, cap);
+    }
+
+    /// [`update_regrets_capped`](Self::update_regrets_capped) for an interned id.
+    ///
+    /// The visit count is kept next to the regrets, so an update takes no
+    /// lock beyond the one guarding them.
+    pub fn update_regrets_by_id(&self, id: u32, regret_updates: &[f64], use_cfr_plus: bool, cap: Option<f64>) {
+        if let Some(arena) = &self.arena {
+            let mut arena = arena.write().unwrap();
+            apply_regret_updates(arena.regrets_mut(id, regret_updates.len()), regret_updates, use_cfr_plus, cap);
+            arena.record_visit(id);
+            return;
+        }
+
         let mut regrets = self.regrets.write().unwrap();
-        let mut action_counts = self.action_counts.write().unwrap();
+        let mut info_set_counts = self.info_set_counts.write().unwrap();
 
         let num_actions = regret_updates.len();
 
         // Initialize or get existing regrets
-        let entry = regrets
-            .entry(info_key.to_string())
-            .or_insert_with(|| vec![0.0; num_actions]);
+        let entry = regrets.entry(id).or_insert_with(|| vec![0.0; num_actions]);
 
         // Verify action count consistency
-        if let Some(&stored_count) = action_counts.get(info_key) {
-            debug_assert_eq!(
-                stored_count, num_actions,
-                "Action count mismatch for info set {}",
-                info_key
-            );
-        } else {
-            action_counts.insert(info_key.to_string(), num_actions);
-        }
+        let counts = info_set_counts.entry(id).or_insert(InfoSetCounts { actions: num_actions, visits: 0 });
+        debug_assert_eq!(counts.actions, num_actions, "Action count mismatch for info set #{}", id);
+        counts.visits += 1;
 
-        // Update regrets
-        for (i, &update) in regret_updates.iter().enumerate() {
-            entry[i] += update;
+        apply_regret_updates(entry, regret_updates, use_cfr_plus, cap);
+    }
 
-            // CFR+: floor negative regrets to 0
-            if use_cfr_plus && entry[i] < 0.0 {
-                entry[i] = 0.0;
-            }
+    /// Number of regret updates applied to an info set so far.
+    ///
+    /// Each MCCFR traversal that reaches the info set as the traverser
+    /// counts once, so a low count flags an under-sampled info set. Returns
+    /// 0 for unknown keys.
+    pub fn visit_count(&self, info_key: &str) -> u64 {
+        self.lookup(info_key).and_then(|id| self.visit_count_by_id(id)).unwrap_or(0)
+    }
+
+    /// Smallest [`visit_count`](Self::visit_count) over all info sets with
+    /// regrets, or 0 if there are none.
+    pub fn min_visit_count(&self) -> u64 {
+        let min = match &self.arena {
+            Some(arena) => arena.read().unwrap().iter_visit_counts().map(|(_, count)| count).min(),
+            None => self.info_set_counts.read().unwrap().values().map(|counts| counts.visits).min(),
+        };
+        min.unwrap_or(0)
+    }
+
+    fn visit_count_by_id(&self, id: u32) -> Option<u64> {
+        match &self.arena {
+            Some(arena) => arena.read().unwrap().visit_count(id),
+            None => self.info_set_counts.read().unwrap().get(&id).map(|counts| counts.visits),
         }
     }
 
@@ -178,17 +428,113 @@ impl RegretStorage {
     /// * `strategy` - Current strategy for each action
     /// * `weight` - Weight to apply (typically reach probability * iteration weight)
     pub fn update_strategy_sum(&self, info_key: &str, strategy: &[f64], weight: f64) {
-        let mut strategy_sums = self.strategy_sums.write().unwrap();
+        self.update_strategy_sum_by_id(self.intern(info_key), strategy, weight);
+    }
 
+    /// [`update_strategy_sum`](Self::update_strategy_sum) for an interned id.
+    pub fn update_strategy_sum_by_id(&self, id: u32, strategy: &[f64], weight: f64) {
         let num_actions = strategy.len();
+        let add = |entry: &mut [f64]| {
+            for (i, &prob) in strategy.iter().enumerate() {
+                entry[i] += prob * weight;
+            }
+        };
 
-        let entry = strategy_sums
+        if let Some(arena) = &self.arena {
+            add(arena.write().unwrap().strategy_sums_mut(id, num_actions));
+            return;
+        }
+
+        let mut strategy_sums = self.strategy_sums.write().unwrap();
+        add(strategy_sums.entry(id).or_insert_with(|| vec![0.0; num_actions]));
+    }
+
+    /// Move the strategy EMA for an info set towards `strategy`.
+    ///
+    /// The first update seeds the EMA with `strategy`; later updates apply
+    /// `ema = (1 - alpha) * ema + alpha * strategy`.
+    ///
+    /// # Arguments
+    /// * `info_key` - The information set key
+    /// * `strategy` - Current strategy for each action
+    /// * `alpha` - Smoothing factor in (0, 1]
+    pub fn update_ema_strategy(&self, info_key: &str, strategy: &[f64], alpha: f64) {
+        let mut ema_strategies = self.ema_strategies.write().unwrap();
+
+        match ema_strategies.get_mut(info_key) {
+            Some(ema) => {
+                for (e, &prob) in ema.iter_mut().zip(strategy.iter()) {
+                    *e += alpha * (prob - *e);
+                }
+            }
+            None => {
+                ema_strategies.insert(info_key.to_string(), strategy.to_vec());
+            }
+        }
+    }
+
+    /// Add one traversal's counterfactual action values to an info set's totals.
+    pub fn update_action_values(&self, info_key: &str, values: &[f64]) {
+        let mut action_values = self.action_values.write().unwrap();
+        let entry = action_values
             .entry(info_key.to_string())
-            .or_insert_with(|| vec![0.0; num_actions]);
+            .or_insert_with(|| ActionValueSums {
+                sums: vec![0.0; values.len()],
+                visits: 0,
+            });
+        for (sum, &value) in entry.sums.iter_mut().zip(values) {
+            *sum += value;
+        }
+        entry.visits += 1;
+    }
 
-        for (i, &prob) in strategy.iter().enumerate() {
-            entry[i] += prob * weight;
+    /// Average counterfactual value of each action at an info set.
+    ///
+    /// Only tracked when `CFRConfig::track_action_values` is set; `None` if
+    /// the info set has no recorded values.
+    pub fn get_action_values(&self, info_key: &str) -> Option<Vec<f64>> {
+        let action_values = self.action_values.read().unwrap();
+        let entry = action_values.get(info_key)?;
+        let visits = entry.visits.max(1) as f64;
+        Some(entry.sums.iter().map(|&sum| sum / visits).collect())
+    }
+
+    /// Check a visited state against the first state seen at its info set.
+    ///
+    /// The first visit records `player`, `actions` and `describe_state()`;
+    /// later visits must match both, otherwise this panics with the two state
+    /// descriptions. Used when `CFRConfig::check_info_set_collisions` is set.
+    pub fn check_info_set_signature(
+        &self,
+        info_key: &str,
+        player: usize,
+        actions: &[String],
+        describe_state: impl FnOnce() -> String,
+    ) {
+        if let Some(first) = self.signatures.read().unwrap().get(info_key) {
+            assert!(
+                first.player == player && first.actions == actions,
+                "info-set key collision at {}\nfirst seen: player {} with actions {:?}\n{}\nnow: player {} with actions {:?}\n{}",
+                info_key,
+                first.player,
+                first.actions,
+                first.state,
+                player,
+                actions,
+                describe_state()
+            );
+            return;
         }
+
+        self.signatures
+            .write()
+            .unwrap()
+            .entry(info_key.to_string())
+            .or_insert_with(|| InfoSetSignature {
+                player,
+                actions: actions.to_vec(),
+                state: describe_state(),
+            });
     }
 
     /// Store action names for an info set (only stores if not already present).
@@ -201,6 +547,13 @@ impl RegretStorage {
         action_names.entry(info_key.to_string()).or_insert(names);
     }
 
+    /// Whether action names are stored for an info set.
+    ///
+    /// Lets callers skip building the names on every visit.
+    pub fn has_action_names(&self, info_key: &str) -> bool {
+        self.action_names.read().unwrap().contains_key(info_key)
+    }
+
     /// Get action names for an info set.
     ///
     /// # Arguments
@@ -223,6 +576,11 @@ impl RegretStorage {
     /// # Arguments
     /// * `discount` - Discount factor (0 to 1)
     pub fn discount_regrets(&self, discount: f64) {
+        if let Some(arena) = &self.arena {
+            arena.write().unwrap().discount_regrets(discount);
+            return;
+        }
+
         let mut regrets = self.regrets.write().unwrap();
 
         for values in regrets.values_mut() {
@@ -232,11 +590,33 @@ impl RegretStorage {
         }
     }
 
+    /// Discount regrets by sign: positive regrets are multiplied by
+    /// `positive` and negative ones by `negative` (for DCFR).
+    pub fn discount_regrets_signed(&self, positive: f64, negative: f64) {
+        if let Some(arena) = &self.arena {
+            arena.write().unwrap().discount_regrets_signed(positive, negative);
+            return;
+        }
+
+        let mut regrets = self.regrets.write().unwrap();
+
+        for values in regrets.values_mut() {
+            for v in values.iter_mut() {
+                *v *= if *v > 0.0 { positive } else { negative };
+            }
+        }
+    }
+
     /// Apply discount to all strategy sums (for Discounted CFR).
     ///
     /// # Arguments
     /// * `discount` - Discount factor (0 to 1)
     pub fn discount_strategy_sums(&self, discount: f64) {
+        if let Some(arena) = &self.arena {
+            arena.write().unwrap().discount_strategy_sums(discount);
+            return;
+        }
+
         let mut strategy_sums = self.strategy_sums.write().unwrap();
 
         for values in strategy_sums.values_mut() {
@@ -248,84 +628,154 @@ impl RegretStorage {
 
     /// Get the number of information sets stored.
     pub fn num_info_sets(&self) -> usize {
+        if let Some(arena) = &self.arena {
+            return arena.read().unwrap().len();
+        }
         self.regrets.read().unwrap().len()
     }
 
-    /// Check if an info set exists in storage.
-    pub fn contains(&self, info_key: &str) -> bool {
-        self.regrets.read().unwrap().contains_key(info_key)
+    /// Number of info sets the storage can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        if let Some(arena) = &self.arena {
+            return arena.read().unwrap().capacity();
+        }
+        self.regrets.read().unwrap().capacity()
     }
 
-    /// Get read access to regrets (for analysis/export).
-    pub fn regrets(&self) -> RwLockReadGuard<'_, FxHashMap<String, Vec<f64>>> {
-        self.regrets.read().unwrap()
+    /// Check if an info set exists in storage.
+    pub fn contains(&self, info_key: &str) -> bool {
+        let Some(id) = self.lookup(info_key) else {
+            return false;
+        };
+        if let Some(arena) = &self.arena {
+            return arena.read().unwrap().regrets(id).is_some();
+        }
+        self.regrets.read().unwrap().contains_key(&id)
     }
 
-    /// Get read access to strategy sums (for analysis/export).
-    pub fn strategy_sums(&self) -> RwLockReadGuard<'_, FxHashMap<String, Vec<f64>>> {
-        self.strategy_sums.read().unwrap()
+    /// Keys of every info set with regrets.
+    pub fn info_set_keys(&self) -> Vec<String> {
+        self.regrets().into_keys().collect()
     }
 
-    /// Get mutable access to regrets (for loading checkpoints).
-    pub fn regrets_mut(&self) -> RwLockWriteGuard<'_, FxHashMap<String, Vec<f64>>> {
-        self.regrets.write().unwrap()
+    /// Copy of the regrets, keyed by info-set key (for analysis/export).
+    pub fn regrets(&self) -> FxHashMap<String, Vec<f64>> {
+        let interner = self.interner.read().unwrap();
+        let by_key = |(id, values): (u32, &[f64])| (interner.key(id).to_string(), values.to_vec());
+        match &self.arena {
+            Some(arena) => arena.read().unwrap().iter_regrets().map(by_key).collect(),
+            None => self.regrets.read().unwrap().iter().map(|(&id, v)| by_key((id, v))).collect(),
+        }
     }
 
-    /// Get mutable access to strategy sums (for loading checkpoints).
-    pub fn strategy_sums_mut(&self) -> RwLockWriteGuard<'_, FxHashMap<String, Vec<f64>>> {
-        self.strategy_sums.write().unwrap()
+    /// Copy of the strategy sums, keyed by info-set key (for analysis/export).
+    pub fn strategy_sums(&self) -> FxHashMap<String, Vec<f64>> {
+        let interner = self.interner.read().unwrap();
+        let by_key = |(id, values): (u32, &[f64])| (interner.key(id).to_string(), values.to_vec());
+        match &self.arena {
+            Some(arena) => arena.read().unwrap().iter_strategy_sums().map(by_key).collect(),
+            None => self.strategy_sums.read().unwrap().iter().map(|(&id, v)| by_key((id, v))).collect(),
+        }
     }
 
-    /// Clear all stored data.
+    /// Clear all stored data, including interned ids.
     pub fn clear(&self) {
+        if let Some(arena) = &self.arena {
+            *arena.write().unwrap() = RegretArena::with_capacity(0);
+        }
+        *self.interner.write().unwrap() = KeyInterner::default();
         self.regrets.write().unwrap().clear();
         self.strategy_sums.write().unwrap().clear();
-        self.action_counts.write().unwrap().clear();
+        self.info_set_counts.write().unwrap().clear();
         self.action_names.write().unwrap().clear();
+        self.ema_strategies.write().unwrap().clear();
+        self.action_values.write().unwrap().clear();
+        self.signatures.write().unwrap().clear();
     }
 
     /// Get total memory usage estimate in bytes.
     pub fn memory_usage(&self) -> usize {
+        let key_size = self.interner.read().unwrap().key_bytes();
+        if let Some(arena) = &self.arena {
+            return key_size + arena.read().unwrap().memory_usage();
+        }
+
         let regrets = self.regrets.read().unwrap();
         let strategy_sums = self.strategy_sums.read().unwrap();
 
-        let regret_size: usize = regrets
-            .iter()
-            .map(|(k, v)| k.len() + v.len() * std::mem::size_of::<f64>())
-            .sum();
-
-        let strategy_size: usize = strategy_sums
-            .iter()
-            .map(|(k, v)| k.len() + v.len() * std::mem::size_of::<f64>())
-            .sum();
-
-        regret_size + strategy_size
+        let values: usize = regrets.values().chain(strategy_sums.values()).map(Vec::len).sum();
+        key_size + values * std::mem::size_of::<f64>()
     }
 
     /// Export storage to serializable format.
     pub fn export(&self) -> StorageExport {
         StorageExport {
-            regrets: self.regrets.read().unwrap().clone(),
-            strategy_sums: self.strategy_sums.read().unwrap().clone(),
+            regrets: self.regrets(),
+            strategy_sums: self.strategy_sums(),
             action_names: self.action_names.read().unwrap().clone(),
+            ema_strategies: self.ema_strategies.read().unwrap().clone(),
+            action_values: self.action_values.read().unwrap().clone(),
+            visit_counts: {
+                let interner = self.interner.read().unwrap();
+                let by_key = |(id, count): (u32, u64)| (interner.key(id).to_string(), count);
+                match &self.arena {
+                    Some(arena) => arena.read().unwrap().iter_visit_counts().map(by_key).collect(),
+                    None => {
+                        let info_set_counts = self.info_set_counts.read().unwrap();
+                        info_set_counts.iter().map(|(&id, counts)| by_key((id, counts.visits))).collect()
+                    }
+                }
+            },
         }
     }
 
     /// Import storage from serialized format.
     pub fn import(&self, data: StorageExport) {
-        *self.regrets.write().unwrap() = data.regrets;
-        *self.strategy_sums.write().unwrap() = data.strategy_sums;
+        let by_id = |map: FxHashMap<String, Vec<f64>>| -> FxHashMap<u32, Vec<f64>> {
+            map.into_iter().map(|(key, values)| (self.intern(&key), values)).collect()
+        };
+        let regrets = by_id(data.regrets);
+        let strategy_sums = by_id(data.strategy_sums);
         *self.action_names.write().unwrap() = data.action_names;
+        *self.ema_strategies.write().unwrap() = data.ema_strategies;
+        *self.action_values.write().unwrap() = data.action_values;
+        let visit_counts: FxHashMap<u32, u64> =
+            data.visit_counts.into_iter().map(|(key, count)| (self.intern(&key), count)).collect();
+
+        if let Some(arena) = &self.arena {
+            let mut rebuilt = RegretArena::from_entries(
+                regrets.iter().map(|(&id, v)| (id, v.as_slice())),
+                strategy_sums.iter().map(|(&id, v)| (id, v.as_slice())),
+            );
+            for (&id, &count) in &visit_counts {
+                rebuilt.set_visit_count(id, count);
+            }
+            *arena.write().unwrap() = rebuilt;
+            return;
+        }
 
         // Rebuild action counts
-        let mut action_counts = self.action_counts.write().unwrap();
-        action_counts.clear();
-        for (key, values) in self.regrets.read().unwrap().iter() {
-            action_counts.insert(key.clone(), values.len());
-        }
+        *self.info_set_counts.write().unwrap() = regrets
+            .iter()
+            .map(|(&id, values)| {
+                let visits = visit_counts.get(&id).copied().unwrap_or(0);
+                (id, InfoSetCounts { actions: values.len(), visits })
+            })
+            .collect();
+        *self.regrets.write().unwrap() = regrets;
+        *self.strategy_sums.write().unwrap() = strategy_sums;
     }
 }
 
+/// Map-layout bookkeeping for one info set, stored beside its regrets.
+#[derive(Debug, Clone, Copy)]
+struct InfoSetCounts {
+    /// Number of actions, to verify consistency across updates
+    actions: usize,
+    /// Regret updates applied so far
+    visits: u64,
+}
+
 /// Serializable export format for storage.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StorageExport {
@@ -336,6 +786,33 @@ pub struct StorageExport {
     /// Action names for each info set
     #[serde(default)]
     pub action_names: FxHashMap<String, Vec<String>>,
+    /// Strategy EMAs for each info set
+    #[serde(default)]
+    pub ema_strategies: FxHashMap<String, Vec<f64>>,
+    /// Action value totals for each info set
+    #[serde(default)]
+    pub action_values: FxHashMap<String, ActionValueSums>,
+    /// Regret updates applied to each info set
+    #[serde(default)]
+    pub visit_counts: FxHashMap<String, u64>,
+}
+
+/// Acting player and legal actions first seen at an info set.
+#[derive(Debug, Clone)]
+struct InfoSetSignature {
+    player: usize,
+    actions: Vec<String>,
+    /// Description of the state that first reached the info set
+    state: String,
+}
+
+/// Running totals behind [`RegretStorage::get_action_values`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ActionValueSums {
+    /// Sum of sampled counterfactual values per action
+    pub sums: Vec<f64>,
+    /// Number of traversals summed
+    pub visits: u64,
 }
 
 /// Snapshot of average strategies for CI calculation.
@@ -353,27 +830,46 @@ impl RegretStorage {
     ///
     /// Used for calculating Convergence Indicator (CI).
     pub fn snapshot_strategies(&self) -> StrategySnapshot {
-        let strategy_sums = self.strategy_sums.read().unwrap();
-        let action_counts = self.action_counts.read().unwrap();
-
         let mut strategies = FxHashMap::default();
         let mut totals = FxHashMap::default();
 
-        for (key, sums) in strategy_sums.iter() {
-            let num_actions = action_counts.get(key).copied().unwrap_or(sums.len());
-            let total: f64 = sums.iter().sum();
+        self.for_each_strategy_sum(|key, sums| {
+            strategies.insert(key.to_string(), normalize_sums(Some(sums), sums.len()));
+            totals.insert(key.to_string(), sums.iter().sum());
+        });
 
-            let avg_strategy = if total > 0.0 {
-                sums.iter().map(|&x| x / total).collect()
-            } else {
-                vec![1.0 / num_actions as f64; num_actions]
-            };
+        StrategySnapshot { strategies, totals }
+    }
 
-            strategies.insert(key.clone(), avg_strategy);
-            totals.insert(key.clone(), total);
+    /// Restart regret matching from the current average strategy.
+    ///
+    /// Zeros every regret, so the next iterations start from uniform current
+    /// strategies, and multiplies the strategy sums by `strategy_scale`
+    /// (1.0 keeps them, smaller values let later iterations move the average
+    /// faster). The average strategy itself is unchanged. Returns it as a
+    /// snapshot taken just before the reset.
+    pub fn merge_into_average(&self, strategy_scale: f64) -> StrategySnapshot {
+        let baseline = self.snapshot_strategies();
+        self.discount_regrets(0.0);
+        if strategy_scale != 1.0 {
+            self.discount_strategy_sums(strategy_scale);
         }
+        baseline
+    }
 
-        StrategySnapshot { strategies, totals }
+    /// Visit the raw strategy sums of every info set that has them.
+    fn for_each_strategy_sum<F: FnMut(&str, &[f64])>(&self, mut f: F) {
+        let interner = self.interner.read().unwrap();
+        if let Some(arena) = &self.arena {
+            for (id, sums) in arena.read().unwrap().iter_strategy_sums() {
+                f(interner.key(id), sums);
+            }
+            return;
+        }
+
+        for (&id, sums) in self.strategy_sums.read().unwrap().iter() {
+            f(interner.key(id), sums);
+        }
     }
 
     /// Calculate Convergence Indicator (CI) by comparing current strategies to a snapshot.
@@ -396,14 +892,11 @@ impl RegretStorage {
     /// # Returns
     /// The CI value (lower is better)
     pub fn calculate_ci(&self, snapshot: &StrategySnapshot) -> f64 {
-        let strategy_sums = self.strategy_sums.read().unwrap();
-        let action_counts = self.action_counts.read().unwrap();
-
         let mut total_change = 0.0;
         let mut num_info_sets = 0;
 
-        for (key, sums) in strategy_sums.iter() {
-            let num_actions = action_counts.get(key).copied().unwrap_or(sums.len());
+        self.for_each_strategy_sum(|key, sums| {
+            let num_actions = sums.len();
             let current_total: f64 = sums.iter().sum();
 
             // Get old total from snapshot (0 if not present)
@@ -412,7 +905,7 @@ impl RegretStorage {
             // Only count this info set if it was visited in at least one snapshot
             // This avoids counting uniform vs uniform comparisons (both unvisited)
             if current_total == 0.0 && old_total == 0.0 {
-                continue;
+                return;
             }
 
             let new_strategy: Vec<f64> = if current_total > 0.0 {
@@ -442,7 +935,7 @@ impl RegretStorage {
                 total_change += change;
                 num_info_sets += 1;
             }
-        }
+        });
 
         if num_info_sets == 0 {
             return f64::INFINITY; // No comparison possible
@@ -478,21 +971,18 @@ impl RegretStorage {
             return f64::INFINITY;
         }
 
-        let regrets = self.regrets.read().unwrap();
-
-        if regrets.is_empty() {
-            return f64::INFINITY;
-        }
-
         let mut total_positive_regret = 0.0;
         let mut num_info_sets = 0;
 
-        for (_key, regret_vec) in regrets.iter() {
-            // Sum of positive regrets for this info set
-            // In CFR, exploitability is bounded by average positive regret
-            let positive_regret: f64 = regret_vec.iter().map(|&r| r.max(0.0)).sum();
-            total_positive_regret += positive_regret;
+        // Sum of positive regrets for each info set
+        // In CFR, exploitability is bounded by average positive regret
+        let mut add = |regret_vec: &[f64]| {
+            total_positive_regret += regret_vec.iter().map(|&r| r.max(0.0)).sum::<f64>();
             num_info_sets += 1;
+        };
+        match &self.arena {
+            Some(arena) => arena.read().unwrap().iter_regrets().for_each(|(_, r)| add(r)),
+            None => self.regrets.read().unwrap().values().for_each(|r| add(r)),
         }
 
         if num_info_sets == 0 {
@@ -519,10 +1009,74 @@ impl RegretStorage {
 impl Clone for RegretStorage {
     fn clone(&self) -> Self {
         Self {
+            interner: RwLock::new(self.interner.read().unwrap().clone()),
             regrets: RwLock::new(self.regrets.read().unwrap().clone()),
             strategy_sums: RwLock::new(self.strategy_sums.read().unwrap().clone()),
-            action_counts: RwLock::new(self.action_counts.read().unwrap().clone()),
+            info_set_counts: RwLock::new(self.info_set_counts.read().unwrap().clone()),
             action_names: RwLock::new(self.action_names.read().unwrap().clone()),
+            ema_strategies: RwLock::new(self.ema_strategies.read().unwrap().clone()),
+            action_values: RwLock::new(self.action_values.read().unwrap().clone()),
+            signatures: RwLock::new(self.signatures.read().unwrap().clone()),
+            arena: self.arena.as_ref().map(|arena| RwLock::new(arena.read().unwrap().clone())),
+        }
+    }
+}
+
+/// Regret matching: probabilities proportional to positive regrets, uniform
+/// if none are positive or the info set is unseen.
+fn regret_matching(regrets: Option<&[f64]>, num_actions: usize) -> Vec<f64> {
+    let mut num_actions = num_actions;
+    if let Some(r) = regrets {
+        let sum: f64 = r.iter().map(|&x| x.max(0.0)).sum();
+        if sum > 0.0 {
+            return r.iter().map(|&x| x.max(0.0) / sum).collect();
+        }
+        num_actions = r.len();
+    }
+    vec![1.0 / num_actions as f64; num_actions]
+}
+
+/// Normalize strategy sums into an average strategy, uniform if empty or unseen.
+fn normalize_sums(sums: Option<&[f64]>, num_actions: usize) -> Vec<f64> {
+    if let Some(sums) = sums {
+        let total: f64 = sums.iter().sum();
+        if total > 0.0 {
+            return sums.iter().map(|&x| x / total).collect();
+        }
+    }
+    vec![1.0 / num_actions as f64; num_actions]
+}
+
+/// The `n` largest `(index, value)` pairs, largest first, ties to the lower index.
+///
+/// Uses a partial selection so only the kept prefix is fully sorted.
+fn top_n(values: &[f64], n: usize) -> Vec<(usize, f64)> {
+    let by_value_desc = |a: &(usize, f64), b: &(usize, f64)| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0));
+
+    if n == 0 {
+        return Vec::new();
+    }
+    let mut ranked: Vec<(usize, f64)> = values.iter().copied().enumerate().collect();
+    if n < ranked.len() {
+        ranked.select_nth_unstable_by(n - 1, by_value_desc);
+        ranked.truncate(n);
+    }
+    ranked.sort_unstable_by(by_value_desc);
+    ranked
+}
+
+/// Add `updates` to `regrets`, flooring at zero under CFR+.
+fn apply_regret_updates(regrets: &mut [f64], updates: &[f64], use_cfr_plus: bool, cap: Option<f64>) {
+    for (regret, &update) in regrets.iter_mut().zip(updates) {
+        *regret += update;
+
+        // CFR+: floor negative regrets to 0
+        if use_cfr_plus && *regret < 0.0 {
+            *regret = 0.0;
+        }
+
+        if let Some(cap) = cap {
+            *regret = regret.clamp(-cap, cap);
         }
     }
 }
@@ -0,0 +1,65 @@
+//! Pluggable weighting for the average strategy.
+//!
+//! The average strategy is a weighted sum of the current strategies seen
+//! during training. By default the weight comes from the Linear CFR settings
+//! on [`CFRConfig`](super::CFRConfig); an [`AverageStrategyWeighter`] replaces
+//! that formula entirely, which makes schemes like burn-in periods or phase
+//! resets possible without touching the solver.
+
+use std::fmt::Debug;
+
+/// Trait for computing the strategy-sum weight of a single update.
+///
+/// # Example
+/// ```
+/// use rust_solver_poc::cfr::{AverageStrategyWeighter, CFRConfig};
+///
+/// /// Ignore everything before iteration 1000, then average uniformly.
+/// #[derive(Debug)]
+/// struct BurnIn(u64);
+///
+/// impl AverageStrategyWeighter for BurnIn {
+///     fn weight(&self, iteration: u64, reach: f64) -> f64 {
+///         if iteration < self.0 { 0.0 } else { reach }
+///     }
+/// }
+///
+/// let config = CFRConfig::default().with_strategy_weighter(BurnIn(1000));
+/// assert_eq!(config.strategy_weight(10, 1.0), 0.0);
+/// ```
+pub trait AverageStrategyWeighter: Debug + Send + Sync {
+    /// Weight for an update at `iteration` (1-based) made by a player who
+    /// reached the info set with probability `reach`.
+    fn weight(&self, iteration: u64, reach: f64) -> f64;
+}
+
+/// Every iteration counts equally (vanilla CFR averaging).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UniformWeighter;
+
+impl AverageStrategyWeighter for UniformWeighter {
+    fn weight(&self, _iteration: u64, reach: f64) -> f64 {
+        reach
+    }
+}
+
+/// Iteration `t` is weighted by `t` (Linear CFR).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LinearWeighter;
+
+impl AverageStrategyWeighter for LinearWeighter {
+    fn weight(&self, iteration: u64, reach: f64) -> f64 {
+        reach * iteration as f64
+    }
+}
+
+/// Iteration `t` is weighted by `t²` (as in DCFR with γ = 2).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct QuadraticWeighter;
+
+impl AverageStrategyWeighter for QuadraticWeighter {
+    fn weight(&self, iteration: u64, reach: f64) -> f64 {
+        let t = iteration as f64;
+        reach * t * t
+    }
+}
@@ -41,6 +41,7 @@
 //! **Expected Value**: Player 1 EV = -1/18 ≈ -0.0556
 
 use rand::Rng;
+use std::collections::HashMap;
 use std::fmt;
 
 use crate::cfr::game::{Action, Game, GameState, InfoState};
@@ -159,6 +160,40 @@ impl KuhnPoker {
             _ => "Unknown",
         }
     }
+
+    /// Analytic Nash equilibrium for a given Jack bluffing frequency.
+    ///
+    /// Kuhn Poker has a one-parameter family of equilibria: Player 1 bets
+    /// Jack with probability `alpha` in [0, 1/3] (clamped), bets King with
+    /// `3 * alpha`, and calls with Queen after checking with `alpha + 1/3`;
+    /// Player 2's strategy is the same in all of them. Returns
+    /// `[Pass, Bet]` probabilities for all 12 info-set keys.
+    pub fn reference_equilibrium(alpha: f64) -> HashMap<String, Vec<f64>> {
+        let alpha = alpha.clamp(0.0, 1.0 / 3.0);
+        let third = 1.0 / 3.0;
+
+        [
+            // Player 1, first to act
+            ("0:", alpha),
+            ("1:", 0.0),
+            ("2:", 3.0 * alpha),
+            // Player 1, check then facing a bet
+            ("0:pb", 0.0),
+            ("1:pb", alpha + third),
+            ("2:pb", 1.0),
+            // Player 2, facing a bet
+            ("0:b", 0.0),
+            ("1:b", third),
+            ("2:b", 1.0),
+            // Player 2, after a check
+            ("0:p", third),
+            ("1:p", 0.0),
+            ("2:p", 1.0),
+        ]
+        .into_iter()
+        .map(|(key, bet)| (key.to_string(), vec![1.0 - bet, bet]))
+        .collect()
+    }
 }
 
 impl Game for KuhnPoker {
@@ -308,6 +343,14 @@ impl Game for KuhnPoker {
         }
     }
 
+    fn chance_outcomes(&self, state: &Self::State) -> Vec<(Self::State, f64)> {
+        // 6 equally likely ordered deals of two distinct cards
+        let deals = (0..3u8).flat_map(|p0| (0..3u8).filter(move |&p1| p1 != p0).map(move |p1| [p0, p1]));
+        deals
+            .map(|cards| (KuhnState { cards, dealt: true, ..state.clone() }, 1.0 / 6.0))
+            .collect()
+    }
+
     fn action_name(&self, action: &Self::Action) -> String {
         match action {
             KuhnAction::Pass => "Pass".to_string(),
@@ -318,6 +361,35 @@ impl Game for KuhnPoker {
     fn state_description(&self, state: &Self::State) -> String {
         format!("{}", state)
     }
+
+    fn max_depth(&self) -> Option<usize> {
+        // Deal, then at most pass-bet-call
+        Some(4)
+    }
+
+    fn estimated_info_sets(&self) -> Option<usize> {
+        // 3 cards x 4 decision histories ("", "p", "b", "pb")
+        Some(12)
+    }
+
+    fn describe_info_state(&self, info: &Self::InfoState) -> String {
+        let card = match info.card {
+            0 => "Jack",
+            1 => "Queen",
+            2 => "King",
+            _ => "?",
+        };
+        let player = info.history.len() % 2;
+        if info.history.is_empty() {
+            return format!("Player {} with {}, first to act", player, card);
+        }
+        let history: Vec<&str> = info
+            .history
+            .chars()
+            .map(|c| if c == 'b' { "Bet" } else { "Pass" })
+            .collect();
+        format!("Player {} with {} after {}", player, card, history.join(", "))
+    }
 }
 
 #[cfg(test)]
@@ -410,6 +482,19 @@ mod tests {
         assert_eq!(info.key(), "2:p");
     }
 
+    /// Assert the average strategy is within `tolerance` of a Kuhn equilibrium.
+    ///
+    /// Equilibria form a family indexed by P1's Jack bluff frequency α; the
+    /// comparison is against the member matching the one the solver settled on.
+    fn assert_near_kuhn_equilibrium(solver: &CFRSolver<KuhnPoker>, tolerance: f64) {
+        let alpha = solver.get_average_strategy("0:", 2)[1];
+        let error = solver.max_strategy_error(&KuhnPoker::reference_equilibrium(alpha));
+        println!("Jack bet α = {:.3}, max strategy error = {:.3}", alpha, error);
+
+        assert!(alpha < 0.4, "Jack bet probability {} should be in [0, 1/3]", alpha);
+        assert!(error < tolerance, "max strategy error {} vs α = {} equilibrium", error, alpha);
+    }
+
     #[test]
     fn test_kuhn_cfr_convergence() {
         let game = KuhnPoker::new();
@@ -420,83 +505,1184 @@ mod tests {
         // MCCFR with external sampling needs more iterations than full CFR
         solver.train(50_000);
 
-        // Check that we discovered the expected info sets
-        // There are 12 info sets: 3 cards × 4 possible histories
-        // (empty, "p", "b", "pb" for decision points)
-        assert!(solver.num_info_sets() > 0);
+        // There are 12 info sets: 3 cards × 4 possible decision histories
+        assert_eq!(solver.num_info_sets(), 12);
 
-        // Expected Nash Equilibrium for Kuhn Poker:
-        // P1 Jack:  Pass=0.667, Bet=0.333 (bluff with probability α = 1/3)
-        // P1 Queen: Pass=1.000, Bet=0.000 (never bet)
-        // P1 King:  Pass=0.000, Bet=1.000 (always bet)
+        assert_near_kuhn_equilibrium(&solver, 0.15);
+    }
+
+    #[test]
+    fn test_kuhn_windowed_linear_average_long_run() {
+        let game = KuhnPoker::new();
+        let config = CFRConfig::default()
+            .with_seed(7)
+            .with_linear_weight_window(5_000);
+        let mut solver = CFRSolver::new(game, config);
 
-        // Check P1's strategy with Jack at root (should bet ~1/3)
-        let jack_strategy = solver.get_average_strategy("0:", 2);
-        println!("Jack strategy: Pass={:.3}, Bet={:.3}", jack_strategy[0], jack_strategy[1]);
+        solver.train(200_000);
 
-        // Jack should bet with probability around 1/3 (±0.15 for convergence tolerance)
-        // Index 0 = Pass, Index 1 = Bet
-        let jack_bet_prob = jack_strategy[1];
+        // Any equilibrium has P1 King betting 3x as often as P1 Jack bluffs.
+        let jack_bet = solver.get_average_strategy("0:", 2)[1];
+        let king_bet = solver.get_average_strategy("2:", 2)[1];
         assert!(
-            jack_bet_prob > 0.15 && jack_bet_prob < 0.5,
-            "Jack bet probability {} should be near 1/3",
-            jack_bet_prob
+            jack_bet < 0.4,
+            "Jack bet probability {} should be in [0, 1/3]",
+            jack_bet
+        );
+        assert!(
+            (king_bet - 3.0 * jack_bet).abs() < 0.1,
+            "King bet {} should be ~3x Jack bet {}",
+            king_bet,
+            jack_bet
         );
 
-        // Check P1's strategy with Queen at root (should always pass)
-        let queen_strategy = solver.get_average_strategy("1:", 2);
-        println!("Queen strategy: Pass={:.3}, Bet={:.3}", queen_strategy[0], queen_strategy[1]);
-
-        // Queen should mostly pass (>95%)
+        let queen_call = solver.get_average_strategy("1:b", 2)[1];
         assert!(
-            queen_strategy[0] > 0.95,
-            "Queen pass probability {} should be near 1.0",
-            queen_strategy[0]
+            (queen_call - 1.0 / 3.0).abs() < 0.1,
+            "P2 Queen call probability {} should be near 1/3",
+            queen_call
         );
 
-        // Check P1's strategy with King at root (should mostly bet)
-        let king_strategy = solver.get_average_strategy("2:", 2);
-        println!("King strategy: Pass={:.3}, Bet={:.3}", king_strategy[0], king_strategy[1]);
+        // With the window, the final iteration carries ~1/t of the weight
+        // rather than ~2/t, so the tail cannot take over the average.
+        let config = solver.config();
+        let last = config.strategy_weight(200_000, 1.0);
+        let total: f64 = (1..=200_000u64).map(|t| config.strategy_weight(t, 1.0)).sum();
+        assert!(last / total < 1.5 / 200_000.0);
+    }
+
+    /// Total probability the average strategy puts on actions that are
+    /// never played in any Kuhn equilibrium.
+    fn kuhn_dominated_mass(solver: &CFRSolver<KuhnPoker>) -> f64 {
+        let queen_bet = solver.get_average_strategy("1:", 2)[1];
+        let jack_call = solver.get_average_strategy("0:b", 2)[1];
+        let king_fold = solver.get_average_strategy("2:b", 2)[0];
+        let jack_call_after_check = solver.get_average_strategy("0:pb", 2)[1];
+        let king_fold_after_check = solver.get_average_strategy("2:pb", 2)[0];
+
+        queen_bet + jack_call + king_fold + jack_call_after_check + king_fold_after_check
+    }
 
-        // King should bet more than 50% and more than Jack
+    #[test]
+    fn test_kuhn_burn_in_weighter() {
+        use crate::cfr::{AverageStrategyWeighter, UniformWeighter};
+
+        #[derive(Debug)]
+        struct BurnIn(u64);
+
+        impl AverageStrategyWeighter for BurnIn {
+            fn weight(&self, iteration: u64, reach: f64) -> f64 {
+                if iteration < self.0 { 0.0 } else { reach }
+            }
+        }
+
+        // Same seed => identical regret trajectory; only the averaging differs
+        let mut uniform = CFRSolver::new(
+            KuhnPoker::new(),
+            CFRConfig::default().with_seed(3).with_strategy_weighter(UniformWeighter),
+        );
+        let mut burn_in = CFRSolver::new(
+            KuhnPoker::new(),
+            CFRConfig::default().with_seed(3).with_strategy_weighter(BurnIn(1_000)),
+        );
+        uniform.train(4_000);
+        burn_in.train(4_000);
+
+        let uniform_error = kuhn_dominated_mass(&uniform);
+        let burn_in_error = kuhn_dominated_mass(&burn_in);
         assert!(
-            king_strategy[1] > 0.5,
-            "King bet probability {} should be >50%",
-            king_strategy[1]
+            burn_in_error < uniform_error,
+            "burn-in error {} should beat uniform error {}",
+            burn_in_error,
+            uniform_error
         );
+    }
+
+    #[test]
+    fn test_kuhn_average_delay() {
+        let train = |config: CFRConfig, iterations: u64| {
+            let mut solver = CFRSolver::new(KuhnPoker::new(), config);
+            solver.train(iterations);
+            solver
+        };
+
+        // Short run with uniform averaging: the early noise is a large share
+        // of the average, so skipping it is clearly better
+        let base = CFRConfig::default().with_seed(3).with_linear_cfr(false);
+        let plain = kuhn_dominated_mass(&train(base.clone(), 4_000));
+        let delayed = kuhn_dominated_mass(&train(base.with_average_delay(1_000), 4_000));
+        assert!(delayed < plain, "delayed error {} should beat plain error {}", delayed, plain);
+
+        // With the default linear weighting it should never hurt
+        let base = CFRConfig::default().with_seed(5);
+        let plain = kuhn_dominated_mass(&train(base.clone(), 20_000));
+        let delayed = kuhn_dominated_mass(&train(base.with_average_delay(500), 20_000));
+        assert!(delayed <= plain + 0.01, "delayed error {} vs plain error {}", delayed, plain);
+
+        // Nothing is averaged until the delay has passed
+        let solver = train(CFRConfig::default().with_seed(1).with_average_delay(100), 100);
+        assert!(solver.storage().contains("0:"));
+        assert!(solver.storage().strategy_sums().is_empty());
+    }
+
+    #[test]
+    fn test_for_each_average_strategy() {
+        let mut solver = CFRSolver::new(KuhnPoker::new(), CFRConfig::default().with_seed(11));
+        solver.train(2_000);
+
+        let mut visited = 0;
+        solver.storage().for_each_average_strategy(|key, strategy| {
+            visited += 1;
+            let total: f64 = strategy.iter().sum();
+            assert!((total - 1.0).abs() < 1e-9, "{} sums to {}", key, total);
+            assert!(strategy.iter().all(|&p| (0.0..=1.0).contains(&p)));
+            assert_eq!(strategy, solver.get_average_strategy(key, strategy.len()).as_slice());
+        });
+
+        assert_eq!(visited, solver.num_info_sets());
+        assert_eq!(visited, 12);
+    }
+
+    #[test]
+    fn test_purified_strategy_tiebreak() {
+        use crate::cfr::argmax_with_tiebreak;
+
+        assert_eq!(argmax_with_tiebreak(&[0.5, 0.5]), Some(0));
+        assert_eq!(argmax_with_tiebreak(&[0.2, 0.4, 0.4]), Some(1));
+        assert_eq!(argmax_with_tiebreak(&[f64::NAN, 0.1]), Some(1));
+        assert_eq!(argmax_with_tiebreak(&[]), None);
+
+        // An untrained info set averages to uniform [0.5, 0.5]
+        for seed in 0..5 {
+            let solver = CFRSolver::new(KuhnPoker::new(), CFRConfig::default().with_seed(seed));
+            assert_eq!(solver.get_average_strategy("1:", 2), vec![0.5, 0.5]);
+            assert_eq!(solver.get_purified_strategy("1:", 2), vec![1.0, 0.0]);
+        }
+    }
+
+    #[test]
+    fn test_train_with_checkpoints() {
+        let mut solver = CFRSolver::new(KuhnPoker::new(), CFRConfig::default().with_seed(5));
+        solver.train(100);
+
+        let mut checkpoints = Vec::new();
+        solver.train_with_checkpoints(1_000, 300, |state| {
+            assert_eq!(state.stats.iterations, state.iteration);
+            checkpoints.push(state.iteration);
+        });
+        assert_eq!(checkpoints, vec![400, 700, 1_000]);
+
+        // Interval 0 falls back to the config default
+        let config = CFRConfig::default().with_seed(5).with_checkpoint_every(250);
+        let mut solver = CFRSolver::new(KuhnPoker::new(), config);
+        let mut fired = 0;
+        solver.train_with_checkpoints(1_000, 0, |state| {
+            fired += 1;
+            assert_eq!(state.iteration, fired * 250);
+            assert!(!state.storage.regrets.is_empty());
+        });
+        assert_eq!(fired, 4);
+    }
+
+    #[test]
+    fn test_checkpoint_resume_matches_single_run() {
+        use crate::cfr::SolverState;
+
+        let config = CFRConfig::default().with_seed(21);
+        let mut straight = CFRSolver::new(KuhnPoker::new(), config.clone());
+        straight.train(10_000);
+
+        let mut first = CFRSolver::new(KuhnPoker::new(), config.clone());
+        first.train(5_000);
+        let json = serde_json::to_string(&first.export_state()).unwrap();
+        let checkpoint: SolverState = serde_json::from_str(&json).unwrap();
+        assert!(checkpoint.rng.is_some());
+
+        // A fresh solver with a different seed picks up the checkpoint's RNG
+        let mut resumed = CFRSolver::new(KuhnPoker::new(), config.with_seed(99));
+        resumed.import_state(checkpoint).unwrap();
+        resumed.train(5_000);
+
+        assert_eq!(resumed.report().strategies, straight.report().strategies);
+        assert_eq!(resumed.storage().export().regrets, straight.storage().export().regrets);
+    }
+
+    #[test]
+    fn test_checkpoint_resume_is_bit_identical() {
+        use crate::cfr::{SolverState, UpdateMode};
+
+        let configs = [
+            CFRConfig::default().with_seed(22),
+            CFRConfig::vanilla().with_seed(23),
+            CFRConfig::default().with_update_mode(UpdateMode::Simultaneous).with_exploration(0.3).with_seed(24),
+        ];
+        for config in configs {
+            // Checkpoint taken mid-run by the callback, not after `train` returns
+            let mut straight = CFRSolver::new(KuhnPoker::new(), config.clone());
+            let mut midway = None;
+            straight.train_with_checkpoints(4_000, 1_500, |state| {
+                if state.iteration == 1_500 {
+                    midway = Some(serde_json::to_string(state).unwrap());
+                }
+            });
+
+            let checkpoint: SolverState = serde_json::from_str(&midway.unwrap()).unwrap();
+            let mut resumed = CFRSolver::new(KuhnPoker::new(), config.clone());
+            resumed.import_state(checkpoint).unwrap();
+            resumed.train(2_500);
+
+            assert_eq!(resumed.iteration(), straight.iteration());
+            assert_eq!(resumed.storage().export().regrets, straight.storage().export().regrets);
+            assert_eq!(resumed.storage().export().strategy_sums, straight.storage().export().strategy_sums);
+        }
+
+        // Checkpoints from before RNG state was recorded still import
+        let mut solver = CFRSolver::new(KuhnPoker::new(), CFRConfig::default().with_seed(25));
+        solver.train(500);
+        let mut legacy = serde_json::to_value(solver.export_state()).unwrap();
+        legacy.as_object_mut().unwrap().remove("rng");
+        let legacy: SolverState = serde_json::from_value(legacy).unwrap();
+        assert!(legacy.rng.is_none());
+        let mut restored = CFRSolver::new(KuhnPoker::new(), CFRConfig::default().with_seed(25));
+        restored.import_state(legacy).unwrap();
+        assert_eq!(restored.report().strategies, solver.report().strategies);
+    }
+
+    #[test]
+    fn test_export_formats_round_trip() {
+        use crate::cfr::{read_checkpoint, read_jsonl, StrategyFormat};
+
+        let mut solver = CFRSolver::new(KuhnPoker::new(), CFRConfig::default().with_seed(26));
+        solver.train(2_000);
+        let export = |format| {
+            let mut buf = Vec::new();
+            solver.export_to_writer(&mut buf, format).map(|_| buf)
+        };
+
+        let lines = read_jsonl(&export(StrategyFormat::Jsonl).unwrap()[..]).unwrap();
+        assert_eq!(lines.len(), 12);
+        assert!(lines.iter().all(|line| line.actions == ["Pass", "Bet"]), "{:?}", lines[0]);
+        let strategies: std::collections::BTreeMap<_, _> =
+            lines.into_iter().map(|line| (line.key, line.strategy)).collect();
+        assert_eq!(strategies, solver.report().strategies);
+
+        let checkpoint = read_checkpoint(&export(StrategyFormat::Checkpoint).unwrap()[..]).unwrap();
+        let mut restored = CFRSolver::new(KuhnPoker::new(), CFRConfig::default());
+        restored.import_state(checkpoint).unwrap();
+        assert_eq!(restored.report().strategies, solver.report().strategies);
+
+        // Kuhn info sets are cards plus history, not hand classes
+        let err = export(StrategyFormat::HandClassCsv).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::Unsupported);
+    }
+
+    #[test]
+    fn test_train_seeded() {
+        let mut base = CFRSolver::new(KuhnPoker::new(), CFRConfig::default());
+        base.train_seeded(200, 0);
+
+        let run = |seed: u64| {
+            let mut solver = base.clone();
+            solver.train_seeded(1_000, seed);
+            solver.get_average_strategy("0:", 2)
+        };
+
+        assert_eq!(run(1), run(1));
+        assert_ne!(run(1), run(2));
+    }
+
+    #[test]
+    fn test_stats_json_and_summary() {
+        use crate::cfr::CFRStats;
+
+        let mut solver = CFRSolver::new(KuhnPoker::new(), CFRConfig::default().with_seed(3));
+        let stats = solver.train(500).clone();
+        assert!(stats.nodes_visited > 0);
+        assert!(stats.peak_memory_bytes > 0);
+
+        let json = stats.to_json().unwrap();
+        let restored: CFRStats = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.iterations, stats.iterations);
+        assert_eq!(restored.info_sets, stats.info_sets);
+        assert_eq!(restored.nodes_visited, stats.nodes_visited);
+        assert_eq!(restored.peak_memory_bytes, stats.peak_memory_bytes);
+
+        let summary = stats.summary();
+        assert!(summary.contains("Iterations:     500"));
+        assert!(summary.contains(&format!("{:.2}s", stats.elapsed_seconds)));
+    }
+
+    #[test]
+    fn test_random_playout_invariants() {
+        crate::cfr::assert_game_invariants(&KuhnPoker::new(), 1_000);
+    }
+
+    #[test]
+    fn test_convergence_eta() {
+        let mut solver = CFRSolver::new(KuhnPoker::new(), CFRConfig::default().with_seed(5));
+        let mut reports = Vec::new();
+        solver.train_until_converged(
+            1e-9,
+            500,
+            20_000,
+            Some(|stats: &crate::cfr::ConvergenceStats| reports.push(stats.clone())),
+        );
+
+        // Warmup and the first snapshot report no estimate
+        assert!(reports.iter().filter(|r| !r.ci.is_finite()).all(|r| r.eta_seconds.is_none()));
+
+        let etas: Vec<f64> = reports.iter().filter_map(|r| r.eta_seconds).collect();
+        assert!(!etas.is_empty(), "CI never trended down");
+        assert!(etas.iter().all(|&eta| eta.is_finite() && eta > 0.0));
+    }
+
+    #[test]
+    fn test_converge_on_exploitability_ci() {
+        let config = CFRConfig::default()
+            .with_seed(42)
+            .with_convergence_metric(crate::cfr::ConvergenceMetric::Exploitability);
+        let mut solver = CFRSolver::new(KuhnPoker::new(), config);
+        let result = solver.train_until_converged(
+            2.0,
+            1_000,
+            200_000,
+            None::<fn(&crate::cfr::ConvergenceStats)>,
+        );
+
+        assert!(result.converged, "stopped at CI {} after {} iterations", result.final_ci, result.iterations);
+        assert!(result.iterations < 200_000);
+        assert_eq!(result.final_ci, solver.storage().calculate_exploitability_ci(result.iterations));
+
+        assert_near_kuhn_equilibrium(&solver, 0.15);
+    }
+
+    #[test]
+    fn test_merge_into_average() {
+        let mut solver = CFRSolver::new(KuhnPoker::new(), CFRConfig::default().with_seed(12));
+        solver.train(20_000);
+        let before = solver.snapshot_strategies();
+
+        let baseline = solver.merge_into_average(0.5);
+        assert_eq!(baseline.strategies, before.strategies);
+
+        // Regrets are gone but the average is untouched
+        assert_eq!(solver.storage().get_current_strategy("0:", 2), vec![0.5, 0.5]);
+        for (key, probs) in &before.strategies {
+            let after = solver.get_average_strategy(key, probs.len());
+            assert!(probs.iter().zip(&after).all(|(a, b)| (a - b).abs() < 1e-12), "{} moved", key);
+        }
+
+        solver.train(20_000);
+        assert!(solver.snapshot_strategies().strategies.values().flatten().all(|p| p.is_finite()));
+        assert_near_kuhn_equilibrium(&solver, 0.15);
+    }
+
+    #[test]
+    fn test_regret_cap() {
+        let cap = 50.0;
+        let config = CFRConfig::default().with_regret_cap(cap).with_seed(13);
+        assert!(config.validate().is_ok());
+        assert!(CFRConfig::default().with_regret_cap(0.0).validate().is_err());
+
+        let mut solver = CFRSolver::new(KuhnPoker::new(), config);
+        solver.train(50_000);
+
+        let regrets = solver.storage().regrets();
+        assert!(!regrets.is_empty());
+        for (key, values) in regrets.iter() {
+            assert!(values.iter().all(|r| r.abs() <= cap), "{} regrets {:?} exceed cap", key, values);
+        }
+        // Uncapped regrets reach well past 100 here, so the cap must bind
+        let max = regrets.values().flatten().fold(0.0f64, |m, r| m.max(r.abs()));
+        assert!(max > 0.9 * cap, "largest regret {} never approached the cap", max);
+        drop(regrets);
+
+        assert_near_kuhn_equilibrium(&solver, 0.15);
+    }
+
+    #[test]
+    fn test_exploitability_reach_pruning() {
+        let mut solver = CFRSolver::new(KuhnPoker::new(), CFRConfig::default().with_seed(9));
+        solver.train(20_000);
+
+        let mut measure = |min_reach: f64| {
+            let before = solver.stats().exploitability_nodes_visited;
+            let exploitability = solver.calculate_exploitability_pruned(2_000, min_reach);
+            (exploitability, solver.stats().exploitability_nodes_visited - before)
+        };
+
+        let (full, full_nodes) = measure(0.0);
+        let (pruned, pruned_nodes) = measure(0.05);
         assert!(
-            king_strategy[1] > jack_bet_prob,
-            "King should bet more often than Jack"
+            (full - pruned).abs() < 0.05,
+            "pruned exploitability {} drifted from {}",
+            pruned,
+            full
         );
+        assert!(pruned_nodes < full_nodes, "{} >= {}", pruned_nodes, full_nodes);
+    }
+
+    #[test]
+    fn test_player_gains_average_to_exploitability() {
+        let mut solver = CFRSolver::new(KuhnPoker::new(), CFRConfig::default().with_seed(15));
+        solver.train(5_000);
+        assert!(solver.game().is_zero_sum());
+
+        let gains = solver.calculate_player_gains(500);
+        let exploitability = solver.calculate_exploitability(500);
+
+        assert_eq!(gains.len(), 2);
+        assert!(gains.iter().all(|&gain| gain > -0.05), "gains {:?}", gains);
+        assert!((gains.iter().sum::<f64>() / 2.0 - exploitability).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_exact_exploitability() {
+        // The uniform strategy's best responses gain 11/12 in total
+        let untrained = CFRSolver::new(KuhnPoker::new(), CFRConfig::default());
+        let uniform = untrained.calculate_exploitability_exact();
+        assert!((uniform - 11_000.0 / 12.0).abs() < 1e-9, "uniform exploitability {}", uniform);
+
+        let mut solver = CFRSolver::new(KuhnPoker::new(), CFRConfig::default().with_seed(42));
+        solver.train(1_000);
+        let early = solver.calculate_exploitability_exact();
+        assert_eq!(early, solver.calculate_exploitability_exact(), "exact exploitability is deterministic");
+
+        solver.train(49_000);
+        let late = solver.calculate_exploitability_exact();
+        println!("exact exploitability: {:.2} -> {:.2} mbb/g", early, late);
+        assert!(late < early, "exploitability should fall with training: {} -> {}", early, late);
+        assert!(late < 20.0, "exploitability {} mbb/g after 50k iterations", late);
+    }
 
-        // Check P2's strategies facing a bet
-        let p2_jack_vs_bet = solver.get_average_strategy("0:b", 2);
-        let p2_queen_vs_bet = solver.get_average_strategy("1:b", 2);
-        let p2_king_vs_bet = solver.get_average_strategy("2:b", 2);
+    #[test]
+    fn test_train_parallel_matches_serial() {
+        let config = CFRConfig::default().with_seed(42);
 
-        println!("P2 Jack facing bet: Fold={:.3}, Call={:.3}", p2_jack_vs_bet[0], p2_jack_vs_bet[1]);
-        println!("P2 Queen facing bet: Fold={:.3}, Call={:.3}", p2_queen_vs_bet[0], p2_queen_vs_bet[1]);
-        println!("P2 King facing bet: Fold={:.3}, Call={:.3}", p2_king_vs_bet[0], p2_king_vs_bet[1]);
+        let mut serial = CFRSolver::new(KuhnPoker::new(), config.clone());
+        serial.train(20_000);
+        let serial_exploitability = serial.calculate_exploitability_exact();
 
-        // P2 with Jack should always fold (>95%)
+        let mut parallel = CFRSolver::new(KuhnPoker::new(), config);
+        parallel.train_parallel(10_000, 4);
+        let stats = parallel.train_parallel(10_000, 4);
+        assert_eq!(stats.iterations, 20_000);
+        assert_eq!(stats.info_sets, 12);
+        let parallel_exploitability = parallel.calculate_exploitability_exact();
+
+        println!(
+            "exact exploitability: serial {:.2}, parallel {:.2} mbb/g",
+            serial_exploitability, parallel_exploitability
+        );
         assert!(
-            p2_jack_vs_bet[0] > 0.95,
-            "P2 Jack should fold to bet"
+            parallel_exploitability < 2.0 * serial_exploitability + 10.0,
+            "parallel {} vs serial {} mbb/g",
+            parallel_exploitability,
+            serial_exploitability
         );
+    }
 
-        // P2 with King should always call (>95%)
+    #[test]
+    fn test_strategy_ema() {
+        let config = CFRConfig::default().with_seed(13).with_strategy_ema(0.01);
+        assert!(config.validate().is_ok());
+        assert!(CFRConfig::default().with_strategy_ema(0.0).validate().is_err());
+
+        let mut solver = CFRSolver::new(KuhnPoker::new(), config);
+        solver.train(5_000);
+
+        // Track P2 Queen facing a bet, which mixes at equilibrium
+        let (mut ema_path, mut current_path) = (Vec::new(), Vec::new());
+        for _ in 0..100 {
+            solver.train(100);
+            ema_path.push(solver.get_ema_strategy("1:b", 2)[1]);
+            current_path.push(solver.get_current_strategy("1:b", 2)[1]);
+        }
+        let total_variation =
+            |path: &[f64]| path.windows(2).map(|w| (w[1] - w[0]).abs()).sum::<f64>();
         assert!(
-            p2_king_vs_bet[1] > 0.95,
-            "P2 King should call bet"
+            total_variation(&ema_path) < total_variation(&current_path),
+            "EMA path {} should be smoother than current path {}",
+            total_variation(&ema_path),
+            total_variation(&current_path)
         );
 
-        // P2 with Queen should call about 1/3 of the time (±0.15)
+        // Dominated actions decay out of the EMA
+        assert!(solver.get_ema_strategy("0:b", 2)[0] > 0.95, "Jack should fold to bet");
+        assert!(solver.get_ema_strategy("2:b", 2)[1] > 0.95, "King should call bet");
+
+        // Without the config flag no EMA is kept
+        let mut plain = CFRSolver::new(KuhnPoker::new(), CFRConfig::default().with_seed(13));
+        plain.train(1_000);
+        assert!(plain.storage().export().ema_strategies.is_empty());
+    }
+
+    #[test]
+    fn test_subtree_exploitability() {
+        let game = KuhnPoker::new();
+
+        // P1 facing a bet after checking, holding the King or the Jack:
+        // calling (King) and folding (Jack) are the only correct responses
+        let pb_roots: Vec<KuhnState> = [[2, 0], [0, 2]]
+            .into_iter()
+            .map(|cards| {
+                let dealt = KuhnState { cards, dealt: true, ..KuhnState::default() };
+                let checked = game.apply_action(&dealt, &KuhnAction::Pass);
+                game.apply_action(&checked, &KuhnAction::Bet)
+            })
+            .collect();
+
+        let subtree_gap = |iterations: u64| {
+            let mut solver = CFRSolver::new(KuhnPoker::new(), CFRConfig::default().with_seed(9));
+            solver.train(iterations);
+            pb_roots
+                .iter()
+                .map(|root| solver.calculate_exploitability_for_subtree(root, 1))
+                .sum::<f64>()
+        };
+
+        let short = subtree_gap(10);
+        let long = subtree_gap(10_000);
+        assert!(long >= 0.0);
+        assert!(long < short, "subtree gap {} after 10k should beat {} after 10", long, short);
+        assert!(long < 0.01, "subtree gap {} should be near 0", long);
+    }
+
+    #[test]
+    fn test_action_values() {
+        let mut solver = CFRSolver::new(KuhnPoker::new(), CFRConfig::default().with_seed(4).with_action_values(true));
+        solver.train(5_000);
+
+        // P1 King: betting wins at least the ante, checking can let P2 check behind
+        let king = solver.action_values("2:").expect("King root values tracked");
+        assert_eq!(king.len(), 2);
+        assert!(king[1] > king[0], "King Bet value {} should beat Pass value {}", king[1], king[0]);
+
+        // Jack facing a bet: calling always loses 2, folding loses 1
+        let jack = solver.action_values("0:pb").unwrap();
+        assert!((jack[0] + 1.0).abs() < 1e-9 && (jack[1] + 2.0).abs() < 1e-9, "{:?}", jack);
+
+        // Off by default
+        let mut untracked = CFRSolver::new(KuhnPoker::new(), CFRConfig::default().with_seed(4));
+        untracked.train(100);
+        assert!(untracked.action_values("2:").is_none());
+    }
+
+    #[test]
+    fn test_describe_info_state() {
+        let game = KuhnPoker::new();
+        let info = KuhnInfoState { card: 1, history: "pb".to_string() };
+        assert_eq!(game.describe_info_state(&info), "Player 0 with Queen after Pass, Bet");
+
+        let info = KuhnInfoState { card: 2, history: String::new() };
+        assert_eq!(game.describe_info_state(&info), "Player 0 with King, first to act");
+    }
+
+    #[test]
+    fn test_storage_presized_from_estimate() {
+        let mut solver = CFRSolver::new(KuhnPoker::new(), CFRConfig::default().with_seed(2));
+        let capacity = solver.storage().capacity();
+        assert!(capacity >= 12, "capacity {} below the 12 Kuhn info sets", capacity);
+
+        solver.train(1_000);
+        assert_eq!(solver.num_info_sets(), 12);
+        assert_eq!(solver.storage().capacity(), capacity, "storage rehashed during training");
+    }
+
+    #[test]
+    fn test_visit_counts() {
+        use crate::cfr::StorageLayout;
+
+        for layout in [StorageLayout::Map, StorageLayout::Arena] {
+            let config = CFRConfig::default().with_seed(4).with_storage_layout(layout);
+            let mut solver = CFRSolver::new(KuhnPoker::new(), config.clone());
+            solver.train(1_000);
+
+            // Player 1 holds the Jack as traverser in about a third of iterations
+            let storage = solver.storage();
+            let jack = storage.visit_count("0:");
+            assert!((250..=420).contains(&jack), "{:?}: Jack opened {} times", layout, jack);
+            let min = storage.min_visit_count();
+            assert!(min > 0, "{:?}: some info set never visited", layout);
+            for key in storage.info_set_keys() {
+                assert!(storage.visit_count(&key) >= min);
+            }
+            assert_eq!(storage.visit_count("unknown"), 0);
+
+            let mut restored = CFRSolver::new(KuhnPoker::new(), config);
+            restored.import_state(solver.export_state()).unwrap();
+            assert_eq!(restored.storage().visit_count("0:"), jack);
+            assert_eq!(restored.storage().min_visit_count(), min);
+
+            restored.reset();
+            assert_eq!(restored.storage().visit_count("0:"), 0);
+            assert_eq!(restored.storage().min_visit_count(), 0);
+        }
+    }
+
+    #[test]
+    fn test_simultaneous_updates() {
+        use crate::cfr::UpdateMode;
+
+        let solve = |mode: UpdateMode, iterations: u64| {
+            let config = CFRConfig::default().with_seed(21).with_update_mode(mode);
+            let mut solver = CFRSolver::new(KuhnPoker::new(), config);
+            solver.train(iterations);
+            solver
+        };
+
+        // Same seed, different schedule: the early trajectories diverge
+        let alternating = solve(UpdateMode::Alternating, 50);
+        let simultaneous = solve(UpdateMode::Simultaneous, 50);
+        assert_ne!(alternating.storage().regrets(), simultaneous.storage().regrets());
+
+        // ...but both schemes end up at a Kuhn equilibrium
+        for mode in [UpdateMode::Alternating, UpdateMode::Simultaneous] {
+            let solver = solve(mode, 50_000);
+            let dominated = kuhn_dominated_mass(&solver);
+            assert!(dominated < 0.1, "{:?} dominated mass {}", mode, dominated);
+
+            let queen_call = solver.get_average_strategy("1:b", 2)[1];
+            assert!(
+                (queen_call - 1.0 / 3.0).abs() < 0.1,
+                "{:?} P2 Queen call probability {} should be near 1/3",
+                mode,
+                queen_call
+            );
+        }
+    }
+
+    #[test]
+    fn test_mmap_policy_matches_solver() {
+        use crate::cfr::MmapPolicy;
+
+        let mut solver = CFRSolver::new(KuhnPoker::new(), CFRConfig::default().with_seed(4));
+        solver.train(2_000);
+
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("rust_solver_poc_kuhn_policy_{}.bin", std::process::id()));
+        MmapPolicy::write(solver.storage(), &path).unwrap();
+        let policy = MmapPolicy::open(&path).unwrap();
+
+        assert_eq!(policy.len(), solver.num_info_sets());
+        for key in solver.info_set_keys() {
+            assert_eq!(
+                policy.get_average_strategy(&key),
+                Some(solver.get_average_strategy(&key, 2)),
+                "{}",
+                key
+            );
+        }
+        assert_eq!(policy.get_average_strategy("9:zz"), None);
+        drop(policy);
+
+        // A truncated index is rejected up front
+        let bytes = std::fs::read(&path).unwrap();
+        let corrupt_path = dir.join(format!("rust_solver_poc_kuhn_policy_corrupt_{}.bin", std::process::id()));
+        for corrupt in [&bytes[..100], &bytes[..8]] {
+            std::fs::write(&corrupt_path, corrupt).unwrap();
+            let error = MmapPolicy::open(&corrupt_path).unwrap_err();
+            assert_eq!(error.kind(), std::io::ErrorKind::InvalidData, "{}", error);
+        }
+
+        // Bad entries only fail the lookups that reach them, without panicking
+        let last_key = solver.info_set_keys().into_iter().max().unwrap();
+        std::fs::write(&corrupt_path, &bytes[..bytes.len() - 1]).unwrap();
+        let truncated = MmapPolicy::open(&corrupt_path).unwrap();
+        assert_eq!(truncated.get_average_strategy(&last_key), None);
+        let mut swapped = bytes.clone();
+        let (first, second) = swapped[16..64].split_at_mut(24);
+        first.swap_with_slice(second);
+        std::fs::write(&corrupt_path, &swapped).unwrap();
+        let reordered = MmapPolicy::open(&corrupt_path).unwrap();
+        for key in solver.info_set_keys() {
+            reordered.get_average_strategy(&key);
+        }
+        drop((truncated, reordered));
+
+        let _ = std::fs::remove_file(&path);
+        let _ = std::fs::remove_file(&corrupt_path);
+    }
+
+    #[test]
+    fn test_train_accumulates_elapsed_time() {
+        let mut solver = CFRSolver::new(KuhnPoker::new(), CFRConfig::default().with_seed(42));
+
+        let first = solver.train(1000).elapsed_seconds;
+        let started = std::time::Instant::now();
+        let stats = solver.train(1000).clone();
+        let second = started.elapsed().as_secs_f64();
+
+        assert_eq!(stats.iterations, 2000);
+        assert!(first > 0.0);
         assert!(
-            p2_queen_vs_bet[1] > 0.2 && p2_queen_vs_bet[1] < 0.5,
-            "P2 Queen call probability {} should be near 1/3",
-            p2_queen_vs_bet[1]
+            stats.elapsed_seconds > first && stats.elapsed_seconds <= first + second + 1e-6,
+            "elapsed {} should be the sum of both batches ({} + <= {})",
+            stats.elapsed_seconds,
+            first,
+            second
+        );
+        assert!((stats.iterations_per_second - 2000.0 / stats.elapsed_seconds).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_convergence_loops_resume_the_clock() {
+        use crate::cfr::ConvergenceStats;
+
+        let mut solver = CFRSolver::new(KuhnPoker::new(), CFRConfig::default().with_seed(42));
+        let first = solver.train(1000).elapsed_seconds;
+
+        let mut rates = Vec::new();
+        let result = solver.train_until_converged(0.0, 1000, 3000, Some(|s: &ConvergenceStats| {
+            rates.push(s.iterations_per_second * s.elapsed_seconds / s.iteration as f64)
+        }));
+        assert_eq!(result.iterations, 3000);
+        assert!(result.elapsed_seconds > first);
+        assert_eq!(solver.stats().iterations, 3000);
+        assert_eq!(solver.stats().elapsed_seconds, result.elapsed_seconds);
+        // The rate is over the whole run, not just this call
+        assert!(rates.iter().all(|&r| (r - 1.0).abs() < 1e-6), "{:?}", rates);
+
+        let before = solver.stats().elapsed_seconds;
+        let result = solver.train_parallel_until_converged(0.0, 1000, 5000, 2, None::<fn(&ConvergenceStats)>);
+        assert_eq!(result.iterations, 5000);
+        assert!(result.elapsed_seconds > before);
+        let stats = solver.stats();
+        assert_eq!((stats.iterations, stats.elapsed_seconds), (5000, result.elapsed_seconds));
+        assert!((stats.iterations_per_second - 5000.0 / stats.elapsed_seconds).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_train_for_duration() {
+        use std::time::{Duration, Instant};
+
+        let mut solver = CFRSolver::new(KuhnPoker::new(), CFRConfig::default().with_seed(8));
+        solver.train(100);
+
+        let mut batches = Vec::new();
+        let started = Instant::now();
+        let stats = solver
+            .train_for_duration(Duration::from_millis(200), 50, |stats| batches.push(stats.iterations))
+            .clone();
+        assert!(started.elapsed() >= Duration::from_millis(200));
+
+        // The clock is checked only between batches of 50
+        assert!(!batches.is_empty());
+        assert!(batches.iter().all(|&iterations| (iterations - 100) % 50 == 0));
+        assert_eq!(stats.iterations, *batches.last().unwrap());
+        assert_eq!(stats.info_sets, 12);
+        assert!((stats.iterations_per_second - stats.iterations as f64 / stats.elapsed_seconds).abs() < 1e-6);
+
+        // An exhausted budget runs nothing
+        let before = solver.iteration();
+        solver.train_for_duration(Duration::ZERO, 50, |_| panic!("no batch should run"));
+        assert_eq!(solver.iteration(), before);
+    }
+
+    #[test]
+    fn test_exploitability_reproducible() {
+        let config = CFRConfig::default().with_seed(21);
+        let mut solver = CFRSolver::new(KuhnPoker::new(), config.clone());
+        solver.train(500);
+
+        let first = solver.calculate_exploitability(1000);
+        assert_eq!(solver.calculate_exploitability(1000), first);
+
+        // Measuring mid-training does not advance the training RNG
+        solver.train(500);
+        let mut uninterrupted = CFRSolver::new(KuhnPoker::new(), config);
+        uninterrupted.train(1000);
+        for key in ["0:", "1:p", "2:pb"] {
+            assert_eq!(solver.get_average_strategy(key, 2), uninterrupted.get_average_strategy(key, 2));
+        }
+    }
+
+    #[test]
+    fn test_iter_average_strategies() {
+        use crate::cfr::StorageLayout;
+
+        for layout in [StorageLayout::Map, StorageLayout::Arena] {
+            let config = CFRConfig::default().with_seed(5).with_storage_layout(layout);
+            let mut solver = CFRSolver::new(KuhnPoker::new(), config);
+            solver.train(1_000);
+
+            let strategies: std::collections::HashMap<_, _> = solver.iter_average_strategies().collect();
+            assert_eq!(strategies.len(), 12);
+            for key in solver.info_set_keys() {
+                assert_eq!(strategies[&key], solver.get_average_strategy(&key, 2), "{}", key);
+            }
+        }
+    }
+
+    #[test]
+    fn test_queries_prefer_stored_action_count() {
+        let mut solver = CFRSolver::new(KuhnPoker::new(), CFRConfig::default().with_strategy_ema(0.1).with_seed(14));
+        solver.train(5_000);
+
+        assert_eq!(solver.num_actions_for("0:"), Some(2));
+        assert_eq!(solver.num_actions_for("unseen"), None);
+
+        let correct = solver.get_average_strategy("0:", 2);
+        for wrong in [1, 3, 5] {
+            assert_eq!(solver.get_average_strategy("0:", wrong), correct);
+            assert_eq!(solver.get_current_strategy("0:", wrong).len(), 2);
+            assert_eq!(solver.get_ema_strategy("0:", wrong).len(), 2);
+        }
+
+        // Known key whose regrets are all zero still reports its own count
+        solver.storage().update_regrets("flat", &[0.0; 3], false);
+        assert_eq!(solver.get_current_strategy("flat", 2), vec![1.0 / 3.0; 3]);
+        assert_eq!(solver.get_average_strategy("flat", 2), vec![1.0 / 3.0; 3]);
+        assert_eq!(solver.get_ema_strategy("flat", 2), vec![1.0 / 3.0; 3]);
+
+        // Unseen keys fall back to the caller's count
+        assert_eq!(solver.get_average_strategy("unseen", 4), vec![0.25; 4]);
+    }
+
+    #[test]
+    fn test_top_actions() {
+        let solver = CFRSolver::new(KuhnPoker::new(), CFRConfig::default());
+        let storage = solver.storage();
+        storage.update_regrets("wide", &[0.0; 5], false);
+        storage.update_strategy_sum("wide", &[0.1, 0.35, 0.05, 0.3, 0.2], 1.0);
+        storage.update_strategy_sum("wide", &[0.1, 0.35, 0.05, 0.3, 0.2], 1.0);
+
+        let top = solver.top_actions("wide", 2);
+        assert_eq!(top.len(), 2);
+        assert_eq!(top[0].0, 1);
+        assert_eq!(top[1].0, 3);
+        assert!((top[0].1 - 0.35).abs() < 1e-12);
+        assert!((top[1].1 - 0.3).abs() < 1e-12);
+
+        // n larger than the action count returns everything, sorted
+        let all: Vec<usize> = solver.top_actions("wide", 10).iter().map(|&(i, _)| i).collect();
+        assert_eq!(all, vec![1, 3, 4, 0, 2]);
+        assert!(solver.top_actions("unseen", 2).is_empty());
+    }
+
+    #[test]
+    fn test_solve_report_round_trip() {
+        use crate::cfr::SolveReport;
+
+        let mut solver = CFRSolver::new(KuhnPoker::new(), CFRConfig::default().with_seed(5));
+        solver.train(1_000);
+
+        let report = solver.report();
+        assert_eq!(report.strategies.len(), solver.num_info_sets());
+        assert_eq!(report.stats.iterations, 1_000);
+        for (key, strategy) in &report.strategies {
+            assert_eq!(strategy, &solver.get_average_strategy(key, 2), "{}", key);
+        }
+
+        let json = serde_json::to_string(&report).unwrap();
+        let restored: SolveReport = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.strategies.len(), report.strategies.len());
+        for (key, strategy) in &report.strategies {
+            for (a, b) in restored.strategies[key].iter().zip(strategy) {
+                assert!((a - b).abs() < 1e-12, "{}: {} vs {}", key, a, b);
+            }
+        }
+        assert_eq!(restored.stats.iterations, report.stats.iterations);
+        assert_eq!(restored.config.seed, Some(5));
+        assert_eq!(restored.config.regret_floor, f64::NEG_INFINITY);
+        assert_eq!(restored.abstraction_fingerprint, report.abstraction_fingerprint);
+    }
+
+    #[test]
+    fn test_interned_info_set_keys() {
+        use crate::cfr::StorageLayout;
+
+        for layout in [StorageLayout::Map, StorageLayout::Arena] {
+            let config = CFRConfig::default().with_seed(3).with_storage_layout(layout);
+            let mut solver = CFRSolver::new(KuhnPoker::new(), config);
+            solver.train(2_000);
+            let storage = solver.storage();
+
+            // Keys come back as the original strings
+            let mut keys = solver.info_set_keys();
+            keys.sort();
+            let mut expected: Vec<String> = KuhnPoker::reference_equilibrium(0.0).into_keys().collect();
+            expected.sort();
+            assert_eq!(keys, expected);
+
+            // Ids are dense, stable and agree with the string API
+            for key in &keys {
+                let id = storage.lookup(key).expect("trained key is interned");
+                assert!(id < 12);
+                assert_eq!(storage.intern(key), id);
+                assert_eq!(storage.key_of(id).as_deref(), Some(key.as_str()));
+                assert_eq!(storage.get_current_strategy_by_id(id, 2), storage.get_current_strategy(key, 2));
+            }
+
+            // Queries for unseen keys don't intern them
+            assert_eq!(storage.get_average_strategy("9:", 3), vec![1.0 / 3.0; 3]);
+            assert_eq!(storage.lookup("9:"), None);
+            assert_eq!(storage.key_of(12), None);
+        }
+    }
+
+    #[test]
+    fn test_arena_layout_matches_map_layout() {
+        use crate::cfr::StorageLayout;
+
+        for config in [CFRConfig::default().with_seed(6), CFRConfig::discounted(0.9, 0.5).with_seed(6)] {
+            let mut map = CFRSolver::new(KuhnPoker::new(), config.clone());
+            let mut arena = CFRSolver::new(
+                KuhnPoker::new(),
+                config.with_storage_layout(StorageLayout::Arena),
+            );
+            assert_eq!(arena.storage().layout(), StorageLayout::Arena);
+            map.train(2_000);
+            arena.train(2_000);
+
+            assert_eq!(arena.num_info_sets(), map.num_info_sets());
+            assert_eq!(arena.report().strategies, map.report().strategies);
+            assert_eq!(arena.storage().export().regrets, map.storage().export().regrets);
+            // Only the summation order across info sets differs
+            let ci_gap = arena.storage().calculate_exploitability_ci(2_000)
+                - map.storage().calculate_exploitability_ci(2_000);
+            assert!(ci_gap.abs() < 1e-9);
+
+            // Checkpoints move freely between layouts
+            let mut restored = CFRSolver::new(KuhnPoker::new(), CFRConfig::default());
+            restored.import_state(arena.export_state()).unwrap();
+            assert_eq!(restored.report().strategies, map.report().strategies);
+        }
+    }
+
+    #[test]
+    fn test_parallel_rejects_sequential_only_options() {
+        use crate::cfr::{ConfigError, SamplingScheme, UpdateMode};
+
+        let base = CFRConfig::default().with_seed(42);
+        assert!(base.validate_parallel().is_ok());
+        let rejected = [
+            (base.clone().with_dcfr(1.5, 0.0, 2.0), "DCFR"),
+            (base.clone().with_cfr_plus(false).with_pruning(-1.0), "regret pruning"),
+            (base.clone().with_update_mode(UpdateMode::Simultaneous), "simultaneous updates"),
+            (base.clone().with_exploration(0.6).with_sampling(SamplingScheme::Outcome), "outcome sampling"),
+        ];
+        for (config, option) in rejected {
+            assert!(config.validate().is_ok());
+            assert!(matches!(config.validate_parallel(), Err(ConfigError::SequentialOnly(o)) if o == option));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "DCFR is only supported by sequential training")]
+    fn test_train_parallel_panics_on_dcfr() {
+        let config = CFRConfig::default().with_seed(42).with_dcfr(1.5, 0.0, 2.0);
+        CFRSolver::new(KuhnPoker::new(), config).train_parallel(10, 2);
+    }
+
+    #[test]
+    fn test_outcome_sampling_converges() {
+        use crate::cfr::SamplingScheme;
+
+        let mut external = CFRSolver::new(KuhnPoker::new(), CFRConfig::default().with_seed(42));
+        external.train(20_000);
+
+        // Without exploration the traverser would never sample actions it has stopped playing
+        assert!(CFRConfig::default().with_sampling(SamplingScheme::Outcome).validate().is_err());
+
+        let config = CFRConfig::default()
+            .with_seed(42)
+            .with_exploration(0.6)
+            .with_sampling(SamplingScheme::Outcome);
+        assert!(config.validate().is_ok());
+        let mut outcome = CFRSolver::new(KuhnPoker::new(), config);
+        outcome.train(20_000);
+        // One path per traversal instead of every traverser action
+        assert!(outcome.stats().nodes_visited < external.stats().nodes_visited);
+        let early = outcome.calculate_exploitability_exact();
+
+        outcome.train(380_000);
+        let late = outcome.calculate_exploitability_exact();
+        println!("outcome sampling exploitability: {:.2} -> {:.2} mbb/g", early, late);
+        assert!(late < early, "exploitability should fall with training: {} -> {}", early, late);
+        assert!(late < 30.0, "exploitability {} mbb/g after 400k outcome-sampled iterations", late);
+    }
+
+    #[test]
+    fn test_vanilla_cfr_converges() {
+        let mut solver = CFRSolver::new(KuhnPoker::new(), CFRConfig::default());
+        for _ in 0..2_000 {
+            solver.run_iteration_vanilla();
+        }
+        assert_eq!(solver.iteration(), 2_000);
+        assert_eq!(solver.num_info_sets(), 12);
+
+        // Player 2 bluffs a Jack a third of the time in every equilibrium
+        let jack_bet = solver.get_average_strategy("0:p", 2)[1];
+        assert!((jack_bet - 1.0 / 3.0).abs() < 0.02, "jack bet frequency {}", jack_bet);
+
+        // No RNG involved: an unseeded solver lands on the same strategy
+        let mut other = CFRSolver::new(KuhnPoker::new(), CFRConfig::default());
+        for _ in 0..2_000 {
+            other.run_iteration_vanilla();
+        }
+        assert_eq!(other.report().strategies, solver.report().strategies);
+
+        // Far closer to equilibrium than MCCFR after as many iterations
+        let mut sampled = CFRSolver::new(KuhnPoker::new(), CFRConfig::default().with_seed(42));
+        sampled.train(2_000);
+        let vanilla = solver.calculate_exploitability_exact();
+        let mccfr = sampled.calculate_exploitability_exact();
+        assert!(vanilla < mccfr / 2.0, "vanilla {} vs sampled {} mbb/g", vanilla, mccfr);
+    }
+
+    /// Kuhn poker without [`Game::chance_outcomes`], so deals must be sampled.
+    #[derive(Clone)]
+    struct SampledKuhn(KuhnPoker);
+
+    impl Game for SampledKuhn {
+        type State = KuhnState;
+        type Action = KuhnAction;
+        type InfoState = KuhnInfoState;
+
+        fn initial_state(&self) -> KuhnState {
+            self.0.initial_state()
+        }
+        fn is_terminal(&self, state: &KuhnState) -> bool {
+            self.0.is_terminal(state)
+        }
+        fn get_payoff(&self, state: &KuhnState, player: usize) -> f64 {
+            self.0.get_payoff(state, player)
+        }
+        fn current_player(&self, state: &KuhnState) -> Option<usize> {
+            self.0.current_player(state)
+        }
+        fn num_players(&self) -> usize {
+            self.0.num_players()
+        }
+        fn available_actions(&self, state: &KuhnState) -> Vec<KuhnAction> {
+            self.0.available_actions(state)
+        }
+        fn apply_action(&self, state: &KuhnState, action: &KuhnAction) -> KuhnState {
+            self.0.apply_action(state, action)
+        }
+        fn info_state(&self, state: &KuhnState) -> KuhnInfoState {
+            self.0.info_state(state)
+        }
+        fn is_chance(&self, state: &KuhnState) -> bool {
+            self.0.is_chance(state)
+        }
+        fn sample_chance<R: rand::Rng>(&self, state: &KuhnState, rng: &mut R) -> KuhnState {
+            self.0.sample_chance(state, rng)
+        }
+    }
+
+    #[test]
+    fn test_vanilla_cfr_samples_unenumerable_chance() {
+        let kuhn = KuhnPoker::new();
+        let deals = kuhn.chance_outcomes(&kuhn.initial_state());
+        assert_eq!(deals.len(), 6);
+        assert!(deals.iter().all(|(state, p)| state.dealt && *p == 1.0 / 6.0));
+        let sampled = SampledKuhn(kuhn);
+        assert!(sampled.chance_outcomes(&sampled.initial_state()).is_empty());
+
+        let mut solver = CFRSolver::new(SampledKuhn(KuhnPoker::new()), CFRConfig::default().with_seed(5));
+        for _ in 0..20_000 {
+            solver.run_iteration_vanilla();
+        }
+        assert_eq!(solver.num_info_sets(), 12);
+
+        let jack_bet = solver.get_average_strategy("0:p", 2)[1];
+        assert!((jack_bet - 1.0 / 3.0).abs() < 0.05, "jack bet frequency {}", jack_bet);
+    }
+
+    #[test]
+    fn test_warm_start() {
+        // Kuhn exploitability after a few thousand sampled iterations is
+        // noisy, so compare averages over several seeds
+        const SEEDS: u64 = 4;
+        let mean = |f: &dyn Fn(u64) -> f64| (1..=SEEDS).map(f).sum::<f64>() / SEEDS as f64;
+        let checkpoint = |seed: u64| {
+            let mut prior = CFRSolver::new(KuhnPoker::new(), CFRConfig::default().with_seed(seed));
+            prior.train(10_000);
+            prior
+        };
+
+        let cold = mean(&|seed| {
+            let mut cold = CFRSolver::new(KuhnPoker::new(), CFRConfig::default().with_seed(seed + 100));
+            cold.train(2_000);
+            cold.calculate_exploitability_exact()
+        });
+
+        for scale in [1.0, 0.5, 0.0] {
+            let warm = mean(&|seed| {
+                let prior = checkpoint(seed);
+                let config = CFRConfig::default().with_seed(seed + 100).with_warm_start_regret_scale(scale);
+                let mut warm = CFRSolver::new(KuhnPoker::new(), config);
+                warm.warm_start(prior.export_state()).unwrap();
+
+                // Strategy sums carry over, regrets are rescaled
+                assert_eq!(warm.iteration(), 10_000);
+                assert_eq!(warm.get_average_strategy("0:", 2), prior.get_average_strategy("0:", 2));
+                let expected: Vec<f64> = prior.storage().regrets()["0:"].iter().map(|r| r * scale).collect();
+                assert_eq!(warm.storage().regrets()["0:"], expected);
+
+                warm.train(2_000);
+                warm.calculate_exploitability_exact()
+            });
+            assert!(warm < cold, "scale {}: warm {} vs cold {} mbb/g", scale, warm, cold);
+        }
+
+        let invalid = CFRConfig::default().with_warm_start_regret_scale(-1.0);
+        assert!(matches!(invalid.validate(), Err(crate::cfr::ConfigError::InvalidWarmStartScale(_))));
+    }
+
+    #[test]
+    fn test_dcfr_beats_cfr_plus() {
+        use crate::cfr::DcfrParams;
+
+        let dcfr = DcfrParams::default();
+        assert_eq!(dcfr.regret_factors(1), (0.5, 0.5));
+        assert_eq!(dcfr.regret_factors(4), (8.0 / 9.0, 0.5));
+        assert_eq!(dcfr.strategy_factor(1), 0.25);
+
+        // Full-tree iterations, so the comparison isn't at the mercy of sampling
+        let exploitability = |config: CFRConfig| {
+            let mut solver = CFRSolver::new(KuhnPoker::new(), config);
+            for _ in 0..1_000 {
+                solver.run_iteration_vanilla();
+            }
+            solver.calculate_exploitability_exact()
+        };
+        let dcfr = exploitability(CFRConfig::default().with_dcfr(dcfr.alpha, dcfr.beta, dcfr.gamma));
+        let cfr_plus = exploitability(CFRConfig::default());
+        let plain_cfr_plus = exploitability(CFRConfig::default().with_linear_cfr(false));
+        assert!(dcfr < cfr_plus, "DCFR {} vs CFR+ {} mbb/g", dcfr, cfr_plus);
+        assert!(dcfr < plain_cfr_plus, "DCFR {} vs CFR+ without linear averaging {} mbb/g", dcfr, plain_cfr_plus);
+    }
+
+    #[test]
+    fn test_pruning_keeps_queen_pass() {
+        // CFR+ floors regrets at zero, so pruning needs plain regrets
+        let solve = |config: CFRConfig| {
+            let mut solver = CFRSolver::new(KuhnPoker::new(), config.with_cfr_plus(false).with_seed(7));
+            solver.train(50_000);
+            solver
+        };
+        let unpruned = solve(CFRConfig::default());
+        let pruned = solve(CFRConfig::default().with_pruning(-300.0));
+
+        // The Queen never opens and always checks behind
+        for key in ["1:", "1:p"] {
+            let pass = pruned.get_average_strategy(key, 2)[0];
+            assert!(pass > 0.99, "Queen passes {} at {}", pass, key);
+        }
+        assert!(
+            pruned.stats().nodes_visited < unpruned.stats().nodes_visited,
+            "pruned {} vs unpruned {} nodes",
+            pruned.stats().nodes_visited,
+            unpruned.stats().nodes_visited
         );
+        let exploitability = pruned.calculate_exploitability_exact();
+        assert!(exploitability < 60.0, "pruned exploitability {} mbb/g", exploitability);
 
-        println!("Kuhn Poker CFR convergence test passed!");
+        let mut config = CFRConfig::default().with_pruning(-300.0);
+        config.pruning.as_mut().unwrap().probability = 1.5;
+        assert!(config.validate().is_err());
     }
 }
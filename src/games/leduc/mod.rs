@@ -0,0 +1,575 @@
+//! Leduc Hold'em implementation for CFR validation.
+//!
+//! Leduc Hold'em is the standard step up from Kuhn Poker: still small
+//! enough to solve in seconds, but with two betting rounds, a public board
+//! card and a few hundred info sets, so it exercises multi-street play that
+//! Kuhn cannot.
+//!
+//! ## Game Rules
+//!
+//! - 6 cards: Jack, Queen and King in two suits
+//! - 2 players, each antes 1 chip and receives 1 private card
+//! - Round 1: bets and raises are 2 chips
+//! - One public board card is revealed
+//! - Round 2: bets and raises are 4 chips
+//! - At most 2 raises (a bet and a raise) per round; Player 1 acts first in
+//!   both rounds
+//! - At showdown a card pairing the board wins, otherwise the higher rank
+//!   wins; equal ranks split the pot
+//!
+//! ## Known Game Value
+//!
+//! **Expected Value**: Player 1 EV ≈ -0.0856
+
+use rand::Rng;
+use std::fmt;
+
+use crate::cfr::game::{Action, Game, GameState, InfoState};
+
+/// Number of cards in the deck.
+const DECK_SIZE: u8 = 6;
+
+/// Maximum number of bets and raises in one betting round.
+const MAX_RAISES: u8 = 2;
+
+/// Bet and raise size for each round.
+const BET_SIZES: [i32; 2] = [2, 4];
+
+/// Rank (0=Jack, 1=Queen, 2=King) of a card in `0..DECK_SIZE`.
+fn rank(card: u8) -> u8 {
+    card / 2
+}
+
+/// Display name of a rank.
+fn rank_name(rank: u8) -> &'static str {
+    match rank {
+        0 => "J",
+        1 => "Q",
+        2 => "K",
+        _ => "?",
+    }
+}
+
+/// Actions in Leduc Hold'em.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LeducAction {
+    /// Fold (only when facing a bet)
+    Fold,
+    /// Call (check if no bet)
+    Call,
+    /// Raise (bet if no bet)
+    Raise,
+}
+
+impl Action for LeducAction {
+    fn to_string(&self) -> String {
+        match self {
+            LeducAction::Fold => "f".to_string(),
+            LeducAction::Call => "c".to_string(),
+            LeducAction::Raise => "r".to_string(),
+        }
+    }
+}
+
+impl fmt::Display for LeducAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LeducAction::Fold => write!(f, "Fold"),
+            LeducAction::Call => write!(f, "Call"),
+            LeducAction::Raise => write!(f, "Raise"),
+        }
+    }
+}
+
+/// Information state in Leduc Hold'em.
+///
+/// What a player knows: the rank of their card, the board rank once it is
+/// revealed, and the public action history. Suits never matter for the
+/// outcome, so they are left out and suit-isomorphic deals share info sets.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LeducInfoState {
+    /// Rank of the player's card (0=Jack, 1=Queen, 2=King)
+    pub rank: u8,
+    /// Rank of the board card, once revealed
+    pub board_rank: Option<u8>,
+    /// Action history, rounds separated by '/' (e.g., "rc/r")
+    pub history: String,
+}
+
+impl InfoState for LeducInfoState {
+    fn key(&self) -> String {
+        match self.board_rank {
+            Some(board) => format!("{}{}:{}", self.rank, board, self.history),
+            None => format!("{}:{}", self.rank, self.history),
+        }
+    }
+}
+
+impl fmt::Display for LeducInfoState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let board = self.board_rank.map_or("-", rank_name);
+        write!(f, "{}|{}|{}", rank_name(self.rank), board, self.history)
+    }
+}
+
+/// Complete game state in Leduc Hold'em.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeducState {
+    /// Private card of each player, in `0..6` (rank * 2 + suit)
+    pub cards: [u8; 2],
+    /// Public board card, once revealed
+    pub board: Option<u8>,
+    /// Action history, rounds separated by '/'
+    pub history: String,
+    /// Amount each player has invested in the pot
+    pub pot: [i32; 2],
+    /// Current betting round (0 or 1); 2 once the second round has closed
+    pub round: u8,
+    /// Bets and raises made in the current round
+    pub raises: u8,
+    /// Actions taken in the current round
+    pub round_actions: u8,
+    /// Player to act
+    pub to_act: usize,
+    /// Player who folded, if any
+    pub folded: Option<usize>,
+    /// Whether private cards have been dealt (for chance node handling)
+    pub dealt: bool,
+}
+
+impl GameState for LeducState {}
+
+impl Default for LeducState {
+    fn default() -> Self {
+        Self {
+            cards: [0, 0],
+            board: None,
+            history: String::new(),
+            pot: [1, 1], // Both ante 1
+            round: 0,
+            raises: 0,
+            round_actions: 0,
+            to_act: 0,
+            folded: None,
+            dealt: false,
+        }
+    }
+}
+
+impl fmt::Display for LeducState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let board = self.board.map_or("-", |c| rank_name(rank(c)));
+        write!(
+            f,
+            "P1:{} P2:{} Board:{} History:{} Pot:{:?}",
+            rank_name(rank(self.cards[0])),
+            rank_name(rank(self.cards[1])),
+            board,
+            self.history,
+            self.pot
+        )
+    }
+}
+
+impl LeducState {
+    /// Close the current betting round, moving to the board deal or showdown.
+    fn end_round(&mut self) {
+        self.round += 1;
+        self.raises = 0;
+        self.round_actions = 0;
+        self.to_act = 0;
+        if self.round == 1 {
+            self.history.push('/');
+        }
+    }
+}
+
+/// Leduc Hold'em game.
+#[derive(Debug, Clone, Default)]
+pub struct LeducPoker;
+
+impl LeducPoker {
+    /// Create a new Leduc Hold'em game.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Get card name for display.
+    pub fn card_name(card: u8) -> &'static str {
+        match rank(card) {
+            0 => "Jack",
+            1 => "Queen",
+            2 => "King",
+            _ => "Unknown",
+        }
+    }
+
+    /// Showdown strength of `card` with `board`: pairs beat every high card.
+    fn hand_strength(card: u8, board: u8) -> u8 {
+        if rank(card) == rank(board) {
+            3 + rank(card)
+        } else {
+            rank(card)
+        }
+    }
+
+    /// A card from `0..DECK_SIZE` not in `used`, drawn uniformly.
+    fn draw<R: Rng>(used: &[u8], rng: &mut R) -> u8 {
+        let remaining: Vec<u8> = (0..DECK_SIZE).filter(|c| !used.contains(c)).collect();
+        remaining[rng.gen_range(0..remaining.len())]
+    }
+}
+
+impl Game for LeducPoker {
+    type State = LeducState;
+    type Action = LeducAction;
+    type InfoState = LeducInfoState;
+
+    fn initial_state(&self) -> Self::State {
+        LeducState::default()
+    }
+
+    fn is_terminal(&self, state: &Self::State) -> bool {
+        state.folded.is_some() || state.round == 2
+    }
+
+    fn get_payoff(&self, state: &Self::State, player: usize) -> f64 {
+        debug_assert!(self.is_terminal(state), "get_payoff called on non-terminal state");
+
+        let opponent = 1 - player;
+        if let Some(folder) = state.folded {
+            return if folder == player {
+                -state.pot[player] as f64
+            } else {
+                state.pot[opponent] as f64
+            };
+        }
+
+        let board = state.board.expect("showdown without a board card");
+        let mine = Self::hand_strength(state.cards[player], board);
+        let theirs = Self::hand_strength(state.cards[opponent], board);
+        match mine.cmp(&theirs) {
+            std::cmp::Ordering::Greater => state.pot[opponent] as f64,
+            std::cmp::Ordering::Less => -state.pot[player] as f64,
+            std::cmp::Ordering::Equal => 0.0,
+        }
+    }
+
+    fn current_player(&self, state: &Self::State) -> Option<usize> {
+        if self.is_terminal(state) || self.is_chance(state) {
+            return None;
+        }
+        Some(state.to_act)
+    }
+
+    fn num_players(&self) -> usize {
+        2
+    }
+
+    fn available_actions(&self, state: &Self::State) -> Vec<Self::Action> {
+        if self.is_terminal(state) || self.is_chance(state) {
+            return vec![];
+        }
+
+        let mut actions = Vec::with_capacity(3);
+        if state.pot[0] != state.pot[1] {
+            actions.push(LeducAction::Fold);
+        }
+        actions.push(LeducAction::Call);
+        if state.raises < MAX_RAISES {
+            actions.push(LeducAction::Raise);
+        }
+        actions
+    }
+
+    fn apply_action(&self, state: &Self::State, action: &Self::Action) -> Self::State {
+        let mut new_state = state.clone();
+        let player = state.to_act;
+        let opponent = 1 - player;
+        new_state.history.push_str(&Action::to_string(action));
+        new_state.round_actions += 1;
+
+        match action {
+            LeducAction::Fold => {
+                new_state.folded = Some(player);
+            }
+            LeducAction::Call => {
+                let facing_bet = state.pot[opponent] > state.pot[player];
+                new_state.pot[player] = state.pot[opponent];
+                // A call, or a check behind a check, closes the round
+                if facing_bet || state.round_actions > 0 {
+                    new_state.end_round();
+                } else {
+                    new_state.to_act = opponent;
+                }
+            }
+            LeducAction::Raise => {
+                new_state.pot[player] = state.pot[opponent] + BET_SIZES[state.round as usize];
+                new_state.raises += 1;
+                new_state.to_act = opponent;
+            }
+        }
+
+        new_state
+    }
+
+    fn info_state(&self, state: &Self::State) -> Self::InfoState {
+        let player = self.current_player(state).unwrap_or(0);
+        LeducInfoState {
+            rank: rank(state.cards[player]),
+            board_rank: state.board.map(rank),
+            history: state.history.clone(),
+        }
+    }
+
+    fn is_chance(&self, state: &Self::State) -> bool {
+        // Private cards before round 1, the board card before round 2
+        !state.dealt || (state.round == 1 && state.board.is_none())
+    }
+
+    fn sample_chance<R: Rng>(&self, state: &Self::State, rng: &mut R) -> Self::State {
+        debug_assert!(self.is_chance(state), "sample_chance called on non-chance state");
+
+        let mut new_state = state.clone();
+        if !state.dealt {
+            let first = Self::draw(&[], rng);
+            let second = Self::draw(&[first], rng);
+            new_state.cards = [first, second];
+            new_state.dealt = true;
+        } else {
+            new_state.board = Some(Self::draw(&state.cards, rng));
+        }
+        new_state
+    }
+
+    fn chance_outcomes(&self, state: &Self::State) -> Vec<(Self::State, f64)> {
+        let outcomes: Vec<LeducState> = if !state.dealt {
+            (0..DECK_SIZE)
+                .flat_map(|first| (0..DECK_SIZE).filter(move |&c| c != first).map(move |second| [first, second]))
+                .map(|cards| LeducState { cards, dealt: true, ..state.clone() })
+                .collect()
+        } else {
+            (0..DECK_SIZE)
+                .filter(|c| !state.cards.contains(c))
+                .map(|board| LeducState { board: Some(board), ..state.clone() })
+                .collect()
+        };
+        let p = 1.0 / outcomes.len() as f64;
+        outcomes.into_iter().map(|s| (s, p)).collect()
+    }
+
+    fn action_name(&self, action: &Self::Action) -> String {
+        format!("{}", action)
+    }
+
+    fn state_description(&self, state: &Self::State) -> String {
+        format!("{}", state)
+    }
+
+    fn check_invariants(&self, state: &Self::State) -> Result<(), String> {
+        let max_pot = 1 + MAX_RAISES as i32 * (BET_SIZES[0] + BET_SIZES[1]);
+        if state.pot.iter().any(|&p| !(1..=max_pot).contains(&p)) {
+            return Err(format!("contribution out of range: {:?}", state.pot));
+        }
+        if state.raises > MAX_RAISES {
+            return Err(format!("{} raises in one round", state.raises));
+        }
+        if state.round == 2 && state.pot[0] != state.pot[1] {
+            return Err(format!("showdown with unequal contributions: {:?}", state.pot));
+        }
+        if state.board.is_some_and(|b| state.cards.contains(&b)) {
+            return Err("board card duplicates a private card".to_string());
+        }
+        Ok(())
+    }
+
+    fn max_depth(&self) -> Option<usize> {
+        // Per round at most check-bet-raise-call, plus the two deals
+        Some(10)
+    }
+
+    fn estimated_info_sets(&self) -> Option<usize> {
+        // 3 ranks x 6 round-1 decision histories, plus 3 x 3 rank/board
+        // pairs x 5 non-folding round-1 histories x 6 round-2 decision histories
+        Some(3 * 6 + 3 * 3 * 5 * 6)
+    }
+
+    fn describe_info_state(&self, info: &Self::InfoState) -> String {
+        let card = LeducPoker::card_name(info.rank * 2);
+        let board = match info.board_rank {
+            Some(board) => format!(", board {}", LeducPoker::card_name(board * 2)),
+            None => String::new(),
+        };
+        if info.history.is_empty() {
+            return format!("{}{}, first to act", card, board);
+        }
+        let history: Vec<&str> = info
+            .history
+            .chars()
+            .map(|c| match c {
+                'f' => "Fold",
+                'c' => "Call",
+                'r' => "Raise",
+                _ => "Board",
+            })
+            .collect();
+        format!("{}{} after {}", card, board, history.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cfr::{CFRConfig, CFRSolver};
+
+    /// Dealt state with the given private cards, before any action.
+    fn dealt(cards: [u8; 2]) -> LeducState {
+        LeducState { cards, dealt: true, ..LeducState::default() }
+    }
+
+    fn play(game: &LeducPoker, mut state: LeducState, actions: &[LeducAction]) -> LeducState {
+        for action in actions {
+            assert!(game.available_actions(&state).contains(action), "{:?} not legal at {}", action, state);
+            state = game.apply_action(&state, action);
+        }
+        state
+    }
+
+    /// Exact value for player 0 when both players follow the solver's average
+    /// strategy, enumerating every deal.
+    fn game_value(solver: &CFRSolver<LeducPoker>, state: &LeducState) -> f64 {
+        let game = solver.game();
+        if game.is_terminal(state) {
+            return game.get_payoff(state, 0);
+        }
+        if game.is_chance(state) {
+            let outcomes = game.chance_outcomes(state);
+            return outcomes.iter().map(|(s, p)| p * game_value(solver, s)).sum();
+        }
+
+        let actions = game.available_actions(state);
+        let key = game.info_state(state).key();
+        let strategy = solver.get_average_strategy(&key, actions.len());
+        actions
+            .iter()
+            .zip(&strategy)
+            .map(|(action, p)| p * game_value(solver, &game.apply_action(state, action)))
+            .sum()
+    }
+
+    #[test]
+    fn test_leduc_betting_rounds() {
+        let game = LeducPoker::new();
+        let state = game.initial_state();
+        assert!(game.is_chance(&state));
+
+        // Check-check closes round 1 and waits for the board card
+        let state = play(&game, dealt([0, 2]), &[LeducAction::Call, LeducAction::Call]);
+        assert_eq!(state.history, "cc/");
+        assert!(game.is_chance(&state));
+        assert_eq!(game.current_player(&state), None);
+
+        // Bet and raise are capped at two per round, and fold needs a bet
+        let state = LeducState { board: Some(1), ..state };
+        assert_eq!(game.current_player(&state), Some(0));
+        assert_eq!(game.available_actions(&state), vec![LeducAction::Call, LeducAction::Raise]);
+        let state = play(&game, state, &[LeducAction::Raise, LeducAction::Raise]);
+        assert_eq!(state.pot, [5, 9]);
+        assert_eq!(game.available_actions(&state), vec![LeducAction::Fold, LeducAction::Call]);
+
+        // Calling goes to showdown, where the Jack pairing the board wins
+        let state = play(&game, state, &[LeducAction::Call]);
+        assert!(game.is_terminal(&state));
+        assert_eq!(state.pot, [9, 9]);
+        assert_eq!(game.get_payoff(&state, 0), 9.0);
+        assert_eq!(game.get_payoff(&state, 1), -9.0);
+    }
+
+    #[test]
+    fn test_leduc_payoffs() {
+        let game = LeducPoker::new();
+
+        // Fold loses only what was put in
+        let state = play(&game, dealt([4, 0]), &[LeducAction::Raise, LeducAction::Fold]);
+        assert!(game.is_terminal(&state));
+        assert_eq!(game.get_payoff(&state, 0), 1.0);
+        assert_eq!(game.get_payoff(&state, 1), -1.0);
+
+        // Higher rank wins without a pair; equal ranks split
+        let checked = play(&game, dealt([4, 2]), &[LeducAction::Call, LeducAction::Call]);
+        let showdown = |board, cards| {
+            let state = LeducState { board: Some(board), cards, ..checked.clone() };
+            play(&game, state, &[LeducAction::Call, LeducAction::Call])
+        };
+        assert_eq!(game.get_payoff(&showdown(0, [4, 2]), 0), 1.0);
+        assert_eq!(game.get_payoff(&showdown(3, [4, 2]), 0), -1.0);
+        assert_eq!(game.get_payoff(&showdown(0, [4, 5]), 0), 0.0);
+    }
+
+    #[test]
+    fn test_leduc_info_state_hides_opponent_card() {
+        let game = LeducPoker::new();
+
+        // Player 1 sees only their own card, whatever the opponent holds
+        let vs_jack = game.apply_action(&dealt([0, 4]), &LeducAction::Raise);
+        let vs_queen = game.apply_action(&dealt([2, 5]), &LeducAction::Raise);
+        assert_eq!(game.info_state(&vs_jack).key(), "2:r");
+        assert_eq!(game.info_state(&vs_jack), game.info_state(&vs_queen));
+
+        // The board rank is public and joins the key once dealt
+        let state = play(&game, dealt([0, 4]), &[LeducAction::Call, LeducAction::Call]);
+        let state = LeducState { board: Some(3), ..state };
+        assert_eq!(game.info_state(&state).key(), "01:cc/");
+        let other_suits = LeducState { cards: [1, 5], board: Some(2), ..state.clone() };
+        assert_eq!(game.info_state(&state), game.info_state(&other_suits));
+        let other_hand = LeducState { cards: [0, 2], ..state.clone() };
+        assert_eq!(game.info_state(&state), game.info_state(&other_hand));
+    }
+
+    #[test]
+    fn test_leduc_chance_deals_distinct_cards() {
+        let game = LeducPoker::new();
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..200 {
+            let state = game.sample_chance(&game.initial_state(), &mut rng);
+            assert!(state.dealt && state.cards[0] != state.cards[1]);
+            let state = play(&game, state, &[LeducAction::Call, LeducAction::Call]);
+            let state = game.sample_chance(&state, &mut rng);
+            let board = state.board.expect("board dealt");
+            assert!(!state.cards.contains(&board) && board < DECK_SIZE);
+            assert!(!game.is_chance(&state));
+        }
+    }
+
+    #[test]
+    fn test_random_playout_invariants() {
+        crate::cfr::assert_game_invariants(&LeducPoker::new(), 1_000);
+    }
+
+    #[test]
+    fn test_leduc_cfr_convergence() {
+        let game = LeducPoker::new();
+        let config = CFRConfig::default().with_seed(42);
+        let mut solver = CFRSolver::new(game.clone(), config);
+        solver.train(100_000);
+
+        assert_eq!(Some(solver.num_info_sets()), game.estimated_info_sets());
+
+        let value = game_value(&solver, &game.initial_state());
+        println!("Leduc player 1 value = {:.4}", value);
+        assert!((value - (-0.0856)).abs() < 0.01, "player 1 value {} should be about -0.0856", value);
+
+        let exploitability = solver.calculate_exploitability_exact();
+        println!("Leduc exact exploitability = {:.1} mbb/g", exploitability);
+        assert!(exploitability < 200.0, "exploitability {} mbb/g after 100k iterations", exploitability);
+    }
+
+    #[test]
+    fn test_exact_exploitability_of_uniform_strategy() {
+        // Untrained, both players play uniformly at random
+        let solver = CFRSolver::new(LeducPoker::new(), CFRConfig::default());
+        let exploitability = solver.calculate_exploitability_exact();
+        assert!((exploitability - 4747.222).abs() < 1e-2, "uniform exploitability {}", exploitability);
+    }
+}
@@ -13,6 +13,8 @@
 //! ## Available Games
 //!
 //! - [`kuhn`]: Kuhn Poker - A simplified 3-card poker game with known Nash equilibrium
+//! - [`leduc`]: Leduc Hold'em - A 6-card, two-round poker game with a known game value
+//! - [`rps`]: Rock-Paper-Scissors - A one-shot matrix game for checking regret matching
 //! - [`preflop`]: Texas Hold'em preflop solver (planned)
 //!
 //! ## Adding New Games
@@ -21,12 +23,77 @@
 //!
 //! 1. Create a new module under `src/games/`
 //! 2. Define state, action, and info state types
-//! 3. Implement the `Game` trait
+//! 3. Implement the `Game` trait; only the core methods are required, and
+//!    hooks such as `action_name` and `state_description` fall back to the
+//!    action's `to_string` and the state's `Debug` output
 //! 4. Add tests that verify expected behavior
 //!
 //! See the [`kuhn`] module for a complete example.
+//!
+//! ## Reproducibility
+//!
+//! Every game here must be reproducible: two solvers built from the same
+//! [`CFRConfig::deterministic`](crate::cfr::CFRConfig::deterministic) seed
+//! and trained for the same number of iterations produce identical strategy
+//! tables. All randomness (chance sampling, card abstraction, equity
+//! estimates) has to come from the solver's RNG or be seeded from the game
+//! state. The cross-game test in this module is the regression guard for
+//! that contract; new games should be added to it.
 
 pub mod kuhn;
+pub mod leduc;
 pub mod preflop;
 pub mod preflop_8max;
 pub mod preflop_ranges;
+pub mod rps;
+
+#[cfg(test)]
+mod tests {
+    use crate::cfr::{CFRConfig, CFRSolver, Game};
+    use crate::games::kuhn::KuhnPoker;
+    use crate::games::leduc::LeducPoker;
+    use crate::games::preflop::{AbstractionConfig, SBvsBBConfig, SBvsBBFullGame};
+    use crate::games::preflop_8max::Preflop8MaxGame;
+    use crate::games::preflop_ranges::{Position, PreflopRangeConfig, PreflopRangeGame, Scenario};
+    use crate::games::rps::RockPaperScissors;
+
+    /// Train two solvers from the same deterministic config and compare.
+    fn assert_reproducible<G: Game + Clone>(name: &str, game: G, iterations: u64) {
+        let solve = |game: G| {
+            let mut solver = CFRSolver::new(game, CFRConfig::deterministic(2024));
+            solver.train(iterations);
+            solver.report().strategies
+        };
+
+        let first = solve(game.clone());
+        let second = solve(game);
+        assert!(!first.is_empty(), "{} produced no strategies", name);
+        assert!(first == second, "{} is not reproducible from a fixed seed", name);
+    }
+
+    #[test]
+    fn test_all_games_reproducible() {
+        assert_reproducible("Kuhn", KuhnPoker::new(), 2_000);
+        assert_reproducible("Leduc", LeducPoker::new(), 2_000);
+        assert_reproducible("Rock-Paper-Scissors", RockPaperScissors::biased(2.0), 2_000);
+        assert_reproducible(
+            "preflop ranges",
+            PreflopRangeGame::new(Scenario::RFI { position: Position::CO }, PreflopRangeConfig::default()),
+            2_000,
+        );
+        assert_reproducible("8-max preflop", Preflop8MaxGame::new(), 200);
+
+        // Coarse abstraction: bucket lookups dominate full-game iteration cost
+        let full_game = SBvsBBFullGame::with_config(SBvsBBConfig {
+            abstraction: AbstractionConfig {
+                flop_buckets: 8,
+                turn_buckets: 8,
+                river_buckets: 8,
+                equity_samples: 8,
+                ..AbstractionConfig::default()
+            },
+            ..SBvsBBConfig::fast()
+        });
+        assert_reproducible("SB vs BB full game", full_game, 50);
+    }
+}
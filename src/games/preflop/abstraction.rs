@@ -2,10 +2,61 @@
 //!
 //! This module provides card abstraction to reduce the state space of the poker game.
 //! - Preflop: 169 hand classes (direct mapping)
-//! - Postflop: Equity-based bucketing into configurable number of buckets
+//! - Postflop: per-street mode, either equity-based bucketing into a
+//!   configurable number of buckets, equity-histogram bucketing, or exact
+//!   (one bucket per hole-card combo)
+//!
+//! Equity estimates are memoized per hand and board, so repeated lookups
+//! during solving skip the equity sampler.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+
+use rand::Rng;
+
+use super::card::{Card, HoleCards, Board, Street};
+use super::hand::Range;
+use super::hand_eval::{calculate_equity_vs_random_with_rng, equity_distribution_with_rng};
+
+/// Number of combos of two distinct cards, and so of exact buckets.
+pub const NUM_HOLE_CARD_COMBOS: u16 = 1326;
+
+/// Equity bins in the river-equity histogram used by [`AbstractionMode::Histogram`].
+const HISTOGRAM_BINS: usize = 10;
+
+/// Spread levels a [`AbstractionMode::Histogram`] bucket count is split into.
+const HISTOGRAM_SPREAD_LEVELS: u16 = 4;
+
+/// Most equities an abstraction memoizes; later hands are computed uncached.
+const EQUITY_CACHE_CAPACITY: usize = 1 << 22;
+
+/// How hands are bucketed on one postflop street.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AbstractionMode {
+    /// No abstraction: every hole-card combo gets its own bucket, so the
+    /// street's bucket count is ignored.
+    Exact,
+    /// Bucket by equity against a random hand.
+    #[default]
+    Equity,
+    /// Bucket by the shape of the hand's river-equity histogram against a
+    /// random hand: buckets form a grid of mean equity by spread, so a draw
+    /// and a made hand of the same mean equity land apart. Each lookup
+    /// evaluates `equity_samples` runouts against every villain combo, so
+    /// this is far slower than [`AbstractionMode::Equity`].
+    Histogram,
+}
 
-use super::card::{HoleCards, Board, Street};
-use super::hand_eval::calculate_equity_vs_random;
+impl AbstractionMode {
+    fn name(self) -> &'static str {
+        match self {
+            AbstractionMode::Exact => "exact",
+            AbstractionMode::Equity => "equity",
+            AbstractionMode::Histogram => "histogram",
+        }
+    }
+}
 
 /// Configuration for card abstraction.
 #[derive(Debug, Clone)]
@@ -18,6 +69,12 @@ pub struct AbstractionConfig {
     pub river_buckets: u16,
     /// Number of samples for equity calculation
     pub equity_samples: usize,
+    /// Bucketing mode for the flop (default: equity)
+    pub flop_mode: AbstractionMode,
+    /// Bucketing mode for the turn (default: equity)
+    pub turn_mode: AbstractionMode,
+    /// Bucketing mode for the river (default: equity)
+    pub river_mode: AbstractionMode,
 }
 
 impl Default for AbstractionConfig {
@@ -27,11 +84,56 @@ impl Default for AbstractionConfig {
             turn_buckets: 256,
             river_buckets: 256,
             equity_samples: 500,
+            flop_mode: AbstractionMode::Equity,
+            turn_mode: AbstractionMode::Equity,
+            river_mode: AbstractionMode::Equity,
         }
     }
 }
 
 impl AbstractionConfig {
+    /// Compact description of the settings that determine bucket keys.
+    ///
+    /// Modes are only listed when some street is not equity-bucketed, so
+    /// fingerprints from before per-street modes still match.
+    pub fn fingerprint(&self) -> String {
+        let mut fingerprint = format!(
+            "buckets:{}/{}/{};samples:{}",
+            self.flop_buckets, self.turn_buckets, self.river_buckets, self.equity_samples
+        );
+        let modes = [self.flop_mode, self.turn_mode, self.river_mode];
+        if modes.iter().any(|&mode| mode != AbstractionMode::Equity) {
+            let names: Vec<&str> = modes.iter().map(|mode| mode.name()).collect();
+            fingerprint.push_str(&format!(";modes:{}", names.join("/")));
+        }
+        fingerprint
+    }
+
+    /// Bucketing mode for a street. Preflop always buckets by the 169 hand
+    /// classes and reports the default mode; showdown follows the river.
+    pub fn mode(&self, street: Street) -> AbstractionMode {
+        match street {
+            Street::Preflop => AbstractionMode::Equity,
+            Street::Flop => self.flop_mode,
+            Street::Turn => self.turn_mode,
+            Street::River | Street::Showdown => self.river_mode,
+        }
+    }
+
+    /// Builder method: set the bucketing mode for a postflop street.
+    ///
+    /// # Panics
+    /// Panics for [`Street::Preflop`], which always uses hand classes.
+    pub fn with_mode(mut self, street: Street, mode: AbstractionMode) -> Self {
+        match street {
+            Street::Preflop => panic!("preflop always buckets by hand class"),
+            Street::Flop => self.flop_mode = mode,
+            Street::Turn => self.turn_mode = mode,
+            Street::River | Street::Showdown => self.river_mode = mode,
+        }
+        self
+    }
+
     /// Create a fast configuration with fewer buckets for testing.
     pub fn fast() -> Self {
         Self {
@@ -39,6 +141,7 @@ impl AbstractionConfig {
             turn_buckets: 50,
             river_buckets: 50,
             equity_samples: 100,
+            ..Self::default()
         }
     }
 
@@ -49,36 +152,84 @@ impl AbstractionConfig {
             turn_buckets: 512,
             river_buckets: 512,
             equity_samples: 1000,
+            ..Self::default()
         }
     }
 }
 
 /// Card abstraction system for poker.
+///
+/// Clones share one equity cache, so every copy of a game benefits from
+/// lookups made by the others, including across threads.
 #[derive(Debug, Clone)]
 pub struct CardAbstraction {
     config: AbstractionConfig,
+    equity_cache: Arc<EquityCache>,
+}
+
+/// Thread-safe memo of equity estimates, keyed by [`equity_seed`].
+///
+/// The seed encodes the hole cards and the sorted board, which is everything
+/// the estimate depends on, so a hit returns exactly what the sampler would.
+/// The hand class alone would not do: suits decide flush draws.
+#[derive(Debug, Default)]
+struct EquityCache {
+    equities: RwLock<HashMap<u64, f64>>,
+    /// Number of times the equity sampler ran
+    evaluations: AtomicU64,
 }
 
 impl CardAbstraction {
     /// Create a new card abstraction with default configuration.
     pub fn new() -> Self {
-        Self {
-            config: AbstractionConfig::default(),
-        }
+        Self::with_config(AbstractionConfig::default())
     }
 
     /// Create with custom configuration.
     pub fn with_config(config: AbstractionConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            equity_cache: Arc::default(),
+        }
+    }
+
+    /// Number of (hand, board) equities currently memoized.
+    pub fn cached_equities(&self) -> usize {
+        self.equity_cache.equities.read().unwrap().len()
+    }
+
+    /// Number of equity estimates computed so far, i.e. cache misses.
+    pub fn equity_evaluations(&self) -> u64 {
+        self.equity_cache.evaluations.load(Ordering::Relaxed)
+    }
+
+    /// Drop every memoized equity.
+    pub fn clear_equity_cache(&self) {
+        self.equity_cache.equities.write().unwrap().clear();
     }
 
     /// Get the abstracted bucket for a hand on a given street.
     pub fn get_bucket(&self, hole_cards: &HoleCards, board: &Board) -> u16 {
-        match board.street() {
-            Street::Preflop => self.preflop_bucket(hole_cards),
-            Street::Flop => self.postflop_bucket(hole_cards, board, self.config.flop_buckets),
-            Street::Turn => self.postflop_bucket(hole_cards, board, self.config.turn_buckets),
-            Street::River | Street::Showdown => self.postflop_bucket(hole_cards, board, self.config.river_buckets),
+        let street = board.street();
+        if street == Street::Preflop {
+            return self.preflop_bucket(hole_cards);
+        }
+
+        let num_buckets = self.street_buckets(street);
+        match self.config.mode(street) {
+            AbstractionMode::Exact => exact_bucket(hole_cards),
+            AbstractionMode::Equity => self.postflop_bucket(hole_cards, board, num_buckets),
+            AbstractionMode::Histogram => self.histogram_bucket(hole_cards, board, num_buckets),
+        }
+    }
+
+    /// Configured bucket count for a postflop street.
+    fn street_buckets(&self, street: Street) -> u16 {
+        match street {
+            Street::Preflop => 169,
+            Street::Flop => self.config.flop_buckets,
+            Street::Turn => self.config.turn_buckets,
+            Street::River | Street::Showdown => self.config.river_buckets,
         }
     }
 
@@ -89,21 +240,97 @@ impl CardAbstraction {
     }
 
     /// Get postflop bucket based on equity vs random hands.
+    ///
+    /// The equity sample is seeded from the cards, so a given hand on a given
+    /// board always lands in the same bucket.
     fn postflop_bucket(&self, hole_cards: &HoleCards, board: &Board, num_buckets: u16) -> u16 {
-        let equity = calculate_equity_vs_random(hole_cards, board, self.config.equity_samples);
+        let equity = self.equity(hole_cards, board);
         // Map equity [0, 1] to bucket [0, num_buckets-1]
         let bucket = (equity * num_buckets as f64).floor() as u16;
         bucket.min(num_buckets - 1)
     }
 
+    /// Seeded equity vs random hands, memoized per hand and board.
+    fn equity(&self, hole_cards: &HoleCards, board: &Board) -> f64 {
+        use rand::SeedableRng;
+
+        let seed = equity_seed(hole_cards, board);
+        if let Some(&equity) = self.equity_cache.equities.read().unwrap().get(&seed) {
+            return equity;
+        }
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+        let equity = calculate_equity_vs_random_with_rng(hole_cards, board, self.config.equity_samples, &mut rng);
+        self.equity_cache.evaluations.fetch_add(1, Ordering::Relaxed);
+
+        let mut equities = self.equity_cache.equities.write().unwrap();
+        if equities.len() < EQUITY_CACHE_CAPACITY {
+            equities.insert(seed, equity);
+        }
+        equity
+    }
+
+    /// Get postflop bucket from the mean and spread of the hand's
+    /// river-equity histogram vs random hands.
+    ///
+    /// Buckets are laid out mean-major: `num_buckets` is split into up to
+    /// [`HISTOGRAM_SPREAD_LEVELS`] spread levels per mean level, so with
+    /// fewer buckets than that this reduces to mean-equity bucketing.
+    fn histogram_bucket(&self, hole_cards: &HoleCards, board: &Board, num_buckets: u16) -> u16 {
+        use rand::SeedableRng;
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(equity_seed(hole_cards, board));
+        let histogram = equity_distribution_with_rng(
+            hole_cards,
+            board,
+            &Range::all(),
+            HISTOGRAM_BINS,
+            self.config.equity_samples,
+            &mut rng,
+        );
+
+        // Moments of the histogram, taking each bin at its midpoint
+        let midpoint = |bin: usize| (bin as f64 + 0.5) / HISTOGRAM_BINS as f64;
+        let mean: f64 = histogram.iter().enumerate().map(|(bin, &p)| p * midpoint(bin)).sum();
+        let variance: f64 = histogram
+            .iter()
+            .enumerate()
+            .map(|(bin, &p)| p * (midpoint(bin) - mean).powi(2))
+            .sum();
+
+        let spread_levels = if num_buckets >= HISTOGRAM_SPREAD_LEVELS { HISTOGRAM_SPREAD_LEVELS } else { 1 };
+        let mean_levels = num_buckets / spread_levels;
+        // Equity lies in [0, 1], so its standard deviation is at most 0.5
+        let spread = ((variance.sqrt() * 2.0 * spread_levels as f64) as u16).min(spread_levels - 1);
+        let level = ((mean * mean_levels as f64) as u16).min(mean_levels - 1);
+        level * spread_levels + spread
+    }
+
+    /// All hole-card combos not blocked by `board` that map to `bucket`.
+    pub fn combos_in_bucket(&self, bucket: u16, board: &Board) -> Vec<HoleCards> {
+        let mut combos = Vec::new();
+        for id1 in 0..52u8 {
+            for id2 in (id1 + 1)..52u8 {
+                let Some(hole_cards) = HoleCards::from_ids(id1, id2) else {
+                    continue;
+                };
+                if hole_cards.cards().iter().any(|&c| board.contains(c)) {
+                    continue;
+                }
+                if self.get_bucket(&hole_cards, board) == bucket {
+                    combos.push(hole_cards);
+                }
+            }
+        }
+        combos
+    }
+
     /// Get the number of buckets for a street.
     pub fn num_buckets(&self, street: Street) -> u16 {
-        match street {
-            Street::Preflop => 169,
-            Street::Flop => self.config.flop_buckets,
-            Street::Turn => self.config.turn_buckets,
-            Street::River | Street::Showdown => self.config.river_buckets,
+        if street != Street::Preflop && self.config.mode(street) == AbstractionMode::Exact {
+            return NUM_HOLE_CARD_COMBOS;
         }
+        self.street_buckets(street)
     }
 
     /// Generate a bucket key for information state.
@@ -203,8 +430,6 @@ impl HandClass {
 
     /// Enumerate all specific combos for this hand class.
     pub fn enumerate_combos(&self) -> Vec<HoleCards> {
-        use super::card::Card;
-
         let mut combos = Vec::with_capacity(self.num_combos() as usize);
 
         if self.rank1 == self.rank2 {
@@ -248,6 +473,59 @@ impl HandClass {
     }
 }
 
+/// Exact bucket for a combo: the index of its card-id pair among all
+/// [`NUM_HOLE_CARD_COMBOS`] unordered pairs.
+fn exact_bucket(hole_cards: &HoleCards) -> u16 {
+    let (a, b) = (hole_cards.card1.id() as u16, hole_cards.card2.id() as u16);
+    let (high, low) = if a > b { (a, b) } else { (b, a) };
+    high * (high - 1) / 2 + low
+}
+
+/// Seed for a hand's equity sample: the hole card ids, then the sorted board ids.
+fn equity_seed(hole_cards: &HoleCards, board: &Board) -> u64 {
+    let mut board_ids: Vec<u8> = board.cards().iter().map(|c| c.id()).collect();
+    board_ids.sort_unstable();
+    hole_cards
+        .cards()
+        .iter()
+        .map(|c| c.id())
+        .chain(board_ids)
+        .fold(0u64, |seed, id| seed * 53 + id as u64 + 1)
+}
+
+/// Sample a hand class index (0-168) weighted by its combos not blocked by `blockers`.
+///
+/// Indices follow [`HandClass::from_index`]. With no blockers every class is
+/// weighted by its full combo count (6 pairs, 4 suited, 12 offsuit out of 1326).
+///
+/// # Panics
+/// Panics if `blockers` remove every combo.
+pub fn sample_hand_class_weighted<R: Rng>(rng: &mut R, blockers: &[Card]) -> u8 {
+    let weights: Vec<u32> = HandClassIter::new()
+        .map(|hc| {
+            if blockers.is_empty() {
+                hc.num_combos() as u32
+            } else {
+                hc.count_unblocked_combos(blockers) as u32
+            }
+        })
+        .collect();
+
+    let total: u32 = weights.iter().sum();
+    assert!(total > 0, "every hand class is blocked");
+
+    let roll = rng.gen_range(0..total);
+    let mut cumsum = 0u32;
+    for (class_idx, weight) in weights.iter().enumerate() {
+        cumsum += weight;
+        if roll < cumsum {
+            return class_idx as u8;
+        }
+    }
+
+    unreachable!("roll is below the total weight")
+}
+
 /// Encode two ranks (r1 > r2) to triangular index.
 fn encode_triangular(r1: u8, r2: u8) -> u8 {
     debug_assert!(r1 > r2);
@@ -387,6 +665,48 @@ mod tests {
         assert_eq!(total_combos, 1326);
     }
 
+    #[test]
+    fn test_sample_hand_class_weighted_distribution() {
+        use rand::SeedableRng;
+
+        // A♠ K♠ removed: 1225 combos left, AA/KK down to 3, AKs to 3, AKo to 9
+        let blockers = [Card::new(12, 3), Card::new(11, 3)];
+        let weights: Vec<f64> = HandClassIter::new()
+            .map(|hc| hc.count_unblocked_combos(&blockers) as f64)
+            .collect();
+        let total: f64 = weights.iter().sum();
+        assert_eq!(total, 1225.0);
+
+        let n = 20_000;
+        let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+        let mut counts = [0u32; 169];
+        for _ in 0..n {
+            counts[sample_hand_class_weighted(&mut rng, &blockers) as usize] += 1;
+        }
+
+        for (class_idx, &count) in counts.iter().enumerate() {
+            let p = weights[class_idx] / total;
+            let observed = count as f64 / n as f64;
+            // 5 standard errors
+            let tolerance = 5.0 * (p * (1.0 - p) / n as f64).sqrt() + 1e-9;
+            assert!(
+                (observed - p).abs() <= tolerance,
+                "{}: observed {} expected {}",
+                HandClass::from_index(class_idx as u8).to_string(),
+                observed,
+                p
+            );
+        }
+
+        // Unblocked sampling covers all 1326 combos
+        let mut rng = rand::rngs::StdRng::seed_from_u64(8);
+        let pairs = (0..n)
+            .filter(|_| sample_hand_class_weighted(&mut rng, &[]) < 13)
+            .count() as f64;
+        let p = 78.0 / 1326.0;
+        assert!((pairs / n as f64 - p).abs() < 5.0 * (p * (1.0 - p) / n as f64).sqrt());
+    }
+
     #[test]
     fn test_abstraction_preflop() {
         let abstraction = CardAbstraction::new();
@@ -417,6 +737,92 @@ mod tests {
             "AA bucket {} should be in upper half", bucket);
     }
 
+    #[test]
+    fn test_equity_cache() {
+        let config = AbstractionConfig::fast();
+        let abstraction = CardAbstraction::with_config(config.clone());
+        let board = Board::from_str("Kh 9h 4c").unwrap();
+        let hands = ["AhQh", "AsQs", "9s9d", "7c2d"].map(|h| HoleCards::from_str(h).unwrap());
+
+        let cold: Vec<u16> = hands.iter().map(|h| abstraction.get_bucket(h, &board)).collect();
+        assert_eq!(abstraction.equity_evaluations(), 4);
+        assert_eq!(abstraction.cached_equities(), 4);
+
+        // Repeat lookups, including from a clone, hit the cache
+        let shared = abstraction.clone();
+        let warm: Vec<u16> = hands.iter().map(|h| shared.get_bucket(h, &board)).collect();
+        assert_eq!(warm, cold);
+        assert_eq!(abstraction.equity_evaluations(), 4);
+
+        // Board order doesn't matter, suits do
+        let reordered = Board::from_str("4c Kh 9h").unwrap();
+        assert_eq!(abstraction.get_bucket(&hands[0], &reordered), cold[0]);
+        assert_eq!(abstraction.equity_evaluations(), 4);
+        assert!(cold[0] > cold[1], "flush draw {} vs no draw {}", cold[0], cold[1]);
+
+        // An uncached abstraction agrees
+        let fresh = CardAbstraction::with_config(config);
+        for (hand, &bucket) in hands.iter().zip(&cold) {
+            assert_eq!(fresh.get_bucket(hand, &board), bucket);
+        }
+
+        abstraction.clear_equity_cache();
+        assert_eq!(abstraction.cached_equities(), 0);
+        abstraction.get_bucket(&hands[0], &board);
+        assert_eq!(abstraction.equity_evaluations(), 5);
+    }
+
+    #[test]
+    fn test_exact_river_with_bucketed_flop() {
+        use std::collections::HashSet;
+
+        let config = AbstractionConfig { equity_samples: 20, ..AbstractionConfig::fast() }
+            .with_mode(Street::River, AbstractionMode::Exact);
+        let abstraction = CardAbstraction::with_config(config);
+        assert_eq!(abstraction.num_buckets(Street::River), NUM_HOLE_CARD_COMBOS);
+        assert_eq!(abstraction.num_buckets(Street::Flop), 100);
+        assert!(abstraction.config.fingerprint().ends_with(";modes:equity/equity/exact"));
+        assert_eq!(AbstractionConfig::fast().fingerprint(), "buckets:100/50/50;samples:100");
+
+        let live_combos = |board: &Board| -> Vec<HoleCards> {
+            (0..52u8)
+                .flat_map(|id1| (id1 + 1..52).filter_map(move |id2| HoleCards::from_ids(id1, id2)))
+                .filter(|hole_cards| hole_cards.cards().iter().all(|&c| !board.contains(c)))
+                .collect()
+        };
+
+        // River: one key per live combo
+        let river = Board::from_str("Kd Qc 2s 7h 9d").unwrap();
+        let combos = live_combos(&river);
+        let keys: HashSet<String> = combos.iter().map(|hc| abstraction.bucket_key(hc, &river)).collect();
+        assert_eq!(combos.len(), 1081);
+        assert_eq!(keys.len(), combos.len());
+        assert!(keys.iter().all(|key| key.starts_with("S3B")));
+        assert_eq!(abstraction.combos_in_bucket(abstraction.get_bucket(&combos[0], &river), &river), vec![combos[0]]);
+
+        // Flop: combos still share equity buckets
+        let flop = Board::from_str("Kd Qc 2s").unwrap();
+        let buckets: HashSet<u16> = live_combos(&flop).iter().map(|hc| abstraction.get_bucket(hc, &flop)).collect();
+        assert!(buckets.iter().all(|&bucket| bucket < 100));
+        assert!(buckets.len() <= 100);
+    }
+
+    #[test]
+    fn test_histogram_separates_draws() {
+        let config = AbstractionConfig { flop_buckets: 16, equity_samples: 40, ..AbstractionConfig::fast() }
+            .with_mode(Street::Flop, AbstractionMode::Histogram);
+        let abstraction = CardAbstraction::with_config(config);
+        let flop = Board::from_str("7h 2h 9c").unwrap();
+
+        // Spread is the low part of the bucket: a flush draw's river equity
+        // is far more spread out than a made overpair's
+        let draw = abstraction.get_bucket(&HoleCards::from_str("AhKh").unwrap(), &flop);
+        let made = abstraction.get_bucket(&HoleCards::from_str("TsTd").unwrap(), &flop);
+        assert!(draw < 16 && made < 16);
+        assert!(draw % HISTOGRAM_SPREAD_LEVELS > made % HISTOGRAM_SPREAD_LEVELS, "draw {} made {}", draw, made);
+        assert_eq!(abstraction.get_bucket(&HoleCards::from_str("AhKh").unwrap(), &flop), draw);
+    }
+
     #[test]
     fn test_bucket_key_generation() {
         let abstraction = CardAbstraction::new();
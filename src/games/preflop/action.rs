@@ -71,29 +71,101 @@ impl PokerAction {
     }
 
     /// Parse an action from its short code.
+    ///
+    /// Inverse of [`short_code`](Self::short_code): `F`, `X`, `C`, `A`, and
+    /// `B`/`R` followed by a centi-BB amount. Trailing characters after a
+    /// single-letter code and non-digit amounts are rejected.
     pub fn from_short_code(code: &str) -> Option<Self> {
-        if code.is_empty() {
-            return None;
+        let amount = || -> Option<u32> {
+            let digits = &code[1..];
+            if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            digits.parse().ok()
+        };
+
+        match code {
+            "F" => Some(PokerAction::Fold),
+            "X" => Some(PokerAction::Check),
+            "C" => Some(PokerAction::Call),
+            "A" => Some(PokerAction::AllIn),
+            _ if code.starts_with('B') => amount().map(PokerAction::Bet),
+            _ if code.starts_with('R') => amount().map(PokerAction::Raise),
+            _ => None,
         }
+    }
 
-        match code.chars().next()? {
-            'F' => Some(PokerAction::Fold),
-            'X' => Some(PokerAction::Check),
-            'C' => Some(PokerAction::Call),
-            'A' => Some(PokerAction::AllIn),
-            'B' => {
-                let amt: u32 = code[1..].parse().ok()?;
-                Some(PokerAction::Bet(amt))
-            }
-            'R' => {
-                let amt: u32 = code[1..].parse().ok()?;
-                Some(PokerAction::Raise(amt))
+    /// Parse an action history such as `"R300-C|X-B132-C"` into actions.
+    ///
+    /// Actions are `-`-separated and streets `|`-separated, as produced by
+    /// [`PokerState::full_history_string`](super::state::PokerState::full_history_string);
+    /// street boundaries are dropped. Returns `None` if any code fails to parse.
+    pub fn parse_history(history: &str) -> Option<Vec<Self>> {
+        history
+            .split('|')
+            .filter(|street| !street.is_empty())
+            .flat_map(|street| street.split('-'))
+            .map(Self::from_short_code)
+            .collect()
+    }
+}
+
+/// Byte marking a street boundary (`|`) in [`encode_history_bytes`].
+const HISTORY_STREET_SEPARATOR: u8 = 0xFF;
+/// Byte introducing a verbatim token in [`encode_history_bytes`].
+const HISTORY_ESCAPE: u8 = 0xFE;
+
+/// Append a compact binary encoding of a short-code action history.
+///
+/// The history is the `-`/`|`-separated form used in info-state keys (e.g.
+/// `R300-C|X-B132`). Each action becomes a tag byte (`F`=0, `X`=1, `C`=2,
+/// `A`=3, `B`=4, `R`=5), bets and raises followed by their centi-BB amount
+/// as a LEB128 varint; street boundaries become `0xFF`. Tokens that are not
+/// canonical short codes are kept verbatim behind a length-prefixed escape,
+/// so distinct histories always encode to distinct bytes.
+pub fn encode_history_bytes(history: &str, out: &mut Vec<u8>) {
+    for (i, street) in history.split('|').enumerate() {
+        if i > 0 {
+            out.push(HISTORY_STREET_SEPARATOR);
+        }
+        if street.is_empty() {
+            continue;
+        }
+        for code in street.split('-') {
+            match PokerAction::from_short_code(code) {
+                Some(action) if action.short_code() == code => match action {
+                    PokerAction::Fold => out.push(0),
+                    PokerAction::Check => out.push(1),
+                    PokerAction::Call => out.push(2),
+                    PokerAction::AllIn => out.push(3),
+                    PokerAction::Bet(amt) => {
+                        out.push(4);
+                        push_varint(out, amt as u64);
+                    }
+                    PokerAction::Raise(amt) => {
+                        out.push(5);
+                        push_varint(out, amt as u64);
+                    }
+                },
+                _ => {
+                    out.push(HISTORY_ESCAPE);
+                    push_varint(out, code.len() as u64);
+                    out.extend_from_slice(code.as_bytes());
+                }
             }
-            _ => None,
         }
     }
 }
 
+/// Append `value` as an unsigned LEB128 varint.
+fn push_varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8 & 0x7F) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
 impl Action for PokerAction {
     fn to_string(&self) -> String {
         self.short_code()
@@ -114,9 +186,31 @@ impl fmt::Display for PokerAction {
 }
 
 /// Convert a bet size in BB to centi-BB (integer representation).
+///
+/// Saturates instead of wrapping: negative or NaN sizes map to 0 and sizes
+/// beyond `u32::MAX` centi-BB map to `u32::MAX`. Use [`try_bb_to_centi`]
+/// to detect out-of-range sizes.
 #[inline]
 pub fn bb_to_centi(bb: f64) -> u32 {
-    (bb * 100.0).round() as u32
+    try_bb_to_centi(bb).unwrap_or(if bb > 0.0 { u32::MAX } else { 0 })
+}
+
+/// Convert a bet size in BB to centi-BB, returning `None` if the size is
+/// negative, not finite, or does not fit in a `u32`.
+#[inline]
+pub fn try_bb_to_centi(bb: f64) -> Option<u32> {
+    let centi = (bb * 100.0).round();
+    if centi.is_finite() && (0.0..=u32::MAX as f64).contains(&centi) {
+        Some(centi as u32)
+    } else {
+        None
+    }
+}
+
+/// Snap a BB amount onto the centi-BB grid.
+#[inline]
+pub fn round_to_centi(bb: f64) -> f64 {
+    (bb * 100.0).round() / 100.0
 }
 
 /// Convert centi-BB to BB.
@@ -244,6 +338,41 @@ mod tests {
         assert_eq!(PokerAction::from_short_code("B150"), Some(PokerAction::Bet(150)));
         assert_eq!(PokerAction::from_short_code("R450"), Some(PokerAction::Raise(450)));
         assert_eq!(PokerAction::from_short_code(""), None);
+        assert_eq!(PokerAction::from_short_code("B"), None);
+        assert_eq!(PokerAction::from_short_code("R+5"), None);
+        assert_eq!(PokerAction::from_short_code("FX"), None);
+        assert_eq!(PokerAction::from_short_code("Q"), None);
+
+        for action in [
+            PokerAction::Fold,
+            PokerAction::Check,
+            PokerAction::Call,
+            PokerAction::Bet(132),
+            PokerAction::Raise(300),
+            PokerAction::AllIn,
+        ] {
+            assert_eq!(PokerAction::from_short_code(&action.short_code()), Some(action));
+        }
+    }
+
+    #[test]
+    fn test_parse_history() {
+        assert_eq!(
+            PokerAction::parse_history("R300-C"),
+            Some(vec![PokerAction::Raise(300), PokerAction::Call])
+        );
+        assert_eq!(
+            PokerAction::parse_history("R300-C|X-B132-C|"),
+            Some(vec![
+                PokerAction::Raise(300),
+                PokerAction::Call,
+                PokerAction::Check,
+                PokerAction::Bet(132),
+                PokerAction::Call,
+            ])
+        );
+        assert_eq!(PokerAction::parse_history(""), Some(vec![]));
+        assert_eq!(PokerAction::parse_history("R300-Z"), None);
     }
 
     #[test]
@@ -254,6 +383,18 @@ mod tests {
         assert_eq!(centi_to_bb(230), 2.3);
     }
 
+    #[test]
+    fn test_bb_conversion_bounds() {
+        assert_eq!(bb_to_centi(-1.0), 0);
+        assert_eq!(bb_to_centi(f64::NAN), 0);
+        assert_eq!(bb_to_centi(1e12), u32::MAX);
+        assert_eq!(bb_to_centi(f64::INFINITY), u32::MAX);
+        assert_eq!(try_bb_to_centi(1e12), None);
+        assert_eq!(try_bb_to_centi(-0.5), None);
+        assert_eq!(try_bb_to_centi(500.0), Some(50_000));
+        assert_eq!(round_to_centi(0.1 + 0.2), 0.3);
+    }
+
     #[test]
     fn test_action_properties() {
         assert!(PokerAction::Bet(100).is_aggressive());
@@ -6,6 +6,7 @@
 use super::action::{PokerAction, bb_to_centi};
 use super::card::Street;
 use super::state::PokerState;
+use serde::{Deserialize, Serialize};
 
 /// Configuration for bet sizing.
 #[derive(Debug, Clone)]
@@ -22,6 +23,14 @@ pub struct BettingConfig {
     pub preflop_open: PreflopOpenSizing,
     /// Preflop 3bet multipliers
     pub preflop_3bet: Preflop3BetSizing,
+    /// Postflop bet sizes (fractions of pot) for the OOP player; empty means `geo_size` only
+    pub oop_bet_sizes: Vec<f64>,
+    /// Postflop bet sizes (fractions of pot) for the IP player; empty means `geo_size` only
+    pub ip_bet_sizes: Vec<f64>,
+    /// Postflop raise sizes (fractions of pot after calling); empty means `geo_size` only
+    pub raise_sizes: Vec<f64>,
+    /// SPR-aware pruning of postflop sizes (disabled if `None`)
+    pub sizing_pruning: Option<SizingPruning>,
 }
 
 impl Default for BettingConfig {
@@ -33,6 +42,34 @@ impl Default for BettingConfig {
             max_bets_per_street: -1,
             preflop_open: PreflopOpenSizing::default(),
             preflop_3bet: Preflop3BetSizing::default(),
+            oop_bet_sizes: Vec::new(),
+            ip_bet_sizes: Vec::new(),
+            raise_sizes: Vec::new(),
+            sizing_pruning: None,
+        }
+    }
+}
+
+/// Policy for dropping redundant postflop sizes based on stack-to-pot ratio.
+///
+/// With several sizes per node the full-game tree grows quickly, yet at low
+/// SPR most of those sizes are strategically equivalent to shoving.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SizingPruning {
+    /// At or below this SPR, fractional sizes are replaced by all-in
+    pub allin_only_spr: f64,
+    /// A size committing more than this fraction of the stack becomes all-in
+    pub max_commit_fraction: f64,
+    /// Below this SPR, sizes larger than the pot (overbets) are dropped
+    pub overbet_min_spr: f64,
+}
+
+impl Default for SizingPruning {
+    fn default() -> Self {
+        Self {
+            allin_only_spr: 1.0,
+            max_commit_fraction: 0.6,
+            overbet_min_spr: 3.0,
         }
     }
 }
@@ -160,13 +197,24 @@ impl BettingLogic {
                 }
             }
             _ => {
-                // Postflop: geometric sizing
-                let bet_size = pot * self.config.geo_size;
+                // Postflop: configured sizes, falling back to geometric sizing
+                let sizes = if pos.is_ip_postflop() {
+                    &self.config.ip_bet_sizes
+                } else {
+                    &self.config.oop_bet_sizes
+                };
                 let min_bet = 1.0; // 1bb minimum
 
-                if bet_size >= min_bet && bet_size < stack {
-                    actions.push(PokerAction::Bet(bb_to_centi(bet_size)));
+                for &fraction in Self::sizes_or_geo(sizes, &self.config.geo_size) {
+                    let bet_size = pot * fraction;
+                    if bet_size >= min_bet && bet_size < stack {
+                        let action = PokerAction::Bet(bb_to_centi(bet_size));
+                        if !actions.contains(&action) {
+                            actions.push(action);
+                        }
+                    }
                 }
+                actions = self.prune_sizes(actions, stack, pot, 0.0);
             }
         }
 
@@ -228,14 +276,19 @@ impl BettingLogic {
                 }
             }
             _ => {
-                // Postflop: geometric sizing for raises
+                // Postflop: configured raise sizes, falling back to geometric sizing
                 let pot_after_call = pot + to_call;
-                let raise_size = pot_after_call * self.config.geo_size;
-                let raise_to = opp_invested + raise_size;
 
-                if raise_to >= min_raise_to && raise_to < stack + invested {
-                    actions.push(PokerAction::Raise(bb_to_centi(raise_to)));
+                for &fraction in Self::sizes_or_geo(&self.config.raise_sizes, &self.config.geo_size) {
+                    let raise_to = opp_invested + pot_after_call * fraction;
+                    if raise_to >= min_raise_to && raise_to < stack + invested {
+                        let action = PokerAction::Raise(bb_to_centi(raise_to));
+                        if !actions.contains(&action) {
+                            actions.push(action);
+                        }
+                    }
                 }
+                actions = self.prune_sizes(actions, stack, pot_after_call, invested);
             }
         }
 
@@ -250,6 +303,50 @@ impl BettingLogic {
         self.deduplicate_actions(actions, stack)
     }
 
+    fn sizes_or_geo<'a>(sizes: &'a [f64], geo_size: &'a f64) -> &'a [f64] {
+        if sizes.is_empty() {
+            std::slice::from_ref(geo_size)
+        } else {
+            sizes
+        }
+    }
+
+    /// Apply the SPR-aware sizing policy to postflop bets/raises.
+    ///
+    /// `pot` is the pot the size is measured against and `invested` what the
+    /// actor already has in on this street (raise amounts are raise-to).
+    fn prune_sizes(&self, mut actions: Vec<PokerAction>, stack: f64, pot: f64, invested: f64) -> Vec<PokerAction> {
+        let Some(policy) = &self.config.sizing_pruning else {
+            return actions;
+        };
+        if actions.is_empty() || pot <= 0.0 {
+            return actions;
+        }
+
+        let spr = stack / pot;
+        let mut collapse_to_allin = false;
+
+        actions.retain(|a| {
+            let committed = match a {
+                PokerAction::Bet(amt) | PokerAction::Raise(amt) => *amt as f64 / 100.0 - invested,
+                _ => return true,
+            };
+            if spr <= policy.allin_only_spr || committed > stack * policy.max_commit_fraction {
+                collapse_to_allin = true;
+                return false;
+            }
+            // Overbets are pruned outright: at moderate SPR they add a node
+            // without a meaningfully different outcome from all-in.
+            !(spr < policy.overbet_min_spr && committed > pot)
+        });
+
+        if collapse_to_allin && !actions.contains(&PokerAction::AllIn) {
+            actions.push(PokerAction::AllIn);
+        }
+
+        actions
+    }
+
     /// Remove duplicate actions (e.g., when all-in equals a normal bet).
     fn deduplicate_actions(&self, mut actions: Vec<PokerAction>, stack: f64) -> Vec<PokerAction> {
         let stack_centi = bb_to_centi(stack);
@@ -269,6 +366,10 @@ impl BettingLogic {
             });
         }
 
+        // Sizing pruning may already have added an all-in
+        let mut seen_allin = false;
+        actions.retain(|a| !matches!(a, PokerAction::AllIn) || !std::mem::replace(&mut seen_allin, true));
+
         actions
     }
 
@@ -388,6 +489,77 @@ mod tests {
         assert!(actions.iter().any(|a| matches!(a, PokerAction::AllIn)));
     }
 
+    #[test]
+    fn test_sizing_pruning_low_spr() {
+        let sb_hand = HoleCards::from_str("AsAd").unwrap();
+        let bb_hand = HoleCards::from_str("KhKs").unwrap();
+
+        // SB opens to 3bb, BB calls: 6bb pot with 4bb behind (SPR ~0.67)
+        let mut state = PokerState::new_hu([7.0, 7.0], 0.5, 1.0)
+            .with_hands(sb_hand, bb_hand);
+        state = state.apply(PokerAction::Raise(300));
+        state = state.apply(PokerAction::Call);
+        assert_eq!(state.street, Street::Flop);
+        assert!(state.spr() < 1.0);
+
+        let sizes = vec![0.2, 0.33, 0.5];
+        let unpruned = BettingLogic::with_config(BettingConfig {
+            oop_bet_sizes: sizes.clone(),
+            ..Default::default()
+        });
+        let bets = unpruned
+            .available_actions(&state)
+            .into_iter()
+            .filter(|a| matches!(a, PokerAction::Bet(_)))
+            .count();
+        assert_eq!(bets, 3);
+
+        let pruned = BettingLogic::with_config(BettingConfig {
+            oop_bet_sizes: sizes,
+            sizing_pruning: Some(SizingPruning::default()),
+            ..Default::default()
+        });
+        assert_eq!(
+            pruned.available_actions(&state),
+            vec![PokerAction::Check, PokerAction::AllIn]
+        );
+    }
+
+    #[test]
+    fn test_sizing_pruning_deep_spr() {
+        let betting = BettingLogic::with_config(BettingConfig {
+            oop_bet_sizes: vec![0.33, 0.66, 1.5],
+            sizing_pruning: Some(SizingPruning::default()),
+            ..Default::default()
+        });
+        let sb_hand = HoleCards::from_str("AsAd").unwrap();
+        let bb_hand = HoleCards::from_str("KhKs").unwrap();
+
+        // 6bb pot with 47bb behind: everything survives
+        let mut state = PokerState::new_hu([50.0, 50.0], 0.5, 1.0)
+            .with_hands(sb_hand, bb_hand);
+        state = state.apply(PokerAction::Raise(300));
+        state = state.apply(PokerAction::Call);
+        let bets = betting
+            .available_actions(&state)
+            .into_iter()
+            .filter(|a| matches!(a, PokerAction::Bet(_)))
+            .count();
+        assert_eq!(bets, 3);
+
+        // 6bb pot with 12bb behind (SPR 2): the overbet would commit most of
+        // the stack, so it collapses into the single all-in
+        let mut state = PokerState::new_hu([15.0, 15.0], 0.5, 1.0)
+            .with_hands(sb_hand, bb_hand);
+        state = state.apply(PokerAction::Raise(300));
+        state = state.apply(PokerAction::Call);
+        let actions = betting.available_actions(&state);
+        assert!(actions.contains(&PokerAction::Bet(198)));
+        assert!(actions.contains(&PokerAction::Bet(396)));
+        assert!(!actions.contains(&PokerAction::Bet(900)));
+        assert_eq!(actions.iter().filter(|a| **a == PokerAction::AllIn).count(), 1);
+    }
+
     #[test]
     fn test_pot_odds() {
         let betting = BettingLogic::new();
@@ -5,6 +5,7 @@
 //! - `HoleCards`: A player's two private cards
 //! - `Board`: Community cards (0-5 cards)
 //! - `Deck`: A deck of 52 cards with dealing functionality
+//! - `CardSet`: A bitmask set of cards for allocation-free enumeration
 
 use rand::seq::SliceRandom;
 use rand::Rng;
@@ -37,6 +38,27 @@ const RANK_CHARS: [char; 13] = ['2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J'
 /// Suit characters for display.
 const SUIT_CHARS: [char; 4] = ['c', 'd', 'h', 's'];
 
+/// Sklansky-Malmuth starting hand groups 1-8.
+const SKLANSKY_GROUPS: [&[&str]; 8] = [
+    &["AA", "KK", "QQ", "JJ", "AKs"],
+    &["TT", "AQs", "AJs", "KQs", "AKo"],
+    &["99", "JTs", "QJs", "KJs", "ATs", "AQo"],
+    &["T9s", "KQo", "88", "QTs", "98s", "J9s", "AJo", "KTs"],
+    &[
+        "77", "87s", "Q9s", "T8s", "KJo", "QJo", "JTo", "76s", "97s", "65s",
+        "A9s", "A8s", "A7s", "A6s", "A5s", "A4s", "A3s", "A2s",
+    ],
+    &["66", "ATo", "55", "86s", "KTo", "QTo", "54s", "K9s", "J8s", "75s"],
+    &[
+        "44", "J9o", "64s", "T9o", "53s", "33", "98o", "43s", "22", "T7s", "Q8s",
+        "K8s", "K7s", "K6s", "K5s", "K4s", "K3s", "K2s",
+    ],
+    &[
+        "87o", "A9o", "Q9o", "76o", "42s", "32s", "96s", "85s", "J8o", "J7s", "65o",
+        "54o", "74s", "K9o", "T8o",
+    ],
+];
+
 /// A single playing card.
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Card {
@@ -60,6 +82,12 @@ impl Card {
         Self { id }
     }
 
+    /// Create a card from its ID, or `None` if the ID is not 0-51.
+    #[inline]
+    pub fn try_from_id(id: u8) -> Option<Self> {
+        (id < 52).then_some(Self { id })
+    }
+
     /// Parse a card from string like "As", "Kh", "2c".
     pub fn from_str(s: &str) -> Option<Self> {
         let chars: Vec<char> = s.chars().collect();
@@ -136,6 +164,15 @@ impl HoleCards {
         }
     }
 
+    /// Create hole cards from card IDs (0-51).
+    /// Returns `None` for an out-of-range ID or two identical cards.
+    pub fn from_ids(id1: u8, id2: u8) -> Option<Self> {
+        if id1 == id2 {
+            return None;
+        }
+        Some(Self::new(Card::try_from_id(id1)?, Card::try_from_id(id2)?))
+    }
+
     /// Parse hole cards from string like "AhKs" or "Ah Ks".
     pub fn from_str(s: &str) -> Option<Self> {
         let s = s.replace(' ', "");
@@ -179,6 +216,70 @@ impl HoleCards {
         }
     }
 
+    /// Bill Chen's preflop hand score, from -1 (72o) to 20 (AA).
+    ///
+    /// High card (A=10, K=8, Q=7, J=6, else half the pip value), doubled for
+    /// pairs (minimum 5), +2 if suited, minus a gap penalty of 1/2/4/5 for
+    /// one/two/three/four-plus gaps, +1 for connected or one-gap hands below
+    /// a queen. Half points are rounded up.
+    pub fn chen_score(&self) -> f64 {
+        let high = self.card1.rank();
+        let low = self.card2.rank();
+
+        let mut score = match high {
+            RANK_A => 10.0,
+            RANK_K => 8.0,
+            RANK_Q => 7.0,
+            RANK_J => 6.0,
+            r => (r + 2) as f64 / 2.0,
+        };
+
+        if high == low {
+            return (score * 2.0).max(5.0).ceil();
+        }
+
+        if self.is_suited() {
+            score += 2.0;
+        }
+
+        let gap = high - low - 1;
+        score -= match gap {
+            0 => 0.0,
+            1 => 1.0,
+            2 => 2.0,
+            3 => 4.0,
+            _ => 5.0,
+        };
+
+        if gap <= 1 && high < RANK_Q {
+            score += 1.0;
+        }
+
+        // `+ 0.0` turns the -0.0 that ceil gives for -0.5 into 0.0
+        score.ceil() + 0.0
+    }
+
+    /// Sklansky-Malmuth starting hand group, 1 (strongest) to 8, or 9 for
+    /// hands outside the eight groups.
+    pub fn sklansky_group(&self) -> u8 {
+        let name = self.class_name();
+        SKLANSKY_GROUPS
+            .iter()
+            .position(|group| group.contains(&name.as_str()))
+            .map_or(9, |i| i as u8 + 1)
+    }
+
+    /// Hand class name such as "AA", "AKs" or "72o".
+    fn class_name(&self) -> String {
+        let mut name = String::with_capacity(3);
+        name.push(self.card1.rank_char());
+        name.push(self.card2.rank_char());
+        if !self.is_pair() {
+            name.push(if self.is_suited() { 's' } else { 'o' });
+        }
+        name
+    }
+
     /// Get both cards as an array.
     pub fn cards(&self) -> [Card; 2] {
         [self.card1, self.card2]
@@ -220,6 +321,25 @@ impl Board {
         Self { cards }
     }
 
+    /// Create a board from card IDs (0-51).
+    /// Returns `None` for more than 5 cards, an out-of-range ID, or a repeated card.
+    pub fn from_ids(ids: &[u8]) -> Option<Self> {
+        if ids.len() > 5 {
+            return None;
+        }
+        let mut seen = 0u64;
+        let mut cards = Vec::with_capacity(5);
+        for &id in ids {
+            let card = Card::try_from_id(id)?;
+            if seen & (1u64 << id) != 0 {
+                return None;
+            }
+            seen |= 1u64 << id;
+            cards.push(card);
+        }
+        Some(Self::from_cards(cards))
+    }
+
     /// Parse a board from string like "AhKsQd".
     pub fn from_str(s: &str) -> Option<Self> {
         let s = s.replace(' ', "");
@@ -410,6 +530,20 @@ impl Deck {
         Some(card)
     }
 
+    /// Deal a uniformly random remaining card without shuffling the deck.
+    ///
+    /// A random undealt slot is swapped to the front of the undealt cards and
+    /// dealt, so the draw is O(1) and [`Deck::deal`] and
+    /// [`Deck::remaining_cards`] stay consistent.
+    pub fn deal_random<R: Rng>(&mut self, rng: &mut R) -> Option<Card> {
+        if self.index >= self.size {
+            return None;
+        }
+        let slot = rng.gen_range(self.index..self.size);
+        self.cards.swap(self.index, slot);
+        self.deal()
+    }
+
     /// Deal multiple cards.
     pub fn deal_n(&mut self, n: usize) -> Vec<Card> {
         let mut cards = Vec::with_capacity(n);
@@ -447,6 +581,11 @@ impl Deck {
     pub fn remaining_cards(&self) -> &[Card] {
         &self.cards[self.index..self.size]
     }
+
+    /// Bitmask of the remaining cards (bit `id` set = card still in the deck).
+    pub fn remaining_mask(&self) -> u64 {
+        CardSet::FULL.0 & !self.dealt_mask
+    }
 }
 
 impl Default for Deck {
@@ -461,6 +600,105 @@ impl fmt::Debug for Deck {
     }
 }
 
+/// A set of cards as a 52-bit mask (bit `id` set = card present).
+///
+/// A `Copy` alternative to [`Deck`] for hot loops: removing dead cards,
+/// iterating and sampling are bit operations with no allocation or shuffle.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct CardSet(pub u64);
+
+impl CardSet {
+    /// The empty set.
+    pub const EMPTY: CardSet = CardSet(0);
+    /// All 52 cards.
+    pub const FULL: CardSet = CardSet((1u64 << 52) - 1);
+
+    /// The full deck minus `dead` cards.
+    pub fn without(dead: &[Card]) -> Self {
+        let mut set = Self::FULL;
+        for &card in dead {
+            set.remove(card);
+        }
+        set
+    }
+
+    /// Check if a card is in the set.
+    #[inline]
+    pub fn contains(&self, card: Card) -> bool {
+        self.0 & (1u64 << card.id()) != 0
+    }
+
+    /// Add a card to the set.
+    #[inline]
+    pub fn insert(&mut self, card: Card) {
+        self.0 |= 1u64 << card.id();
+    }
+
+    /// Remove a card from the set.
+    #[inline]
+    pub fn remove(&mut self, card: Card) {
+        self.0 &= !(1u64 << card.id());
+    }
+
+    /// Number of cards in the set.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Check if the set is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Iterate over the cards in ascending id order.
+    pub fn iter(&self) -> CardSetIter {
+        CardSetIter(self.0)
+    }
+
+    /// A uniformly random card from the set, or `None` if it is empty.
+    pub fn random_card<R: Rng>(&self, rng: &mut R) -> Option<Card> {
+        if self.is_empty() {
+            return None;
+        }
+        // Clear the lowest `k` set bits; the next lowest is the k-th card
+        let mut mask = self.0;
+        for _ in 0..rng.gen_range(0..self.len()) {
+            mask &= mask - 1;
+        }
+        Some(Card::from_id(mask.trailing_zeros() as u8))
+    }
+}
+
+impl fmt::Debug for CardSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// Iterator over a [`CardSet`], lowest card id first.
+#[derive(Debug, Clone)]
+pub struct CardSetIter(u64);
+
+impl Iterator for CardSetIter {
+    type Item = Card;
+
+    fn next(&mut self) -> Option<Card> {
+        if self.0 == 0 {
+            return None;
+        }
+        let id = self.0.trailing_zeros() as u8;
+        self.0 &= self.0 - 1;
+        Some(Card::from_id(id))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.0.count_ones() as usize;
+        (n, Some(n))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -503,6 +741,25 @@ mod tests {
         assert!(hc_pair.is_pair());
     }
 
+    #[test]
+    fn test_from_ids() {
+        assert!(Card::try_from_id(51).is_some());
+        assert!(Card::try_from_id(52).is_none());
+
+        let parsed = HoleCards::from_str("AhKs").unwrap();
+        let from_ids = HoleCards::from_ids(parsed.card2.id(), parsed.card1.id()).unwrap();
+        assert_eq!(from_ids, parsed);
+
+        assert!(HoleCards::from_ids(7, 7).is_none());
+        assert!(HoleCards::from_ids(0, 52).is_none());
+
+        let board = Board::from_str("AhKsQd").unwrap();
+        let ids: Vec<u8> = board.cards().iter().map(|c| c.id()).collect();
+        assert_eq!(Board::from_ids(&ids), Some(board));
+        assert!(Board::from_ids(&[1, 2, 1]).is_none());
+        assert!(Board::from_ids(&[0, 1, 2, 3, 4, 5]).is_none());
+    }
+
     #[test]
     fn test_hand_class_index() {
         // Pairs
@@ -526,6 +783,42 @@ mod tests {
         assert!(idx >= 91 && idx <= 168, "AKo index {} should be 91-168", idx);
     }
 
+    #[test]
+    fn test_chen_score() {
+        let chen = |s: &str| HoleCards::from_str(s).unwrap().chen_score();
+
+        assert_eq!(chen("AhAs"), 20.0);
+        assert_eq!(chen("KhKs"), 16.0);
+        assert_eq!(chen("AsKs"), 12.0);
+        assert_eq!(chen("AhKs"), 10.0);
+        assert_eq!(chen("JsTs"), 9.0);
+        assert_eq!(chen("5h5s"), 5.0);
+        assert_eq!(chen("2h2s"), 5.0);
+        assert_eq!(chen("7h2s"), -1.0);
+
+        // Monotone across a spread of hands, strongest first
+        let spread = ["AhAs", "QhQs", "AsKs", "AhKs", "9h9s", "JsTs", "8s7s", "AhTs", "KhTs", "9h4s", "7h2s"];
+        let scores: Vec<f64> = spread.iter().map(|s| chen(s)).collect();
+        for pair in scores.windows(2) {
+            assert!(pair[0] >= pair[1], "Chen order broken: {:?}", scores);
+        }
+    }
+
+    #[test]
+    fn test_sklansky_group() {
+        let group = |s: &str| HoleCards::from_str(s).unwrap().sklansky_group();
+
+        assert_eq!(group("AhAs"), 1);
+        assert_eq!(group("AsKs"), 1);
+        assert_eq!(group("AhKs"), 2);
+        assert_eq!(group("9h9s"), 3);
+        assert_eq!(group("5s4s"), 6);
+        assert_eq!(group("AhKh"), 1);
+        assert_eq!(group("As5s"), 5);
+        assert_eq!(group("Ks2s"), 7);
+        assert_eq!(group("7h2s"), 9);
+    }
+
     #[test]
     fn test_board() {
         let mut board = Board::new();
@@ -561,6 +854,40 @@ mod tests {
         assert!(deck.deal().is_none());
     }
 
+    #[test]
+    fn test_deck_deal_random() {
+        use rand::SeedableRng;
+
+        let dead: Vec<Card> = ["As", "Kd"].iter().map(|c| Card::from_str(c).unwrap()).collect();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(3);
+
+        // Exhausting the deck deals every live card exactly once
+        let mut deck = Deck::without(&dead);
+        let mut dealt = CardSet::EMPTY;
+        while let Some(card) = deck.deal_random(&mut rng) {
+            assert!(!dealt.contains(card) && !dead.contains(&card), "{} dealt twice or dead", card);
+            dealt.insert(card);
+            assert_eq!(CardSet(deck.remaining_mask()).iter().collect::<Vec<_>>().len(), deck.remaining());
+        }
+        assert_eq!(dealt, CardSet::without(&dead));
+
+        // Mixing with ordered dealing never repeats a card
+        let mut deck = Deck::new();
+        let mut seen = CardSet::EMPTY;
+        for i in 0..52 {
+            let card = if i % 2 == 0 { deck.deal_random(&mut rng) } else { deck.deal() }.unwrap();
+            assert!(!seen.contains(card));
+            seen.insert(card);
+        }
+
+        // First card is uniform over the deck
+        let mut counts = [0u32; 52];
+        for _ in 0..52_000 {
+            counts[Deck::new().deal_random(&mut rng).unwrap().id() as usize] += 1;
+        }
+        assert!(counts.iter().all(|&n| (800..1200).contains(&n)), "counts {:?}", counts);
+    }
+
     #[test]
     fn test_deck_without() {
         let dead = vec![
@@ -571,6 +898,38 @@ mod tests {
         assert_eq!(deck.remaining(), 50);
     }
 
+    #[test]
+    fn test_card_set() {
+        let dead: Vec<Card> = ["As", "Ah", "2c", "Td"].iter().map(|c| Card::from_str(c).unwrap()).collect();
+        let set = CardSet::without(&dead);
+
+        // Iteration yields exactly the non-dead cards, in id order
+        let expected: Vec<Card> = (0..52u8).map(Card::from_id).filter(|c| !dead.contains(c)).collect();
+        assert_eq!(set.iter().collect::<Vec<_>>(), expected);
+        assert_eq!(set.len(), 48);
+        assert!(dead.iter().all(|&c| !set.contains(c)));
+
+        // The deck's mask tracks dead and dealt cards
+        let mut deck = Deck::without(&dead);
+        assert_eq!(deck.remaining_mask(), set.0);
+        let dealt = deck.deal().unwrap();
+        let mut after = set;
+        after.remove(dealt);
+        assert_eq!(deck.remaining_mask(), after.0);
+        assert_eq!(CardSet(deck.remaining_mask()).iter().collect::<Vec<_>>(), deck.remaining_cards());
+
+        // Sampling stays inside the set and reaches every card
+        let mut rng = rand::thread_rng();
+        let mut seen = CardSet::EMPTY;
+        for _ in 0..2_000 {
+            let card = set.random_card(&mut rng).unwrap();
+            assert!(set.contains(card));
+            seen.insert(card);
+        }
+        assert_eq!(seen, set);
+        assert_eq!(CardSet::EMPTY.random_card(&mut rng), None);
+    }
+
     #[test]
     fn test_street_progression() {
         assert_eq!(Street::Preflop.next(), Some(Street::Flop));
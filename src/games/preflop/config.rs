@@ -115,6 +115,57 @@ pub struct EquityModel {
     pub model_type: String,
     #[serde(default)]
     pub raked: bool,
+    /// Rake structure; when `raked` is set without one, [`RakeConfig::default`] is used.
+    #[serde(default)]
+    pub rake: Option<RakeConfig>,
+}
+
+impl EquityModel {
+    /// Effective rake, or `None` for unraked models.
+    pub fn rake_config(&self) -> Option<RakeConfig> {
+        if self.raked {
+            Some(self.rake.clone().unwrap_or_default())
+        } else {
+            None
+        }
+    }
+}
+
+/// Rake taken from the pot before it is awarded.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RakeConfig {
+    /// Rake as a fraction of the pot (0.05 = 5%).
+    pub percent: f64,
+    /// Maximum rake per hand in BB.
+    pub cap: f64,
+    /// No flop, no drop: hands that end preflop are not raked.
+    #[serde(default = "default_nfnd")]
+    pub nfnd: bool,
+}
+
+fn default_nfnd() -> bool {
+    true
+}
+
+impl Default for RakeConfig {
+    fn default() -> Self {
+        Self {
+            percent: 0.05,
+            cap: 3.0,
+            nfnd: true,
+        }
+    }
+}
+
+impl RakeConfig {
+    /// Rake taken from a pot of `pot` BB. `saw_flop` is false for hands
+    /// that ended preflop.
+    pub fn rake(&self, pot: f64, saw_flop: bool) -> f64 {
+        if self.nfnd && !saw_flop {
+            return 0.0;
+        }
+        (pot * self.percent).min(self.cap).max(0.0)
+    }
 }
 
 /// Action restrictions configuration.
@@ -304,6 +355,14 @@ impl PreflopConfig {
         self.blinds.bb + self.blinds.sb + (self.blinds.ante * self.hand_data.num_players as f64)
     }
 
+    /// Straddle amount posted by every hand, if the straddle is mandatory.
+    ///
+    /// A mandatory straddle is a forced 2bb post; voluntary straddles are
+    /// not modelled.
+    pub fn mandatory_straddle(&self) -> Option<f64> {
+        (self.hand_data.straddle_type == "MANDATORY").then_some(2.0 * self.blinds.bb)
+    }
+
     /// Check if a position can flat at a given raise level.
     /// Level: 0=RFI, 1=facing RFI, 2=facing 3bet, 3=facing 4bet, 4=facing 5bet
     pub fn can_flat_at_level(&self, level: usize, current_flats: u8) -> bool {
@@ -412,6 +471,52 @@ pub enum ConfigError {
     MissingStack(String),
     InvalidStack { position: String, stack: f64 },
     InvalidBlinds { bb: f64, sb: f64 },
+    /// Ante is negative.
+    InvalidAnte(f64),
+    /// An open size does not exceed the big blind.
+    OpenSizeTooSmall {
+        /// Offending config field
+        field: String,
+        /// Base open size in BB
+        size: f64,
+        /// Big blind it must exceed
+        bb: f64,
+    },
+    /// A per-caller increment is negative.
+    NegativePerCaller {
+        /// Offending config field
+        field: String,
+        /// Increment per caller in BB
+        per_caller: f64,
+    },
+    /// A re-raise multiplier would not exceed the bet it raises.
+    RaiseTooSmall {
+        /// Offending config field
+        field: String,
+        /// Multiplier of the previous raise
+        multiplier: f64,
+    },
+    /// A pot-based raise fraction is not positive.
+    InvalidPotPercent {
+        /// Offending config field
+        field: String,
+        /// Fraction of the pot
+        percent: f64,
+    },
+    /// An all-in threshold is out of range.
+    InvalidAllInThreshold {
+        /// Offending config field
+        field: String,
+        /// Configured threshold
+        value: f64,
+    },
+    /// More flats allowed at a raise level than there are other players.
+    InvalidFlatCount {
+        /// Raise level (0 = RFI)
+        level: usize,
+        /// Configured flat count
+        flats: u8,
+    },
 }
 
 impl std::fmt::Display for ConfigError {
@@ -430,6 +535,25 @@ impl std::fmt::Display for ConfigError {
             Self::InvalidBlinds { bb, sb } => {
                 write!(f, "Invalid blinds: BB={}, SB={}", bb, sb)
             }
+            Self::InvalidAnte(ante) => write!(f, "Invalid ante: {} (must be non-negative)", ante),
+            Self::OpenSizeTooSmall { field, size, bb } => {
+                write!(f, "Open size {} in {} must exceed the big blind ({})", size, field, bb)
+            }
+            Self::NegativePerCaller { field, per_caller } => {
+                write!(f, "Per-caller increment {} in {} must be non-negative", per_caller, field)
+            }
+            Self::RaiseTooSmall { field, multiplier } => {
+                write!(f, "Raise multiplier {} in {} must exceed 1", multiplier, field)
+            }
+            Self::InvalidPotPercent { field, percent } => {
+                write!(f, "Pot fraction {} in {} must be positive", percent, field)
+            }
+            Self::InvalidAllInThreshold { field, value } => {
+                write!(f, "Invalid all-in threshold {} in {}", value, field)
+            }
+            Self::InvalidFlatCount { level, flats } => {
+                write!(f, "Invalid flat count {} at raise level {} (at most 7)", flats, level)
+            }
         }
     }
 }
@@ -509,6 +633,20 @@ mod tests {
         assert!((config.starting_pot() - 2.46).abs() < 0.001);
     }
 
+    #[test]
+    fn test_rake_config() {
+        let mut config = PreflopConfig::from_json_str(TEST_CONFIG).unwrap();
+        assert_eq!(config.equity_model.rake_config(), None);
+
+        config.equity_model.raked = true;
+        let rake = config.equity_model.rake_config().unwrap();
+        assert_eq!(rake, RakeConfig::default());
+
+        assert_eq!(rake.rake(10.0, false), 0.0); // no flop, no drop
+        assert!((rake.rake(10.0, true) - 0.5).abs() < 1e-9);
+        assert_eq!(rake.rake(200.0, true), rake.cap);
+    }
+
     #[test]
     fn test_get_open_sizing() {
         let config = PreflopConfig::from_json_str(TEST_CONFIG).unwrap();
@@ -663,6 +801,7 @@ mod tests {
         assert!(!config.hand_data.skip_sb);
         assert!(!config.hand_data.moving_bu);
         assert_eq!(config.hand_data.straddle_type, "OFF");
+        assert_eq!(config.mandatory_straddle(), None);
 
         // Equity model
         assert_eq!(config.equity_model.model_type, "ChipEV");
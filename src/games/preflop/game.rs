@@ -5,12 +5,13 @@
 
 use rand::Rng;
 
-use super::card::{HoleCards, Street};
+use super::card::{Board, Card, CardSet, Deck, HoleCards, Street};
 use super::state::{PokerState, HUPosition};
 use super::action::PokerAction;
 use super::info_state::PokerInfoState;
 use super::betting::{BettingLogic, BettingConfig};
 use super::abstraction::{CardAbstraction, AbstractionConfig};
+use super::config::RakeConfig;
 use super::hand_eval::HandEvaluator;
 use crate::cfr::game::Game;
 
@@ -23,10 +24,16 @@ pub struct SBvsBBConfig {
     pub sb_amount: f64,
     /// Big blind amount
     pub bb_amount: f64,
+    /// Ante posted by each player
+    pub ante: f64,
+    /// Mandatory straddle posted by SB in place of the small blind, if any
+    pub straddle: Option<f64>,
     /// Betting configuration
     pub betting: BettingConfig,
     /// Card abstraction configuration
     pub abstraction: AbstractionConfig,
+    /// Rake taken from the pot (unraked if `None`)
+    pub rake: Option<RakeConfig>,
 }
 
 impl Default for SBvsBBConfig {
@@ -35,8 +42,11 @@ impl Default for SBvsBBConfig {
             stack_bb: 50.0,
             sb_amount: 0.5,
             bb_amount: 1.0,
+            ante: 0.0,
+            straddle: None,
             betting: BettingConfig::default(),
             abstraction: AbstractionConfig::default(),
+            rake: None,
         }
     }
 }
@@ -48,8 +58,11 @@ impl SBvsBBConfig {
             stack_bb: 50.0,
             sb_amount: 0.5,
             bb_amount: 1.0,
+            ante: 0.0,
+            straddle: None,
             betting: BettingConfig::default(),
             abstraction: AbstractionConfig::fast(),
+            rake: None,
         }
     }
 }
@@ -94,6 +107,89 @@ impl SBvsBBFullGame {
         &self.config
     }
 
+    /// Get the card abstraction used for info-set buckets.
+    pub fn abstraction(&self) -> &CardAbstraction {
+        &self.abstraction
+    }
+
+    /// Build a subgame root by replaying `actions` with the given hole cards and board.
+    ///
+    /// Each action is checked against the legal actions at its node, and board
+    /// cards are dealt from `board` in order whenever a new street starts. The
+    /// line may stop at a street boundary with the board still to come; the
+    /// returned state is then a chance node.
+    ///
+    /// # Example
+    /// ```ignore
+    /// // SB opens 2.5bb, BB calls, flop Kh7c2d, SB to act
+    /// let root = game.state_from_line(
+    ///     &[PokerAction::Raise(250), PokerAction::Call],
+    ///     HoleCards::from_str("AsQs").unwrap(),
+    ///     HoleCards::from_str("JdTd").unwrap(),
+    ///     &Board::from_str("Kh7c2d").unwrap(),
+    /// )?;
+    /// ```
+    pub fn state_from_line(
+        &self,
+        actions: &[PokerAction],
+        hole_sb: HoleCards,
+        hole_bb: HoleCards,
+        board: &Board,
+    ) -> Result<PokerState, LineError> {
+        if board.len() > 5 {
+            return Err(LineError::TooManyBoardCards(board.len()));
+        }
+
+        let mut dead: Vec<Card> = Vec::with_capacity(9);
+        for card in hole_sb.cards().into_iter().chain(hole_bb.cards()).chain(board.cards().iter().copied()) {
+            if dead.contains(&card) {
+                return Err(LineError::DuplicateCard(card));
+            }
+            dead.push(card);
+        }
+
+        let mut state = self.initial_state().with_hands(hole_sb, hole_bb);
+        state.deck = Deck::without(&dead);
+        let mut board_cards = board.cards().iter().copied();
+
+        for (index, action) in actions.iter().enumerate() {
+            if self.is_chance(&state) {
+                for _ in state.board.len()..state.street.num_board_cards() {
+                    match board_cards.next() {
+                        Some(card) => state.board.add(card),
+                        None => return Err(LineError::MissingBoardCards(state.street)),
+                    }
+                }
+            }
+            if state.is_terminal {
+                return Err(LineError::HandOver { index });
+            }
+
+            let legal = self.available_actions(&state);
+            if !legal.contains(action) {
+                return Err(LineError::IllegalAction { index, action: *action, legal });
+            }
+            state = state.apply(*action);
+        }
+
+        // Deal the board for the street the line stopped on, if it was given
+        if self.is_chance(&state) && board.len() >= state.street.num_board_cards() {
+            for card in board_cards.by_ref().take(state.street.num_board_cards() - state.board.len()) {
+                state.board.add(card);
+            }
+        }
+        if board_cards.next().is_some() {
+            return Err(LineError::UnusedBoardCards { used: state.board.len(), given: board.len() });
+        }
+
+        Ok(state)
+    }
+
+    /// Rake taken from a pot of `pot` BB under the configured rake, if any.
+    fn rake(&self, pot: f64, saw_flop: bool) -> f64 {
+        self.config.rake.as_ref().map_or(0.0, |rake| rake.rake(pot, saw_flop))
+    }
+
     /// Determine the winner at showdown.
     fn determine_showdown_winner(&self, state: &PokerState) -> Option<HUPosition> {
         let sb_hand = state.hand(HUPosition::SB)?;
@@ -131,6 +227,58 @@ impl SBvsBBFullGame {
     }
 }
 
+/// Why [`SBvsBBFullGame::state_from_line`] could not build a state.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LineError {
+    /// A card appears more than once across the hole cards and board.
+    DuplicateCard(Card),
+    /// More than five board cards were given.
+    TooManyBoardCards(usize),
+    /// The action at `index` is not legal at its node.
+    IllegalAction {
+        /// Position of the action in the line.
+        index: usize,
+        /// The rejected action.
+        action: PokerAction,
+        /// Actions that were legal there.
+        legal: Vec<PokerAction>,
+    },
+    /// The hand ended before the action at `index`.
+    HandOver {
+        /// Position of the first action after the hand ended.
+        index: usize,
+    },
+    /// The line continues into a street whose board cards weren't given.
+    MissingBoardCards(Street),
+    /// The line stopped before every given board card was dealt.
+    UnusedBoardCards {
+        /// Board cards the line reached.
+        used: usize,
+        /// Board cards given.
+        given: usize,
+    },
+}
+
+impl std::fmt::Display for LineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LineError::DuplicateCard(card) => write!(f, "Card {} is used more than once", card),
+            LineError::TooManyBoardCards(n) => write!(f, "Board has {} cards; at most 5 allowed", n),
+            LineError::IllegalAction { index, action, legal } => {
+                let legal: Vec<String> = legal.iter().map(|a| format!("{}", a)).collect();
+                write!(f, "Action {} ({}) is illegal; legal actions: {}", index, action, legal.join(", "))
+            }
+            LineError::HandOver { index } => write!(f, "Hand is over before action {}", index),
+            LineError::MissingBoardCards(street) => write!(f, "No board cards given for the {}", street),
+            LineError::UnusedBoardCards { used, given } => {
+                write!(f, "Line reaches {} of the {} board cards given", used, given)
+            }
+        }
+    }
+}
+
+impl std::error::Error for LineError {}
+
 impl Default for SBvsBBFullGame {
     fn default() -> Self {
         Self::new()
@@ -151,10 +299,12 @@ impl Game for SBvsBBFullGame {
     type InfoState = PokerInfoState;
 
     fn initial_state(&self) -> Self::State {
-        PokerState::new_hu(
+        PokerState::new_hu_with_antes(
             [self.config.stack_bb, self.config.stack_bb],
             self.config.sb_amount,
             self.config.bb_amount,
+            self.config.ante,
+            self.config.straddle,
         )
     }
 
@@ -164,6 +314,11 @@ impl Game for SBvsBBFullGame {
 
     fn get_payoff(&self, state: &Self::State, player: usize) -> f64 {
         debug_assert!(self.is_terminal(state), "get_payoff called on non-terminal state");
+        debug_assert!(
+            player < 2,
+            "SBvsBBFullGame is heads-up; player index {} is out of range",
+            player
+        );
 
         let pos = HUPosition::from_index(player);
 
@@ -174,7 +329,8 @@ impl Game for SBvsBBFullGame {
                 return -state.invested_total[player];
             } else {
                 // Opponent folded, won the pot minus our investment
-                return state.pot - state.invested_total[player];
+                let saw_flop = state.street != Street::Preflop;
+                return state.pot - self.rake(state.pot, saw_flop) - state.invested_total[player];
             }
         }
 
@@ -184,19 +340,20 @@ impl Game for SBvsBBFullGame {
             // For CFR, we should already have dealt all cards in sample_chance
             // So just evaluate the showdown
 
-            match self.determine_showdown_winner(state) {
-                Some(winner) => {
-                    if winner == pos {
-                        state.pot - state.invested_total[player]
-                    } else {
-                        -state.invested_total[player]
-                    }
-                }
-                None => {
-                    // Tie - split pot
-                    (state.pot / 2.0) - state.invested_total[player]
-                }
-            }
+            // Any uncalled excess goes back to whoever put it in; only the
+            // matched amount is contested (and raked). A showdown always
+            // deals a board, so no-flop-no-drop never applies.
+            let matched = state.invested_total[0].min(state.invested_total[1]);
+            let contested = 2.0 * matched;
+            let raked_pot = contested - self.rake(contested, true);
+
+            let share = match self.determine_showdown_winner(state) {
+                Some(winner) if winner == pos => 1.0,
+                Some(_) => 0.0,
+                // Tie - split the contested pot
+                None => 0.5,
+            };
+            share * raked_pot - matched
         } else {
             // Shouldn't reach here
             0.0
@@ -207,10 +364,33 @@ impl Game for SBvsBBFullGame {
         if self.is_terminal(state) || self.is_chance(state) {
             None
         } else {
-            state.to_act.map(|p| p.index())
+            let player = state.to_act.map(|p| p.index());
+            debug_assert!(
+                player.is_none_or(|p| p < 2),
+                "SBvsBBFullGame is heads-up; player index {:?} is out of range",
+                player
+            );
+            player
         }
     }
 
+    fn check_invariants(&self, state: &Self::State) -> Result<(), String> {
+        state.check_invariants()?;
+
+        let hole = state.hands.iter().flatten().flat_map(|h| h.cards());
+        let mut dealt = CardSet::EMPTY;
+        for card in hole.chain(state.board.cards().iter().copied()) {
+            if dealt.contains(card) {
+                return Err(format!("{} dealt twice", card));
+            }
+            if CardSet(state.deck.remaining_mask()).contains(card) {
+                return Err(format!("dealt card {} is still in the deck", card));
+            }
+            dealt.insert(card);
+        }
+        Ok(())
+    }
+
     fn num_players(&self) -> usize {
         2
     }
@@ -241,35 +421,25 @@ impl Game for SBvsBBFullGame {
         let mut new_state = state.clone();
 
         // Deal hole cards if needed
+        // Each card swaps a random undealt slot to the front of the deck (a
+        // partial Fisher-Yates shuffle), so no street reshuffles the whole deck
         if new_state.hands[0].is_none() || new_state.hands[1].is_none() {
-            new_state.deck.shuffle(rng);
-
-            let c1 = new_state.deck.deal().unwrap();
-            let c2 = new_state.deck.deal().unwrap();
-            let sb_hand = HoleCards::new(c1, c2);
-
-            let c3 = new_state.deck.deal().unwrap();
-            let c4 = new_state.deck.deal().unwrap();
-            let bb_hand = HoleCards::new(c3, c4);
-
-            new_state.hands = [Some(sb_hand), Some(bb_hand)];
-            new_state.to_act = Some(HUPosition::SB);
+            new_state.deal_hole_cards(rng);
+            new_state.to_act = Some(new_state.first_to_act_preflop());
 
             return new_state;
         }
 
         // Deal board cards based on street
-        new_state.deck.shuffle(rng);
-
         match new_state.street {
             Street::Flop if new_state.board.len() == 0 => {
-                new_state.deal_flop();
+                new_state.deal_flop(rng);
             }
             Street::Turn if new_state.board.len() == 3 => {
-                new_state.deal_turn();
+                new_state.deal_turn(rng);
             }
             Street::River if new_state.board.len() == 4 => {
-                new_state.deal_river();
+                new_state.deal_river(rng);
             }
             _ => {}
         }
@@ -290,6 +460,16 @@ impl Game for SBvsBBFullGame {
     fn state_description(&self, state: &Self::State) -> String {
         format!("{}", state)
     }
+
+    fn max_depth(&self) -> Option<usize> {
+        // Raises are uncapped but at least double the bet each time, so even
+        // deep stacks run out within a few dozen actions per street
+        Some(256)
+    }
+
+    fn abstraction_fingerprint(&self) -> String {
+        self.config.abstraction.fingerprint()
+    }
 }
 
 #[cfg(test)]
@@ -344,6 +524,43 @@ mod tests {
         assert!((bb_payoff - 0.5).abs() < 0.01, "BB payoff {} should be 0.5", bb_payoff);
     }
 
+    #[test]
+    fn test_rake_reduces_winner_payoff() {
+        let unraked = SBvsBBFullGame::new();
+        let raked = SBvsBBFullGame::with_config(SBvsBBConfig {
+            rake: Some(RakeConfig { percent: 0.05, cap: 3.0, nfnd: true }),
+            ..SBvsBBConfig::default()
+        });
+
+        let mut rng = rand::thread_rng();
+        let dealt = unraked.sample_chance(&unraked.initial_state(), &mut rng);
+
+        // No flop, no drop: a preflop fold is unaffected
+        let folded = unraked.apply_action(&dealt, &PokerAction::Fold);
+        assert_eq!(raked.get_payoff(&folded, 1), unraked.get_payoff(&folded, 1));
+
+        // Limp, check, flop; bet and fold
+        let mut state = unraked.apply_action(&dealt, &PokerAction::Call);
+        state = unraked.apply_action(&state, &PokerAction::Check);
+        state = unraked.sample_chance(&state, &mut rng);
+        let bet = unraked.available_actions(&state).into_iter()
+            .find(|a| matches!(a, PokerAction::Bet(_)))
+            .unwrap();
+        let bettor = unraked.current_player(&state).unwrap();
+        state = unraked.apply_action(&state, &bet);
+        state = unraked.apply_action(&state, &PokerAction::Fold);
+        assert!(unraked.is_terminal(&state));
+
+        let rake = state.pot * 0.05;
+        let winner_unraked = unraked.get_payoff(&state, bettor);
+        let winner_raked = raked.get_payoff(&state, bettor);
+        assert!((winner_unraked - winner_raked - rake).abs() < 1e-9,
+            "raked {} vs unraked {}", winner_raked, winner_unraked);
+
+        // The loser's payoff is unchanged
+        assert_eq!(raked.get_payoff(&state, 1 - bettor), unraked.get_payoff(&state, 1 - bettor));
+    }
+
     #[test]
     fn test_limp_check_to_flop() {
         let game = SBvsBBFullGame::new();
@@ -504,4 +721,245 @@ mod tests {
         // Should NOT be able to fold when not facing bet
         assert!(!actions.iter().any(|a| matches!(a, PokerAction::Fold)));
     }
+
+    #[test]
+    fn test_state_from_line() {
+        let game = SBvsBBFullGame::fast();
+        let sb = HoleCards::from_str("AsQs").unwrap();
+        let bb = HoleCards::from_str("JdTd").unwrap();
+        let flop = Board::from_str("Kh7c2d").unwrap();
+        let open_call = [PokerAction::Raise(250), PokerAction::Call];
+
+        let root = game.state_from_line(&open_call, sb, bb, &flop).unwrap();
+        assert_eq!(root.street, Street::Flop);
+        assert_eq!(root.board, flop);
+        assert!((root.pot - 5.0).abs() < 1e-9);
+        assert_eq!(root.to_act, Some(HUPosition::SB));
+        assert!(!game.is_chance(&root));
+        assert_eq!(game.current_player(&root), Some(0));
+        assert!(!root.deck.remaining_cards().iter().any(|&c| flop.contains(c) || sb.contains(c)));
+
+        // Stopping at the street boundary without a board leaves a chance node
+        let root = game.state_from_line(&open_call, sb, bb, &Board::new()).unwrap();
+        assert!(game.is_chance(&root));
+
+        let illegal = game.state_from_line(&[PokerAction::Raise(700)], sb, bb, &flop);
+        assert!(matches!(illegal, Err(LineError::IllegalAction { index: 0, .. })));
+
+        let duplicate = game.state_from_line(&open_call, sb, sb, &flop);
+        assert!(matches!(duplicate, Err(LineError::DuplicateCard(_))));
+
+        let past_flop = [PokerAction::Raise(250), PokerAction::Call, PokerAction::Check];
+        let missing = game.state_from_line(&past_flop, sb, bb, &Board::new());
+        assert_eq!(missing.unwrap_err(), LineError::MissingBoardCards(Street::Flop));
+
+        let folded = game.state_from_line(&[PokerAction::Fold, PokerAction::Call], sb, bb, &Board::new());
+        assert_eq!(folded.unwrap_err(), LineError::HandOver { index: 1 });
+    }
+
+    #[test]
+    fn test_checkpoint_abstraction_fingerprint() {
+        let fast = SBvsBBFullGame::fast();
+        let default = SBvsBBFullGame::new();
+        assert_ne!(fast.abstraction_fingerprint(), default.abstraction_fingerprint());
+
+        let source = CFRSolver::new(fast.clone(), CFRConfig::default().with_seed(1));
+        let checkpoint = source.export_state();
+        assert_eq!(checkpoint.abstraction_fingerprint, fast.abstraction_fingerprint());
+
+        // Different bucket counts => rejected
+        let mut mismatched = CFRSolver::new(default, CFRConfig::default());
+        assert!(mismatched.import_state(checkpoint.clone()).is_err());
+
+        // Same abstraction => accepted
+        let mut matched = CFRSolver::new(fast, CFRConfig::default());
+        assert!(matched.import_state(checkpoint).is_ok());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "player index 2 is out of range")]
+    fn test_get_payoff_rejects_third_player() {
+        let game = SBvsBBFullGame::fast();
+        let mut rng = rand::thread_rng();
+
+        let state = game.sample_chance(&game.initial_state(), &mut rng);
+        let state = game.apply_action(&state, &PokerAction::Fold);
+        assert!(game.is_terminal(&state));
+
+        game.get_payoff(&state, 2);
+    }
+
+    #[test]
+    fn test_deterministic_config_reproduces_solve() {
+        // Coarse abstraction: bucket lookups dominate full-game iteration cost
+        let game = SBvsBBFullGame::with_config(SBvsBBConfig {
+            abstraction: AbstractionConfig {
+                flop_buckets: 8,
+                turn_buckets: 8,
+                river_buckets: 8,
+                equity_samples: 8,
+                ..AbstractionConfig::default()
+            },
+            ..SBvsBBConfig::fast()
+        });
+        let mut first = CFRSolver::new(game.clone(), CFRConfig::deterministic(7));
+        let mut second = CFRSolver::new(game, CFRConfig::deterministic(7));
+        first.train(200);
+        second.train(200);
+
+        assert_eq!(first.num_info_sets(), second.num_info_sets());
+        assert_eq!(first.report().strategies, second.report().strategies);
+    }
+
+    #[test]
+    fn test_antes_and_straddle_reach_root() {
+        use rand::SeedableRng;
+
+        let game = SBvsBBFullGame::with_config(SBvsBBConfig {
+            ante: 0.12,
+            straddle: Some(2.0),
+            ..SBvsBBConfig::fast()
+        });
+        let root = game.initial_state();
+        assert!((root.pot - (2.0 + 1.0 + 2.0 * 0.12)).abs() < 1e-9);
+        assert_eq!(root.invested_total, [2.12, 1.12]);
+
+        // BB acts first against the straddle, facing 1bb more
+        let dealt = game.sample_chance(&root, &mut rand::rngs::StdRng::seed_from_u64(0));
+        assert_eq!(game.current_player(&dealt), Some(HUPosition::BB.index()));
+        assert_eq!(dealt.to_call, 1.0);
+
+        crate::cfr::assert_game_invariants(&game, 100);
+    }
+
+    #[test]
+    fn test_random_playout_invariants() {
+        crate::cfr::assert_game_invariants(&SBvsBBFullGame::fast(), 300);
+    }
+
+    /// River showdown where SB has put in `invested[0]` and BB `invested[1]`.
+    fn scripted_showdown(sb: &str, bb: &str, board: &str, invested: [f64; 2]) -> PokerState {
+        let mut state = PokerState::new_hu([50.0, 50.0], 0.5, 1.0);
+        state.hands = [Some(HoleCards::from_str(sb).unwrap()), Some(HoleCards::from_str(bb).unwrap())];
+        state.board = Board::from_str(board).unwrap();
+        state.street = Street::River;
+        state.invested_total = invested;
+        state.stacks = [50.0 - invested[0], 50.0 - invested[1]];
+        state.pot = invested[0] + invested[1];
+        state.is_terminal = true;
+        state
+    }
+
+    #[test]
+    fn test_showdown_returns_uncalled_bet() {
+        let game = SBvsBBFullGame::new();
+
+        // Both play the board; SB's last 10bb were never called
+        let chop = scripted_showdown("AhKd", "AcKs", "2c3d4h5s6c", [20.0, 10.0]);
+        assert_eq!(game.get_payoff(&chop, 0), 0.0);
+        assert_eq!(game.get_payoff(&chop, 1), 0.0);
+
+        // Losing an overbet only costs the matched part
+        let loss = scripted_showdown("7h2d", "AcAs", "KcQdJh9s3c", [20.0, 10.0]);
+        assert_eq!(game.get_payoff(&loss, 0), -10.0);
+        assert_eq!(game.get_payoff(&loss, 1), 10.0);
+
+        // Even investments split exactly as before
+        let even = scripted_showdown("AhKd", "AcKs", "2c3d4h5s6c", [10.0, 10.0]);
+        assert_eq!(game.get_payoff(&even, 0), 0.0);
+
+        // Rake comes out of the contested pot only
+        let raked = SBvsBBFullGame::with_config(SBvsBBConfig {
+            rake: Some(RakeConfig { percent: 0.05, cap: 3.0, nfnd: true }),
+            ..SBvsBBConfig::default()
+        });
+        assert!((raked.get_payoff(&chop, 0) + 0.5).abs() < 1e-9);
+        assert!((raked.get_payoff(&chop, 1) + 0.5).abs() < 1e-9);
+    }
+
+    fn coarse_game() -> SBvsBBFullGame {
+        SBvsBBFullGame::with_config(SBvsBBConfig {
+            abstraction: AbstractionConfig {
+                flop_buckets: 8,
+                turn_buckets: 8,
+                river_buckets: 8,
+                equity_samples: 8,
+                ..AbstractionConfig::default()
+            },
+            ..SBvsBBConfig::fast()
+        })
+    }
+
+    /// Key without the street, keeping only the current street's actions.
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    struct StreetlessKey(String);
+
+    impl InfoState for StreetlessKey {
+        fn key(&self) -> String {
+            self.0.clone()
+        }
+    }
+
+    /// The full game with a deliberately broken abstraction.
+    #[derive(Clone)]
+    struct StreetlessGame(SBvsBBFullGame);
+
+    impl Game for StreetlessGame {
+        type State = PokerState;
+        type Action = PokerAction;
+        type InfoState = StreetlessKey;
+
+        fn initial_state(&self) -> PokerState {
+            self.0.initial_state()
+        }
+        fn is_terminal(&self, state: &PokerState) -> bool {
+            self.0.is_terminal(state)
+        }
+        fn get_payoff(&self, state: &PokerState, player: usize) -> f64 {
+            self.0.get_payoff(state, player)
+        }
+        fn current_player(&self, state: &PokerState) -> Option<usize> {
+            self.0.current_player(state)
+        }
+        fn num_players(&self) -> usize {
+            2
+        }
+        fn available_actions(&self, state: &PokerState) -> Vec<PokerAction> {
+            self.0.available_actions(state)
+        }
+        fn apply_action(&self, state: &PokerState, action: &PokerAction) -> PokerState {
+            self.0.apply_action(state, action)
+        }
+        fn info_state(&self, state: &PokerState) -> StreetlessKey {
+            let info = self.0.info_state(state);
+            let street_actions = info.history.rsplit('|').next().unwrap_or("");
+            StreetlessKey(format!("P{}B{}|{}", info.position, info.bucket, street_actions))
+        }
+        fn is_chance(&self, state: &PokerState) -> bool {
+            self.0.is_chance(state)
+        }
+        fn sample_chance<R: Rng>(&self, state: &PokerState, rng: &mut R) -> PokerState {
+            self.0.sample_chance(state, rng)
+        }
+        fn state_description(&self, state: &PokerState) -> String {
+            self.0.state_description(state)
+        }
+    }
+
+    #[test]
+    fn test_collision_check_accepts_full_keys() {
+        let mut solver = CFRSolver::new(coarse_game(), CFRConfig::deterministic(3).with_collision_check(true));
+        solver.train(50);
+        assert!(solver.num_info_sets() > 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "info-set key collision")]
+    fn test_collision_check_detects_streetless_keys() {
+        // Same-looking lines on different streets share keys but face different pot-sized bets
+        let game = StreetlessGame(coarse_game());
+        let mut solver = CFRSolver::new(game, CFRConfig::deterministic(3).with_collision_check(true));
+        solver.train(200);
+    }
 }
@@ -3,8 +3,11 @@
 //! This module provides utilities for working with poker hand ranges,
 //! including range notation parsing and combo enumeration.
 
-use super::card::{Card, HoleCards};
+use rand::Rng;
+
+use super::card::{Card, CardSet, HoleCards};
 use super::abstraction::HandClass;
+use crate::games::preflop_ranges::hand_class_to_grid;
 use std::collections::HashSet;
 
 /// A poker range represented as a set of hand classes.
@@ -29,6 +32,39 @@ impl Range {
         range
     }
 
+    /// The strongest hand classes covering `pct` (0.0-1.0) of all 1326 combos.
+    ///
+    /// Classes are ranked by the range solver's HRC-calibrated opening
+    /// playability score, with ties going to higher cards and then to suited
+    /// hands. Whole classes are added in that order, weighted by their combo
+    /// counts, and the class that crosses the target is kept only if that
+    /// lands closer to `pct` than stopping short.
+    pub fn from_percentage(pct: f64) -> Self {
+        use crate::games::preflop_ranges::compute_playability;
+
+        let target = pct.clamp(0.0, 1.0) * 1326.0;
+        let mut ranked: Vec<(f64, HandClass)> = (0..169u8)
+            .map(|i| (compute_playability(i), HandClass::from_index(i)))
+            .collect();
+        ranked.sort_by(|(a, ha), (b, hb)| {
+            b.total_cmp(a)
+                .then((hb.rank1, hb.rank2).cmp(&(ha.rank1, ha.rank2)))
+                .then(hb.suited.cmp(&ha.suited))
+        });
+
+        let mut range = Self::empty();
+        let mut combos = 0.0;
+        for (_, hc) in ranked {
+            let next = combos + hc.num_combos() as f64;
+            if combos >= target || next - target > target - combos {
+                break;
+            }
+            range.add_class(hc.index());
+            combos = next;
+        }
+        range
+    }
+
     /// Add a hand class to the range.
     pub fn add_class(&mut self, class_idx: u8) {
         let word = (class_idx / 64) as usize;
@@ -99,28 +135,58 @@ impl Range {
             .collect()
     }
 
+    /// Membership laid out on the standard 13x13 grid.
+    /// Row/column 0 is the Ace; pairs sit on the diagonal, suited hands
+    /// above it and offsuit hands below it.
+    pub fn to_grid_mask(&self) -> [[bool; 13]; 13] {
+        let mut mask = [[false; 13]; 13];
+        for class_idx in 0..169u8 {
+            let (row, col) = hand_class_to_grid(class_idx);
+            mask[row][col] = self.contains_class(class_idx);
+        }
+        mask
+    }
+
     /// Parse a range from notation string.
-    /// Supports: "AA", "AKs", "AKo", "AK" (both suited and offsuit), "TT+", "AQs+", "A5s-A2s"
+    /// Supports: "AA", "AKs", "AKo", "AK" (both suited and offsuit), "TT+", "AQs+", "A5s-A2s", "22-55"
+    ///
+    /// A term with a leading `-` removes hands instead of adding them, so
+    /// "22+, -22-55" is every pair from 66 up. Removals apply after all
+    /// additions regardless of where they appear.
     pub fn from_notation(notation: &str) -> Result<Self, RangeParseError> {
         let mut range = Self::empty();
+        let mut removed = Self::empty();
 
         for part in notation.split(',').map(|s| s.trim()) {
             if part.is_empty() {
                 continue;
             }
 
-            if let Err(e) = Self::parse_part(&mut range, part) {
+            if let Err(e) = Self::parse_part(&mut range, &mut removed, part) {
                 return Err(e);
             }
         }
 
+        for (word, removed_word) in range.classes.iter_mut().zip(removed.classes) {
+            *word &= !removed_word;
+        }
+
         Ok(range)
     }
 
     /// Parse a single part of range notation.
-    fn parse_part(range: &mut Range, part: &str) -> Result<(), RangeParseError> {
+    /// Removal terms (leading `-`) are collected into `removed`.
+    fn parse_part(range: &mut Range, removed: &mut Range, part: &str) -> Result<(), RangeParseError> {
         let part = part.trim();
 
+        if let Some(rest) = part.strip_prefix('-') {
+            let rest = rest.trim();
+            if rest.is_empty() || rest.starts_with('-') {
+                return Err(RangeParseError::InvalidFormat(part.to_string()));
+            }
+            return Self::parse_part(removed, &mut Self::empty(), rest);
+        }
+
         // Check for range notation (e.g., "A5s-A2s")
         if part.contains('-') && !part.starts_with('-') {
             let parts: Vec<&str> = part.split('-').collect();
@@ -228,6 +294,14 @@ impl Range {
         let e_r1 = Self::parse_rank(end_chars[0])?;
         let e_r2 = Self::parse_rank(end_chars[1])?;
 
+        // Pair ranges (e.g., "22-55")
+        if s_r1 == s_r2 && e_r1 == e_r2 {
+            for rank in s_r1.min(e_r1)..=s_r1.max(e_r1) {
+                range.add_class(HandClass { rank1: rank, rank2: rank, suited: false }.index());
+            }
+            return Ok(());
+        }
+
         // Must have same high card for range
         if s_r1 != e_r1 && s_r1.max(s_r2) != e_r1.max(e_r2) {
             return Err(RangeParseError::InvalidRange(format!("{}-{}", start, end)));
@@ -282,6 +356,98 @@ impl Range {
     }
 }
 
+/// A range with a weight per hand class, such as a solved strategy's
+/// frequency of taking one action with each hand.
+///
+/// Every combo of a class carries the class weight, so a class's share of
+/// the range is its weight times its live combos.
+#[derive(Debug, Clone)]
+pub struct WeightedRange {
+    weights: [f64; 169],
+}
+
+impl WeightedRange {
+    /// A range with every weight 0.
+    pub fn empty() -> Self {
+        Self { weights: [0.0; 169] }
+    }
+
+    /// Weight `(class index, weight)` pairs; classes not listed get 0.
+    ///
+    /// # Panics
+    /// Panics on a class index above 168 or a negative or non-finite weight.
+    pub fn from_weights(weights: impl IntoIterator<Item = (u8, f64)>) -> Self {
+        let mut range = Self::empty();
+        for (class_idx, weight) in weights {
+            range.set_weight(class_idx, weight);
+        }
+        range
+    }
+
+    /// Set the weight of a hand class.
+    ///
+    /// # Panics
+    /// Panics on a class index above 168 or a negative or non-finite weight.
+    pub fn set_weight(&mut self, class_idx: u8, weight: f64) {
+        assert!(
+            weight.is_finite() && weight >= 0.0,
+            "weight {} for class {} must be finite and non-negative",
+            weight,
+            class_idx
+        );
+        self.weights[class_idx as usize] = weight;
+    }
+
+    /// Weight of a hand class.
+    pub fn weight(&self, class_idx: u8) -> f64 {
+        self.weights[class_idx as usize]
+    }
+
+    /// Draw a combo with probability proportional to its class weight,
+    /// skipping combos that use a card in `blockers`.
+    ///
+    /// Returns `None` when every combo with positive weight is blocked.
+    pub fn sample_combo<R: Rng>(&self, rng: &mut R, blockers: &[Card]) -> Option<HoleCards> {
+        let live = CardSet::without(blockers);
+        let mut combos = Vec::new();
+        let mut cumulative = Vec::new();
+        let mut total = 0.0;
+
+        for (class_idx, &weight) in self.weights.iter().enumerate() {
+            if weight <= 0.0 {
+                continue;
+            }
+            for combo in HandClass::from_index(class_idx as u8).enumerate_combos() {
+                if combo.cards().iter().all(|&c| live.contains(c)) {
+                    total += weight;
+                    combos.push(combo);
+                    cumulative.push(total);
+                }
+            }
+        }
+
+        if combos.is_empty() {
+            return None;
+        }
+        let roll = rng.gen::<f64>() * total;
+        let idx = cumulative.partition_point(|&c| c <= roll).min(combos.len() - 1);
+        Some(combos[idx])
+    }
+}
+
+impl Default for WeightedRange {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl From<&Range> for WeightedRange {
+    /// Weight 1 for every class in the range.
+    fn from(range: &Range) -> Self {
+        Self::from_weights(range.iter_classes().map(|hc| (hc.index(), 1.0)))
+    }
+}
+
 /// Error type for range parsing.
 #[derive(Debug, Clone)]
 pub enum RangeParseError {
@@ -308,6 +474,27 @@ impl std::error::Error for RangeParseError {}
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_from_percentage() {
+        let top = Range::from_percentage(0.15);
+        let fraction = top.num_combos() as f64 / 1326.0;
+        assert!((fraction - 0.15).abs() < 0.01, "top 15% holds {} combos", top.num_combos());
+
+        let has = |hand: &str| {
+            Range::from_notation(hand).unwrap().iter_classes().all(|hc| top.contains_class(hc.index()))
+        };
+        assert!(has("AA"));
+        assert!(has("AKs"));
+        assert!(!has("72o"));
+
+        assert_eq!(Range::from_percentage(0.0).num_combos(), 0);
+        assert_eq!(Range::from_percentage(1.0).num_classes(), 169);
+
+        // Wider ranges contain narrower ones
+        let wider = Range::from_percentage(0.30);
+        assert!(top.iter_classes().all(|hc| wider.contains_class(hc.index())));
+    }
+
     #[test]
     fn test_empty_and_all() {
         let empty = Range::empty();
@@ -364,6 +551,45 @@ mod tests {
         let range = Range::from_notation("A5s-A2s").unwrap();
         assert_eq!(range.num_classes(), 4);
         assert_eq!(range.num_combos(), 16);
+
+        let pairs = Range::from_notation("55-22").unwrap();
+        assert_eq!(pairs.num_classes(), 4);
+        assert_eq!(pairs.num_combos(), 24);
+    }
+
+    #[test]
+    fn test_parse_removal_terms() {
+        // 22+ minus 22-55 leaves 66 through AA
+        let range = Range::from_notation("22+, -22-55").unwrap();
+        assert_eq!(range.num_classes(), 9);
+        assert_eq!(range.num_combos(), 54);
+        assert!(!range.contains_class(HandClass { rank1: 3, rank2: 3, suited: false }.index()));
+        assert!(range.contains_class(HandClass { rank1: 4, rank2: 4, suited: false }.index()));
+
+        // Removals apply after additions, wherever they appear
+        let same = Range::from_notation("-22, -33, 22+").unwrap();
+        assert_eq!(same.num_combos(), 66);
+
+        let broadways = Range::from_notation("AK, KQ, -KQo").unwrap();
+        assert_eq!(broadways.num_combos(), 20);
+
+        assert!(Range::from_notation("22+, -").is_err());
+        assert!(Range::from_notation("--22").is_err());
+    }
+
+    #[test]
+    fn test_to_grid_mask() {
+        let range = Range::from_notation("AA, AKs, AKo").unwrap();
+        let mask = range.to_grid_mask();
+
+        let set: Vec<(usize, usize)> = (0..13)
+            .flat_map(|r| (0..13).map(move |c| (r, c)))
+            .filter(|&(r, c)| mask[r][c])
+            .collect();
+        assert_eq!(set, vec![(0, 0), (0, 1), (1, 0)]);
+
+        let all = Range::all().to_grid_mask();
+        assert!(all.iter().all(|row| row.iter().all(|&cell| cell)));
     }
 
     #[test]
@@ -406,4 +632,50 @@ mod tests {
         // With one ace blocked, only 3 combos remain
         assert_eq!(combos.len(), 3);
     }
+
+    #[test]
+    fn test_weighted_range_sampling() {
+        use rand::SeedableRng;
+
+        let class = |notation: &str| Range::from_notation(notation).unwrap().iter_classes().next().unwrap();
+        let weighted = [("AA", 1.0), ("AKs", 0.5), ("QJo", 0.75), ("72o", 0.25), ("KK", 0.0)];
+        let range = WeightedRange::from_weights(weighted.iter().map(|&(hand, w)| (class(hand).index(), w)));
+
+        // A♠ K♦ removed: AA keeps 3 combos, AKs 3, the rest all of theirs
+        let blockers = [Card::from_str("As").unwrap(), Card::from_str("Kd").unwrap()];
+        let shares: Vec<f64> = weighted
+            .iter()
+            .map(|&(hand, w)| w * class(hand).count_unblocked_combos(&blockers) as f64)
+            .collect();
+        let total: f64 = shares.iter().sum();
+
+        let n = 20_000;
+        let mut rng = rand::rngs::StdRng::seed_from_u64(11);
+        let mut counts = [0u32; 5];
+        for _ in 0..n {
+            let combo = range.sample_combo(&mut rng, &blockers).unwrap();
+            assert!(!blockers.iter().any(|&b| combo.contains(b)), "{} uses a blocker", combo);
+            let slot = weighted.iter().position(|&(hand, _)| class(hand).index() == combo.hand_class_index());
+            counts[slot.expect("sampled a class outside the range")] += 1;
+        }
+
+        for (i, &(hand, _)) in weighted.iter().enumerate() {
+            let p = shares[i] / total;
+            let observed = counts[i] as f64 / n as f64;
+            // 5 standard errors
+            let tolerance = 5.0 * (p * (1.0 - p) / n as f64).sqrt() + 1e-9;
+            assert!((observed - p).abs() <= tolerance, "{}: observed {} expected {}", hand, observed, p);
+        }
+        assert_eq!(counts[4], 0, "zero-weight KK was sampled");
+
+        // Nothing left to draw
+        let kings: Vec<Card> = ["Kc", "Kd", "Kh", "Ks"].iter().map(|c| Card::from_str(c).unwrap()).collect();
+        let aks_only = WeightedRange::from_weights([(class("AKs").index(), 1.0)]);
+        assert!(aks_only.sample_combo(&mut rng, &kings).is_none());
+        assert!(WeightedRange::empty().sample_combo(&mut rng, &[]).is_none());
+
+        // Plain ranges convert with weight 1 per class
+        let uniform = WeightedRange::from(&Range::from_notation("AA").unwrap());
+        assert_eq!(uniform.weight(class("AA").index()), 1.0);
+    }
 }
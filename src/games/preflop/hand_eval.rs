@@ -2,9 +2,12 @@
 //!
 //! This module provides hand ranking and comparison for 5-7 card poker hands.
 //! The evaluator uses a combination of bit manipulation and direct calculation
-//! for fast hand ranking.
+//! for fast hand ranking: 6- and 7-card hands are ranked straight from
+//! per-rank and per-suit bitmasks, with straights read from a table built at
+//! compile time, instead of scoring every 5-card subset.
 
-use super::card::{Card, HoleCards, Board};
+use super::card::{Card, CardSet, HoleCards, Board};
+use super::hand::Range;
 use std::cmp::Ordering;
 
 /// Hand rank categories, ordered from worst to best.
@@ -88,6 +91,61 @@ impl Ord for HandRank {
     }
 }
 
+/// Value of [`STRAIGHT_HIGH`] for rank masks without a straight.
+const NO_STRAIGHT: u8 = 0;
+
+/// Straight high card for every 13-bit rank mask, as returned by
+/// [`HandEvaluator::find_straight`], or [`NO_STRAIGHT`].
+static STRAIGHT_HIGH: [u8; 1 << 13] = build_straight_table();
+
+const fn build_straight_table() -> [u8; 1 << 13] {
+    let mut table = [NO_STRAIGHT; 1 << 13];
+    let mut rank_bits = 0;
+    while rank_bits < table.len() {
+        table[rank_bits] = straight_high(rank_bits as u16);
+        rank_bits += 1;
+    }
+    table
+}
+
+/// High card of the highest straight in `rank_bits`, or [`NO_STRAIGHT`].
+const fn straight_high(rank_bits: u16) -> u8 {
+    // Add ace-low bit for wheel straight (A-2-3-4-5)
+    let bits = rank_bits | ((rank_bits >> 12) & 1);
+
+    // Check from ace-high down
+    let mut high = 14;
+    while high >= 4 {
+        let mask = if high == 4 {
+            // Wheel: A-2-3-4-5 = bits 12,0,1,2,3
+            0b1_0000_0000_1111u16
+        } else {
+            0b11111u16 << (high - 4)
+        };
+
+        if (bits & mask) == mask {
+            // For wheel, high card is 5 (index 3)
+            return if high == 4 { 3 } else { high as u8 - 2 };
+        }
+        high -= 1;
+    }
+
+    NO_STRAIGHT
+}
+
+/// The `N` highest ranks in a rank mask, best first, padded with 0.
+fn top_ranks<const N: usize>(mut rank_bits: u16) -> [u8; N] {
+    let mut ranks = [0; N];
+    for rank in ranks.iter_mut() {
+        if rank_bits == 0 {
+            break;
+        }
+        *rank = 15 - rank_bits.leading_zeros() as u8;
+        rank_bits &= !(1 << *rank);
+    }
+    ranks
+}
+
 /// Hand evaluator for poker hands.
 #[derive(Debug, Clone, Default)]
 pub struct HandEvaluator;
@@ -202,6 +260,13 @@ impl HandEvaluator {
 
     /// Evaluate a 7-card hand (best 5-card combination).
     pub fn evaluate_7(&self, cards: &[Card; 7]) -> HandRank {
+        self.evaluate_best(cards)
+    }
+
+    /// Evaluate a 7-card hand by scoring all 21 five-card subsets.
+    ///
+    /// The slow reference that [`evaluate_7`](Self::evaluate_7) must agree with.
+    pub fn evaluate_7_exhaustive(&self, cards: &[Card; 7]) -> HandRank {
         let mut best = HandRank(0);
 
         // Try all 21 combinations of 5 cards from 7
@@ -276,46 +341,84 @@ impl HandEvaluator {
 
     /// Evaluate a 6-card hand (best 5-card combination).
     fn evaluate_6(&self, cards: &[Card; 6]) -> HandRank {
-        let mut best = HandRank(0);
+        self.evaluate_best(cards)
+    }
 
-        // Try all 6 combinations of 5 cards from 6
-        for skip in 0..6 {
-            let hand: Vec<Card> = cards.iter()
-                .enumerate()
-                .filter(|&(i, _)| i != skip)
-                .map(|(_, &c)| c)
-                .collect();
-            let rank = self.evaluate_5(&[hand[0], hand[1], hand[2], hand[3], hand[4]]);
-            if rank > best {
-                best = rank;
+    /// Rank the best 5-card hand among 5-7 cards.
+    ///
+    /// Gives exactly the rank of the best 5-card subset under
+    /// [`evaluate_5`](Self::evaluate_5), without enumerating subsets: with at
+    /// most 7 cards a flush rules out quads and full houses, so each category
+    /// can be read off the rank and suit masks in order.
+    fn evaluate_best(&self, cards: &[Card]) -> HandRank {
+        // Ranks held at least once, twice, three and four times, and per suit
+        let (mut ones, mut twos, mut threes, mut fours) = (0u16, 0u16, 0u16, 0u16);
+        let mut suit_bits = [0u16; 4];
+        for card in cards {
+            let bit = 1u16 << card.rank();
+            fours |= threes & bit;
+            threes |= twos & bit;
+            twos |= ones & bit;
+            ones |= bit;
+            suit_bits[card.suit() as usize] |= bit;
+        }
+
+        let flush = suit_bits.iter().copied().find(|bits| bits.count_ones() >= 5);
+        if let Some(high) = flush.and_then(|bits| self.find_straight(bits)) {
+            return HandRank::new(HandCategory::StraightFlush, &[high]);
+        }
+
+        if fours != 0 {
+            let [quads] = top_ranks(fours);
+            let [kicker] = top_ranks(ones & !(1 << quads));
+            return HandRank::new(HandCategory::FourOfAKind, &[quads, kicker]);
+        }
+
+        if threes != 0 {
+            let [trips] = top_ranks(threes);
+            let pairs = twos & !(1 << trips);
+            if pairs != 0 {
+                let [pair] = top_ranks(pairs);
+                return HandRank::new(HandCategory::FullHouse, &[trips, pair]);
             }
         }
 
-        best
+        if let Some(bits) = flush {
+            return HandRank::new(HandCategory::Flush, &top_ranks::<5>(bits));
+        }
+
+        if let Some(high) = self.find_straight(ones) {
+            return HandRank::new(HandCategory::Straight, &[high]);
+        }
+
+        if threes != 0 {
+            let [trips] = top_ranks(threes);
+            let [k1, k2] = top_ranks(ones & !(1 << trips));
+            return HandRank::new(HandCategory::ThreeOfAKind, &[trips, k1, k2]);
+        }
+
+        if twos.count_ones() >= 2 {
+            let [high, low] = top_ranks(twos);
+            let [kicker] = top_ranks(ones & !(1 << high) & !(1 << low));
+            return HandRank::new(HandCategory::TwoPair, &[high, low, kicker]);
+        }
+
+        if twos != 0 {
+            let [pair] = top_ranks(twos);
+            let [k1, k2, k3] = top_ranks(ones & !(1 << pair));
+            return HandRank::new(HandCategory::OnePair, &[pair, k1, k2, k3]);
+        }
+
+        HandRank::new(HandCategory::HighCard, &top_ranks::<5>(ones))
     }
 
     /// Find the highest straight from a rank bitmask.
     /// Returns the high card of the straight, or None if no straight.
     fn find_straight(&self, rank_bits: u16) -> Option<u8> {
-        // Add ace-low bit for wheel straight (A-2-3-4-5)
-        let bits = rank_bits | ((rank_bits >> 12) & 1);
-
-        // Check from ace-high down
-        for high in (4..=14).rev() {
-            let mask = if high == 4 {
-                // Wheel: A-2-3-4-5 = bits 12,0,1,2,3
-                0b1_0000_0000_1111u16
-            } else {
-                0b11111u16 << (high - 4)
-            };
-
-            if (bits & mask) == mask {
-                // For wheel, high card is 5 (index 3)
-                return Some(if high == 4 { 3 } else { high as u8 - 2 });
-            }
+        match STRAIGHT_HIGH[rank_bits as usize] {
+            NO_STRAIGHT => None,
+            high => Some(high),
         }
-
-        None
     }
 
     /// Compare two hands. Returns positive if hand1 wins, negative if hand2 wins, 0 for tie.
@@ -329,6 +432,30 @@ impl HandEvaluator {
             Ordering::Equal => 0,
         }
     }
+
+    /// Rank every player's hand at a multi-way showdown.
+    ///
+    /// Takes `(hole_cards, player)` pairs and returns `(player, rank)` in the
+    /// same order. Use [`HandEvaluator::winners`] to find who takes the pot.
+    pub fn rank_all(&self, hands: &[(&HoleCards, usize)], board: &Board) -> Vec<(usize, HandRank)> {
+        hands
+            .iter()
+            .map(|&(hole, player)| (player, self.evaluate(hole, board)))
+            .collect()
+    }
+
+    /// Players holding the best rank; more than one means a split pot.
+    pub fn winners(ranks: &[(usize, HandRank)]) -> Vec<usize> {
+        let best = match ranks.iter().map(|&(_, rank)| rank).max() {
+            Some(best) => best,
+            None => return Vec::new(),
+        };
+        ranks
+            .iter()
+            .filter(|&&(_, rank)| rank == best)
+            .map(|&(player, _)| player)
+            .collect()
+    }
 }
 
 /// Calculate equity of hole cards against a range on a given board.
@@ -337,8 +464,18 @@ pub fn calculate_equity_vs_random(hole_cards: &HoleCards, board: &Board, samples
     use rand::SeedableRng;
     use rand::rngs::StdRng;
 
+    calculate_equity_vs_random_with_rng(hole_cards, board, samples, &mut StdRng::from_entropy())
+}
+
+/// [`calculate_equity_vs_random`] drawing runouts from `rng`, so a seeded
+/// RNG gives a repeatable estimate.
+pub fn calculate_equity_vs_random_with_rng<R: rand::Rng>(
+    hole_cards: &HoleCards,
+    board: &Board,
+    samples: usize,
+    rng: &mut R,
+) -> f64 {
     let evaluator = HandEvaluator::new();
-    let mut rng = StdRng::from_entropy();
     let mut wins = 0.0;
     let mut total = 0.0;
 
@@ -348,20 +485,26 @@ pub fn calculate_equity_vs_random(hole_cards: &HoleCards, board: &Board, samples
         .copied()
         .collect();
 
+    let live = CardSet::without(&dead);
+    let mut draw = |deck: &mut CardSet| {
+        let card = deck.random_card(rng).expect("deck ran out of cards");
+        deck.remove(card);
+        card
+    };
+
     for _ in 0..samples {
         // Deal opponent's hand and remaining board
-        let mut deck = super::card::Deck::without(&dead);
-        deck.shuffle(&mut rng);
+        let mut deck = live;
 
         // Deal opponent's two cards
-        let opp1 = deck.deal().unwrap();
-        let opp2 = deck.deal().unwrap();
+        let opp1 = draw(&mut deck);
+        let opp2 = draw(&mut deck);
         let opp_hand = HoleCards::new(opp1, opp2);
 
         // Complete the board
         let mut full_board = board.clone();
         while full_board.len() < 5 {
-            full_board.add(deck.deal().unwrap());
+            full_board.add(draw(&mut deck));
         }
 
         // Compare hands
@@ -377,6 +520,138 @@ pub fn calculate_equity_vs_random(hole_cards: &HoleCards, board: &Board, samples
     wins / total
 }
 
+/// Calculate equity of hole cards against a villain range on a given board.
+///
+/// Villain combos blocked by the hole cards or board are skipped. On a
+/// complete board every live combo is evaluated exactly and `samples` is
+/// ignored; otherwise `samples` runouts are drawn, each against a uniformly
+/// chosen live combo. Returns 0.5 if the board leaves villain no combos.
+pub fn calculate_equity_vs_range(hole_cards: &HoleCards, board: &Board, villain: &Range, samples: usize) -> f64 {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    calculate_equity_vs_range_with_rng(hole_cards, board, villain, samples, &mut StdRng::from_entropy())
+}
+
+/// [`calculate_equity_vs_range`] drawing runouts from `rng`.
+pub fn calculate_equity_vs_range_with_rng<R: rand::Rng>(
+    hole_cards: &HoleCards,
+    board: &Board,
+    villain: &Range,
+    samples: usize,
+    rng: &mut R,
+) -> f64 {
+    let evaluator = HandEvaluator::new();
+    let dead: Vec<Card> = hole_cards.cards().iter()
+        .chain(board.cards().iter())
+        .copied()
+        .collect();
+    let combos = villain.enumerate_unblocked_combos(&dead);
+    if combos.is_empty() {
+        return 0.5;
+    }
+
+    let score = |hero: HandRank, villain: HandRank| match hero.cmp(&villain) {
+        Ordering::Greater => 1.0,
+        Ordering::Equal => 0.5,
+        Ordering::Less => 0.0,
+    };
+
+    if board.len() >= 5 {
+        let hero = evaluator.evaluate(hole_cards, board);
+        let total: f64 = combos.iter()
+            .map(|combo| score(hero, evaluator.evaluate(combo, board)))
+            .sum();
+        return total / combos.len() as f64;
+    }
+
+    let live = CardSet::without(&dead);
+    let mut total = 0.0;
+    for _ in 0..samples {
+        let combo = &combos[rng.gen_range(0..combos.len())];
+        let mut deck = live;
+        deck.remove(combo.card1);
+        deck.remove(combo.card2);
+
+        let mut full_board = board.clone();
+        while full_board.len() < 5 {
+            let card = deck.random_card(rng).expect("deck ran out of cards");
+            deck.remove(card);
+            full_board.add(card);
+        }
+        total += score(evaluator.evaluate(hole_cards, &full_board), evaluator.evaluate(combo, &full_board));
+    }
+
+    total / samples.max(1) as f64
+}
+
+/// Runouts sampled by [`equity_distribution`].
+pub const EQUITY_DISTRIBUTION_RUNOUTS: usize = 500;
+
+/// Histogram of hero's river equity against a villain range over random runouts.
+///
+/// Each sampled runout completes the board to the river and records hero's
+/// exact equity against villain's live combos there, so a made hand piles
+/// up near 1.0 while a draw splits between its hit and miss outcomes.
+/// Returns `bins` fractions summing to 1 (bin `i` covers equity
+/// `[i / bins, (i + 1) / bins)`, with 1.0 in the last bin), or all zeros if
+/// no runout leaves villain a live combo.
+pub fn equity_distribution(hero: &HoleCards, board: &Board, villain: &Range, bins: usize) -> Vec<f64> {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    equity_distribution_with_rng(hero, board, villain, bins, EQUITY_DISTRIBUTION_RUNOUTS, &mut StdRng::from_entropy())
+}
+
+/// [`equity_distribution`] over `runouts` runouts drawn from `rng`.
+pub fn equity_distribution_with_rng<R: rand::Rng>(
+    hero: &HoleCards,
+    board: &Board,
+    villain: &Range,
+    bins: usize,
+    runouts: usize,
+    rng: &mut R,
+) -> Vec<f64> {
+    let mut histogram = vec![0.0; bins];
+    if bins == 0 {
+        return histogram;
+    }
+
+    let dead: Vec<Card> = hero.cards().iter()
+        .chain(board.cards().iter())
+        .copied()
+        .collect();
+    let live = CardSet::without(&dead);
+    let mut counted = 0usize;
+
+    for _ in 0..runouts {
+        let mut deck = live;
+        let mut full_board = board.clone();
+        while full_board.len() < 5 {
+            let card = deck.random_card(rng).expect("deck ran out of cards");
+            deck.remove(card);
+            full_board.add(card);
+        }
+
+        // Skip runouts that leave villain's range empty
+        let mut blockers = dead.clone();
+        blockers.extend(full_board.cards().iter().skip(board.len()).copied());
+        if villain.count_unblocked_combos(&blockers) == 0 {
+            continue;
+        }
+
+        let equity = calculate_equity_vs_range_with_rng(hero, &full_board, villain, 0, rng);
+        let bin = ((equity * bins as f64) as usize).min(bins - 1);
+        histogram[bin] += 1.0;
+        counted += 1;
+    }
+
+    if counted > 0 {
+        histogram.iter_mut().for_each(|h| *h /= counted as f64);
+    }
+    histogram
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -394,6 +669,98 @@ mod tests {
         [cards[0], cards[1], cards[2], cards[3], cards[4]]
     }
 
+    fn random_hands(count: usize, seed: u64) -> Vec<[Card; 7]> {
+        use rand::SeedableRng;
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+        (0..count)
+            .map(|_| {
+                let mut deck = CardSet::FULL;
+                [(); 7].map(|_| {
+                    let card = deck.random_card(&mut rng).unwrap();
+                    deck.remove(card);
+                    card
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_evaluate_7_matches_exhaustive() {
+        let eval = HandEvaluator::new();
+        let crafted = [
+            "As Ks Qs Js Ts 9s 8s", // royal flush over a straight flush
+            "5h 4h 3h 2h Ah Kh 6c", // wheel flush with a higher plain flush
+            "As Ad Ah Ac Ks Kd Kh", // quads over a full house
+            "9s 9d 9h 7c 7d 7h 2s", // two sets
+            "Qs Qd 8h 8c 4d 4h Ac", // three pairs
+            "Ts 9s 8s 7s 2s Jd Qh", // flush beats the straight
+            "6s 5d 4h 3c 2s 9d Kh", // six-high run
+            "As 2d 3h 4c 5s 5d 9h", // wheel with a pair
+            "Kd Qd 9h 7c 5s 4d 2h", // high card
+        ];
+        let mut hands = random_hands(20_000, 7);
+        hands.extend(crafted.iter().map(|hand| {
+            let cards = cards_from_str(hand);
+            [cards[0], cards[1], cards[2], cards[3], cards[4], cards[5], cards[6]]
+        }));
+
+        for cards in &hands {
+            assert_eq!(eval.evaluate_7(cards), eval.evaluate_7_exhaustive(cards), "{:?}", cards);
+
+            // Six cards agree with the best of their 5-card subsets too
+            let six = [cards[0], cards[1], cards[2], cards[3], cards[4], cards[5]];
+            let best = (0..6)
+                .map(|skip| {
+                    let mut rest = six.iter().enumerate().filter(|&(i, _)| i != skip).map(|(_, &c)| c);
+                    eval.evaluate_5(&[(); 5].map(|_| rest.next().unwrap()))
+                })
+                .max()
+                .unwrap();
+            assert_eq!(eval.evaluate_6(&six), best, "{:?}", six);
+        }
+    }
+
+    #[test]
+    #[ignore] // Timing benchmark: cargo test --release test_evaluate_7_speedup -- --ignored
+    fn test_evaluate_7_speedup() {
+        use std::hint::black_box;
+        use std::time::Instant;
+
+        let eval = HandEvaluator::new();
+        let hands = random_hands(1_000_000, 11);
+
+        let start = Instant::now();
+        let fast: u64 = hands.iter().map(|cards| eval.evaluate_7(black_box(cards)).value() as u64).sum();
+        let fast_time = start.elapsed();
+
+        let start = Instant::now();
+        let slow: u64 = hands.iter().map(|cards| eval.evaluate_7_exhaustive(black_box(cards)).value() as u64).sum();
+        let slow_time = start.elapsed();
+
+        let speedup = slow_time.as_secs_f64() / fast_time.as_secs_f64();
+        println!("1M hands: {:?} vs {:?} exhaustive ({:.1}x)", fast_time, slow_time, speedup);
+        assert_eq!(fast, slow);
+        assert!(speedup >= 5.0, "speedup {:.1}x", speedup);
+    }
+
+    #[test]
+    fn test_rank_all_three_way_split() {
+        let eval = HandEvaluator::new();
+        let board = Board::from_str("As Kd Qh 7c 2s").unwrap();
+        let broadway1 = HoleCards::from_str("JhTh").unwrap();
+        let broadway2 = HoleCards::from_str("JdTc").unwrap();
+        let nines = HoleCards::from_str("9s9d").unwrap();
+
+        let ranks = eval.rank_all(&[(&broadway1, 0), (&nines, 3), (&broadway2, 5)], &board);
+        assert_eq!(ranks.iter().map(|&(p, _)| p).collect::<Vec<_>>(), vec![0, 3, 5]);
+        assert_eq!(ranks[0].1, ranks[2].1);
+        assert!(ranks[1].1 < ranks[0].1);
+
+        assert_eq!(HandEvaluator::winners(&ranks), vec![0, 5]);
+        assert!(HandEvaluator::winners(&[]).is_empty());
+    }
+
     #[test]
     fn test_high_card() {
         let eval = HandEvaluator::new();
@@ -523,4 +890,52 @@ mod tests {
         let equity = calculate_equity_vs_random(&low, &board, 1000);
         assert!(equity < 0.4, "72o equity {} should be < 40%", equity);
     }
+
+    #[test]
+    fn test_equity_vs_range() {
+        use rand::SeedableRng;
+
+        // Exact on the river: top set beats every overpair and AK
+        let hero = HoleCards::from_str("7h7d").unwrap();
+        let river = Board::from_str("7c Ks 2d 9h 3c").unwrap();
+        let villain = Range::from_notation("AA, QQ, AK").unwrap();
+        assert_eq!(calculate_equity_vs_range(&hero, &river, &villain, 0), 1.0);
+
+        // Sampled preflop: AA is a big favourite over a wide range
+        let aa = HoleCards::from_str("AhAs").unwrap();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(4);
+        let wide = Range::from_notation("22+, A2+, K9+, QT+").unwrap();
+        let equity = calculate_equity_vs_range_with_rng(&aa, &Board::new(), &wide, 2_000, &mut rng);
+        assert!(equity > 0.75, "AA equity {} vs wide range", equity);
+
+        // Fully blocked range
+        let blocked = Range::from_notation("77").unwrap();
+        assert_eq!(calculate_equity_vs_range(&hero, &river, &blocked, 0), 0.5);
+    }
+
+    #[test]
+    fn test_equity_distribution() {
+        use rand::SeedableRng;
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(8);
+        let villain = Range::from_notation("TT+, AQ+").unwrap();
+        let flop = Board::from_str("Ah Kh 2c").unwrap();
+
+        // Top set: nearly all mass in the top bin
+        let set = HoleCards::from_str("AdAc").unwrap();
+        let made = equity_distribution_with_rng(&set, &flop, &villain, 10, 400, &mut rng);
+        assert_eq!(made.len(), 10);
+        assert!((made.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        assert!(made[8] + made[9] > 0.8, "made hand distribution {:?}", made);
+
+        // Six-high flush draw: it either gets there or is drawing dead
+        let draw = HoleCards::from_str("6h5h").unwrap();
+        let dist = equity_distribution_with_rng(&draw, &flop, &villain, 10, 400, &mut rng);
+        let low = dist[0] + dist[1];
+        let high = dist[8] + dist[9];
+        assert!(low > 0.5 && high > 0.2, "draw distribution {:?}", dist);
+        assert!(1.0 - low - high < 0.15, "draw distribution {:?}", dist);
+
+        assert!(equity_distribution(&draw, &flop, &villain, 0).is_empty());
+    }
 }
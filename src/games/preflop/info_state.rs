@@ -7,6 +7,7 @@
 use super::card::Street;
 use super::state::{PokerState, HUPosition};
 use super::abstraction::CardAbstraction;
+use super::action::encode_history_bytes;
 use crate::cfr::game::InfoState;
 use std::fmt;
 
@@ -74,6 +75,16 @@ impl InfoState for PokerInfoState {
         // Example: P0S1B523|R300-C|X-B132-C
         format!("P{}S{}B{}|{}", self.position, self.street, self.bucket, self.history)
     }
+
+    fn key_bytes(&self) -> Vec<u8> {
+        // Layout: position, street, bucket (LE u16), encoded history
+        let mut bytes = Vec::with_capacity(4 + self.history.len());
+        bytes.push(self.position);
+        bytes.push(self.street);
+        bytes.extend_from_slice(&self.bucket.to_le_bytes());
+        encode_history_bytes(&self.history, &mut bytes);
+        bytes
+    }
 }
 
 impl fmt::Display for PokerInfoState {
@@ -265,6 +276,32 @@ mod tests {
         assert_eq!(compact.bucket(), 1000);
     }
 
+    #[test]
+    fn test_key_bytes_compact_and_unique() {
+        use std::collections::HashSet;
+
+        let histories = ["", "C", "C|X", "R300-C|X", "R300-C|X-B132", "R300-C|X-B132-C|", "C|X-B132"];
+        let mut infos = Vec::new();
+        for position in [HUPosition::SB, HUPosition::BB] {
+            for street in [Street::Flop, Street::Turn] {
+                for bucket in [0, 100, 300] {
+                    for history in histories {
+                        infos.push(PokerInfoState::new(position, street, bucket, history.to_string()));
+                    }
+                }
+            }
+        }
+
+        let keys: HashSet<String> = infos.iter().map(|i| i.key()).collect();
+        let bytes: HashSet<Vec<u8>> = infos.iter().map(|i| i.key_bytes()).collect();
+        assert_eq!(keys.len(), infos.len());
+        assert_eq!(bytes.len(), infos.len());
+
+        for info in &infos {
+            assert!(info.key_bytes().len() < info.key().len(), "{}", info.key());
+        }
+    }
+
     #[test]
     fn test_info_state_uniqueness() {
         // Same bucket, different history should produce different keys
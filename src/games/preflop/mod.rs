@@ -32,14 +32,14 @@ pub mod postflop_config;
 pub mod output;
 
 // Re-export commonly used types
-pub use card::{Card, HoleCards, Board, Deck, Street};
-pub use hand::Range;
+pub use card::{Card, CardSet, HoleCards, Board, Deck, Street};
+pub use hand::{Range, WeightedRange};
 pub use hand_eval::HandEvaluator;
-pub use abstraction::{CardAbstraction, AbstractionConfig, HandClass};
+pub use abstraction::{CardAbstraction, AbstractionConfig, AbstractionMode, HandClass};
 pub use action::PokerAction;
 pub use state::{PokerState, HUPosition};
-pub use betting::{BettingLogic, BettingConfig};
+pub use betting::{BettingLogic, BettingConfig, SizingPruning};
 pub use info_state::PokerInfoState;
-pub use game::{SBvsBBFullGame, SBvsBBConfig};
+pub use game::{SBvsBBFullGame, SBvsBBConfig, LineError};
 pub use config::*;
 pub use postflop_config::FullGameConfig;
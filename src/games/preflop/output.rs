@@ -4,14 +4,18 @@
 //! to various formats for analysis and visualization.
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs::File;
 use std::io::Write;
 use std::path::Path;
 
 use crate::cfr::CFRSolver;
+use crate::cfr::game::{Game, InfoState};
 use super::game::SBvsBBFullGame;
 use super::abstraction::HandClass;
+use super::action::PokerAction;
+use super::card::{Board, Card, HoleCards};
+use super::info_state::PokerInfoState;
 
 /// Strategy entry for a single info state.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -89,30 +93,7 @@ impl SolverOutput {
         solver: &CFRSolver<SBvsBBFullGame>,
         key: &str,
     ) -> Option<StrategyEntry> {
-        // Key format: P{position}S{street}B{bucket}|{history}
-        // Example: P0S1B523|R300-C|X-B132
-
-        if !key.starts_with('P') {
-            return None;
-        }
-
-        let parts: Vec<&str> = key.splitn(2, '|').collect();
-        if parts.is_empty() {
-            return None;
-        }
-
-        let header = parts[0];
-        let history = parts.get(1).map(|s| s.to_string()).unwrap_or_default();
-
-        // Parse position, street, bucket from header
-        let position = header.get(1..2)?.parse::<u8>().ok()?;
-
-        // Find S and B markers
-        let s_pos = header.find('S')?;
-        let b_pos = header.find('B')?;
-
-        let street = header.get(s_pos + 1..b_pos)?.parse::<u8>().ok()?;
-        let bucket = header.get(b_pos + 1..)?.parse::<u16>().ok()?;
+        let (position, street, bucket, history) = parse_info_key(key)?;
 
         // Get action names from solver (stored during training)
         let stored_actions = solver.get_action_names(key);
@@ -258,6 +239,98 @@ impl PreflopRangeOutput {
     }
 }
 
+/// Split an info-set key into position, street, bucket and action history.
+///
+/// Key format: `P{position}S{street}B{bucket}|{history}`, e.g.
+/// `P0S1B523|R300-C|X-B132`. Returns `None` for keys of any other shape.
+fn parse_info_key(key: &str) -> Option<(u8, u8, u16, String)> {
+    if !key.starts_with('P') {
+        return None;
+    }
+
+    let (header, history) = key.split_once('|').unwrap_or((key, ""));
+
+    // Parse position, street, bucket from header
+    let position = header.get(1..2)?.parse::<u8>().ok()?;
+
+    // Find S and B markers
+    let s_pos = header.find('S')?;
+    let b_pos = header.find('B')?;
+
+    let street = header.get(s_pos + 1..b_pos)?.parse::<u8>().ok()?;
+    let bucket = header.get(b_pos + 1..)?.parse::<u16>().ok()?;
+
+    Some((position, street, bucket, history.to_string()))
+}
+
+/// Version of the [`StrategyDump`] layout; bumped on any breaking change.
+pub const STRATEGY_DUMP_VERSION: u32 = 1;
+
+/// Average strategy of every discovered info set, for viewers and tools.
+///
+/// Unlike [`SolverOutput`], the layout is meant to stay stable: info sets
+/// are ordered by key, every strategy lists one probability per stored
+/// action (zeros included) and the header carries
+/// [`STRATEGY_DUMP_VERSION`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StrategyDump {
+    /// Metadata identifying the solve
+    pub header: StrategyDumpHeader,
+    /// Strategies keyed by info-set key
+    pub info_sets: BTreeMap<String, InfoSetStrategy>,
+}
+
+/// Header of a [`StrategyDump`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StrategyDumpHeader {
+    /// Layout version ([`STRATEGY_DUMP_VERSION`])
+    pub version: u32,
+    /// Iterations the solver had run
+    pub iterations: u64,
+    /// Number of info sets in the dump
+    pub num_info_sets: usize,
+    /// Hash of the game and solver configuration (16 hex digits)
+    pub config_hash: String,
+    /// Card abstraction the buckets come from
+    pub abstraction: String,
+}
+
+/// One info set's entry in a [`StrategyDump`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InfoSetStrategy {
+    /// Position (0=SB, 1=BB)
+    pub position: u8,
+    /// Street (0=Preflop, 1=Flop, 2=Turn, 3=River)
+    pub street: u8,
+    /// Hand bucket
+    pub bucket: u16,
+    /// Action names, as stored during training
+    pub action_names: Vec<String>,
+    /// Average strategy, one probability per action
+    pub average_strategy: Vec<f64>,
+}
+
+impl StrategyDump {
+    /// Serialize to pretty-printed JSON.
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Save to JSON file.
+    pub fn save_json<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        let json = self.to_json()?;
+        let mut file = File::create(path)?;
+        file.write_all(json.as_bytes())
+    }
+}
+
+/// 64-bit FNV-1a hash, stable across platforms and Rust versions.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
 /// Simple timestamp without external dependencies.
 fn chrono_lite_timestamp() -> String {
     use std::time::{SystemTime, UNIX_EPOCH};
@@ -301,6 +374,110 @@ pub fn export_csv<P: AsRef<Path>>(
     Ok(())
 }
 
+impl CFRSolver<SBvsBBFullGame> {
+    /// Dump the average strategy of every discovered info set.
+    ///
+    /// Action names come from [`RegretStorage::get_action_names`]; info sets
+    /// trained without them get `action_{i}` placeholders. Keys that don't
+    /// parse as [`PokerInfoState`] keys are skipped.
+    ///
+    /// [`RegretStorage::get_action_names`]: crate::cfr::RegretStorage::get_action_names
+    pub fn strategy_dump(&self) -> StrategyDump {
+        let mut info_sets = BTreeMap::new();
+        for key in self.info_set_keys() {
+            let Some((position, street, bucket, _)) = parse_info_key(&key) else {
+                continue;
+            };
+            let action_names = self.get_action_names(&key).unwrap_or_else(|| {
+                let num_actions = self.num_actions_for(&key).unwrap_or(0);
+                (0..num_actions).map(|i| format!("action_{}", i)).collect()
+            });
+            let average_strategy = self.get_average_strategy(&key, action_names.len());
+            info_sets.insert(
+                key,
+                InfoSetStrategy { position, street, bucket, action_names, average_strategy },
+            );
+        }
+
+        // The game config has no serde form; its Debug output covers every field
+        let settings = format!(
+            "{:?}|{}",
+            self.game().config(),
+            serde_json::to_string(self.config()).unwrap_or_default()
+        );
+        StrategyDump {
+            header: StrategyDumpHeader {
+                version: STRATEGY_DUMP_VERSION,
+                iterations: self.iteration(),
+                num_info_sets: info_sets.len(),
+                config_hash: format!("{:016x}", fnv1a(settings.as_bytes())),
+                abstraction: self.game().config().abstraction.fingerprint(),
+            },
+            info_sets,
+        }
+    }
+
+    /// Average strategy for holding `hole` on `board` after `history`.
+    ///
+    /// `history` uses the info-set key format: actions by short code
+    /// (`R250`, `C`, `X`, ...) joined by `-`, with `|` between streets, e.g.
+    /// `"R250-C|X"`. The line is replayed with
+    /// [`SBvsBBFullGame::state_from_line`] to find the acting player, the
+    /// legal actions and the key, so callers never build keys themselves.
+    /// Returns `None` if the line is malformed or illegal, doesn't end at a
+    /// decision with `board` fully dealt, or its info set was never reached
+    /// in training.
+    pub fn strategy_for(&self, hole: HoleCards, board: &Board, history: &str) -> Option<Vec<(PokerAction, f64)>> {
+        let actions = history
+            .split(['|', '-'])
+            .filter(|code| !code.is_empty())
+            .map(PokerAction::from_short_code)
+            .collect::<Option<Vec<_>>>()?;
+
+        // The other seat's cards don't affect the betting, so any free pair will do
+        let dead: Vec<Card> = hole.cards().into_iter().chain(board.cards().iter().copied()).collect();
+        let mut free = (0..52).map(Card::from_id).filter(|card| !dead.contains(card));
+        let filler = HoleCards::new(free.next()?, free.next()?);
+
+        let game = self.game();
+        let mut state = game.state_from_line(&actions, hole, filler, board).ok()?;
+        let to_act = state.to_act?;
+        if game.is_terminal(&state) || game.is_chance(&state) {
+            return None;
+        }
+        state.hands[to_act.index()] = Some(hole);
+        state.hands[to_act.opponent().index()] = Some(filler);
+
+        let key = game.info_state(&state).key();
+        if !self.storage().contains(&key) {
+            return None;
+        }
+        let legal = game.available_actions(&state);
+        let strategy = self.get_average_strategy(&key, legal.len());
+        Some(legal.into_iter().zip(strategy).collect())
+    }
+
+    /// Break a bucketed info set's strategy out to concrete combos.
+    ///
+    /// Every combo on `board` that the game's abstraction maps to
+    /// `info.bucket` is paired with the info set's average strategy, so the
+    /// solution can be shown hand by hand. `board` must match `info.street`.
+    pub fn expand_bucket_strategy(
+        &self,
+        info: &PokerInfoState,
+        board: &Board,
+        num_actions: usize,
+    ) -> Vec<(HoleCards, Vec<f64>)> {
+        let strategy = self.get_average_strategy(&info.key(), num_actions);
+        self.game()
+            .abstraction()
+            .combos_in_bucket(info.bucket, board)
+            .into_iter()
+            .map(|combo| (combo, strategy.clone()))
+            .collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -323,6 +500,68 @@ mod tests {
         assert_eq!(output.metadata.iterations, 100);
     }
 
+    #[test]
+    fn test_strategy_dump() {
+        let mut solver = CFRSolver::new(SBvsBBFullGame::fast(), CFRConfig::default().with_seed(42));
+        solver.train(50);
+
+        let dump = solver.strategy_dump();
+        assert_eq!(dump.header.version, STRATEGY_DUMP_VERSION);
+        assert_eq!(dump.header.iterations, 50);
+        assert_eq!(dump.header.num_info_sets, solver.num_info_sets());
+        assert_eq!(dump.info_sets.len(), solver.num_info_sets());
+
+        for (key, entry) in &dump.info_sets {
+            let (position, street, bucket, _) = parse_info_key(key).unwrap();
+            assert_eq!((entry.position, entry.street, entry.bucket), (position, street, bucket));
+            assert_eq!(Some(&entry.action_names), solver.get_action_names(key).as_ref());
+            assert_eq!(entry.average_strategy.len(), entry.action_names.len());
+            assert!((entry.average_strategy.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        }
+
+        // Stable: same solve, same bytes; the header tracks the configuration
+        let json = dump.to_json().unwrap();
+        assert_eq!(serde_json::from_str::<StrategyDump>(&json).unwrap(), dump);
+        let mut again = CFRSolver::new(SBvsBBFullGame::fast(), CFRConfig::default().with_seed(42));
+        again.train(50);
+        assert_eq!(again.strategy_dump().to_json().unwrap(), json);
+
+        let other = CFRSolver::new(SBvsBBFullGame::fast(), CFRConfig::default().with_seed(7));
+        assert_ne!(other.strategy_dump().header.config_hash, dump.header.config_hash);
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["header"]["config_hash"].as_str().unwrap().len(), 16);
+    }
+
+    #[test]
+    fn test_strategy_for() {
+        let mut solver = CFRSolver::new(SBvsBBFullGame::fast(), CFRConfig::default().with_seed(42));
+        let aks = HoleCards::from_str("AsKs").unwrap();
+        let preflop = Board::new();
+        assert_eq!(solver.strategy_for(aks, &preflop, ""), None, "untrained info set");
+        solver.train(200);
+
+        // Check a trained preflop key for each seat, using any hand in its bucket
+        for prefix in ["P0S0", "P1S0"] {
+            let key = solver.info_set_keys().into_iter().find(|key| key.starts_with(prefix)).unwrap();
+            let (_, _, bucket, history) = parse_info_key(&key).unwrap();
+            let hole = (0..52)
+                .flat_map(|a| (a + 1..52).map(move |b| HoleCards::new(Card::from_id(a), Card::from_id(b))))
+                .find(|hole| solver.game().abstraction().get_bucket(hole, &preflop) == bucket)
+                .unwrap();
+
+            let strategy = solver.strategy_for(hole, &preflop, &history).unwrap();
+            let names: Vec<String> = strategy.iter().map(|(action, _)| action.to_string()).collect();
+            assert_eq!(Some(names), solver.get_action_names(&key), "{}", key);
+            let probabilities: Vec<f64> = strategy.iter().map(|&(_, p)| p).collect();
+            assert_eq!(probabilities, solver.get_average_strategy(&key, strategy.len()), "{}", key);
+        }
+
+        // Malformed lines, a finished hand, and hole cards clashing with the board
+        assert_eq!(solver.strategy_for(aks, &preflop, "Z"), None);
+        assert_eq!(solver.strategy_for(aks, &preflop, "F"), None);
+        assert_eq!(solver.strategy_for(aks, &Board::from_str("As7c2d").unwrap(), ""), None);
+    }
+
     #[test]
     fn test_parse_info_key() {
         // Test key parsing
@@ -339,4 +578,33 @@ mod tests {
         assert!(header.contains("S1"));
         assert!(header.contains("B523"));
     }
+
+    #[test]
+    fn test_expand_bucket_strategy() {
+        use crate::games::preflop::card::Street;
+        use crate::games::preflop::state::HUPosition;
+
+        let mut solver = CFRSolver::new(SBvsBBFullGame::fast(), CFRConfig::default().with_seed(42));
+        solver.train(10);
+
+        let board = Board::new();
+        let abstraction = solver.game().abstraction();
+        let total: usize = (0..abstraction.num_buckets(Street::Preflop))
+            .map(|bucket| abstraction.combos_in_bucket(bucket, &board).len())
+            .sum();
+        assert_eq!(total, 1326);
+
+        let aks = HoleCards::from_str("AsKs").unwrap();
+        let info = PokerInfoState::new(HUPosition::SB, Street::Preflop, aks.hand_class_index() as u16, String::new());
+        let num_actions = solver.get_action_names(&info.key()).map_or(3, |names| names.len());
+        let expected = solver.get_average_strategy(&info.key(), num_actions);
+
+        let expanded = solver.expand_bucket_strategy(&info, &board, num_actions);
+        assert_eq!(expanded.len(), 4);
+        for (combo, strategy) in &expanded {
+            assert!(combo.is_suited());
+            assert_eq!(combo.hand_class_index(), aks.hand_class_index());
+            assert_eq!(strategy, &expected);
+        }
+    }
 }
@@ -4,6 +4,8 @@
 //! that can be loaded from JSON files, compatible with HRC-style settings.
 
 use serde::{Deserialize, Serialize};
+use super::config::RakeConfig;
+use super::betting::SizingPruning;
 use std::fs;
 use std::path::Path;
 
@@ -30,6 +32,9 @@ pub struct FullGameConfig {
     /// Solver settings
     #[serde(default)]
     pub solver: SolverSettings,
+    /// Rake structure (unraked if absent)
+    #[serde(default)]
+    pub rake: Option<RakeConfig>,
 }
 
 /// Blind structure configuration.
@@ -39,6 +44,13 @@ pub struct BlindsConfig {
     pub sb: f64,
     #[serde(default)]
     pub ante: f64,
+    /// Straddle type: "OFF" or "MANDATORY" (a 2bb straddle posted by SB)
+    #[serde(default = "default_straddle_type")]
+    pub straddle_type: String,
+}
+
+fn default_straddle_type() -> String {
+    "OFF".to_string()
 }
 
 impl Default for BlindsConfig {
@@ -47,10 +59,18 @@ impl Default for BlindsConfig {
             bb: 1.0,
             sb: 0.5,
             ante: 0.0,
+            straddle_type: default_straddle_type(),
         }
     }
 }
 
+impl BlindsConfig {
+    /// Straddle amount SB posts every hand, if the straddle is mandatory.
+    pub fn mandatory_straddle(&self) -> Option<f64> {
+        (self.straddle_type == "MANDATORY").then_some(2.0 * self.bb)
+    }
+}
+
 /// Preflop betting configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PreflopBettingConfig {
@@ -96,6 +116,13 @@ pub struct PostflopBettingConfig {
     /// Maximum number of bets per street (-1 for unlimited)
     #[serde(default = "default_max_bets")]
     pub max_bets_per_street: i32,
+    /// Offer every configured size at each node; otherwise only the first
+    /// OOP size is used, for bets and raises alike
+    #[serde(default)]
+    pub multi_size: bool,
+    /// SPR-aware pruning of redundant sizes (disabled if absent)
+    #[serde(default)]
+    pub sizing_pruning: Option<SizingPruning>,
 }
 
 fn default_bet_sizes() -> Vec<f64> {
@@ -123,6 +150,8 @@ impl Default for PostflopBettingConfig {
             add_allin_spr: default_allin_spr(),
             allow_donk: false,
             max_bets_per_street: default_max_bets(),
+            multi_size: false,
+            sizing_pruning: None,
         }
     }
 }
@@ -254,6 +283,20 @@ impl FullGameConfig {
             });
         }
 
+        if self.blinds.ante < 0.0 {
+            return Err(ConfigError::InvalidValue {
+                field: "blinds".to_string(),
+                message: "Ante must be non-negative".to_string(),
+            });
+        }
+
+        if !matches!(self.blinds.straddle_type.as_str(), "OFF" | "MANDATORY") {
+            return Err(ConfigError::InvalidValue {
+                field: "blinds".to_string(),
+                message: format!("Unsupported straddle type: {}", self.blinds.straddle_type),
+            });
+        }
+
         Ok(())
     }
 
@@ -262,10 +305,15 @@ impl FullGameConfig {
         use super::betting::{BettingConfig, PreflopOpenSizing, Preflop3BetSizing};
         use super::abstraction::AbstractionConfig;
 
+        // Without multi_size the betting logic falls back to geo_size alone
+        let sizes = |sizes: &Vec<f64>| if self.postflop.multi_size { sizes.clone() } else { Vec::new() };
+
         super::game::SBvsBBConfig {
             stack_bb: self.stack_bb,
             sb_amount: self.blinds.sb,
             bb_amount: self.blinds.bb,
+            ante: self.blinds.ante,
+            straddle: self.blinds.mandatory_straddle(),
             betting: BettingConfig {
                 geo_size: self.postflop.oop_bet_sizes.first().copied().unwrap_or(0.66),
                 add_allin_spr: self.postflop.add_allin_spr,
@@ -280,13 +328,19 @@ impl FullGameConfig {
                     oop_multiplier: 3.3,
                     bb_vs_sb_multiplier: self.preflop.bb_3bet_multiplier,
                 },
+                oop_bet_sizes: sizes(&self.postflop.oop_bet_sizes),
+                ip_bet_sizes: sizes(&self.postflop.ip_bet_sizes),
+                raise_sizes: sizes(&self.postflop.raise_sizes),
+                sizing_pruning: self.postflop.sizing_pruning.clone(),
             },
             abstraction: AbstractionConfig {
                 flop_buckets: self.abstraction.flop_buckets,
                 turn_buckets: self.abstraction.turn_buckets,
                 river_buckets: self.abstraction.river_buckets,
                 equity_samples: self.abstraction.equity_samples,
+                ..AbstractionConfig::default()
             },
+            rake: self.rake.clone(),
         }
     }
 
@@ -302,6 +356,7 @@ impl FullGameConfig {
             postflop: PostflopBettingConfig::default(),
             abstraction: AbstractionSettings::default(),
             solver: SolverSettings::default(),
+            rake: None,
         }
     }
 
@@ -325,6 +380,7 @@ impl FullGameConfig {
                 iterations: 1000,
                 ..Default::default()
             },
+            rake: None,
         }
     }
 }
@@ -420,6 +476,32 @@ mod tests {
         assert_eq!(game_config.stack_bb, 50.0);
         assert_eq!(game_config.sb_amount, 0.5);
         assert_eq!(game_config.bb_amount, 1.0);
+        assert_eq!(game_config.ante, 0.0);
+        assert_eq!(game_config.straddle, None);
+
+        let mut config = FullGameConfig::default_50bb();
+        config.blinds.ante = 0.12;
+        config.blinds.straddle_type = "MANDATORY".to_string();
+        let game_config = config.to_game_config();
+        assert_eq!(game_config.ante, 0.12);
+        assert_eq!(game_config.straddle, Some(2.0));
+
+        config.blinds.straddle_type = "VOLUNTARY".to_string();
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_multi_size_is_opt_in() {
+        let mut config = FullGameConfig::default_50bb();
+        config.postflop.oop_bet_sizes = vec![0.33, 0.75];
+        let betting = config.to_game_config().betting;
+        assert_eq!(betting.geo_size, 0.33);
+        assert!(betting.oop_bet_sizes.is_empty() && betting.ip_bet_sizes.is_empty() && betting.raise_sizes.is_empty());
+
+        config.postflop.multi_size = true;
+        let betting = config.to_game_config().betting;
+        assert_eq!(betting.oop_bet_sizes, vec![0.33, 0.75]);
+        assert_eq!(betting.raise_sizes, vec![0.66]);
     }
 
     #[test]
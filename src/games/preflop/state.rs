@@ -4,8 +4,9 @@
 //! hole cards, board cards, betting history, and pot/stack information.
 
 use super::card::{HoleCards, Board, Deck, Street};
-use super::action::PokerAction;
+use super::action::{round_to_centi, PokerAction};
 use crate::cfr::game::GameState;
+use rand::Rng;
 use std::fmt;
 
 /// Position in a heads-up poker game.
@@ -108,28 +109,54 @@ pub struct PokerState {
     pub folded: Option<HUPosition>,
     /// Whether someone is all-in
     pub all_in: [bool; 2],
+    /// Whether SB posted a mandatory straddle (BB then acts first preflop
+    /// and SB has the option)
+    pub straddled: bool,
 }
 
 impl PokerState {
     /// Create a new initial state for a heads-up hand.
     pub fn new_hu(starting_stacks: [f64; 2], sb_amount: f64, bb_amount: f64) -> Self {
+        Self::new_hu_with_antes(starting_stacks, sb_amount, bb_amount, 0.0, None)
+    }
+
+    /// Create a new initial state for a heads-up hand with antes and an
+    /// optional mandatory straddle.
+    ///
+    /// Both players post `ante` as dead money: it goes into the pot and
+    /// `invested_total` but not `invested_street`, so it never counts towards
+    /// a call. A `straddle` (usually 2bb) is posted by SB in place of the
+    /// small blind; BB then acts first preflop and SB closes the action with
+    /// the option.
+    pub fn new_hu_with_antes(
+        starting_stacks: [f64; 2],
+        sb_amount: f64,
+        bb_amount: f64,
+        ante: f64,
+        straddle: Option<f64>,
+    ) -> Self {
+        let sb_blind = straddle.unwrap_or(sb_amount);
+        let blinds = [sb_blind, bb_amount];
+        let invested_total = [round_to_centi(sb_blind + ante), round_to_centi(bb_amount + ante)];
+
         Self {
             hands: [None, None],
             board: Board::new(),
             deck: Deck::new(),
 
             street: Street::Preflop,
-            pot: sb_amount + bb_amount,
+            pot: invested_total[0] + invested_total[1],
             stacks: [
-                starting_stacks[0] - sb_amount,
-                starting_stacks[1] - bb_amount,
+                round_to_centi(starting_stacks[0] - invested_total[0]),
+                round_to_centi(starting_stacks[1] - invested_total[1]),
             ],
-            invested_street: [sb_amount, bb_amount],
-            invested_total: [sb_amount, bb_amount],
+            invested_street: blinds,
+            invested_total,
 
-            to_call: bb_amount - sb_amount, // SB needs to call 0.5bb more
-            min_raise: bb_amount, // Minimum raise is 1bb (raise by bb amount)
-            last_bet_size: bb_amount,
+            // Whoever acts first owes the difference between the blinds
+            to_call: (blinds[0] - blinds[1]).abs(),
+            min_raise: sb_blind.max(bb_amount), // Minimum raise is by the largest blind
+            last_bet_size: sb_blind.max(bb_amount),
 
             to_act: None, // Needs card dealing first
             num_bets_street: 1, // BB counts as first "bet"
@@ -141,16 +168,32 @@ impl PokerState {
             is_terminal: false,
             folded: None,
             all_in: [false, false],
+            straddled: straddle.is_some(),
         }
     }
 
     /// Create state after dealing hole cards.
     pub fn with_hands(mut self, sb_hand: HoleCards, bb_hand: HoleCards) -> Self {
         self.hands = [Some(sb_hand), Some(bb_hand)];
-        self.to_act = Some(HUPosition::SB); // SB acts first preflop
+        self.to_act = Some(self.first_to_act_preflop());
         self
     }
 
+    /// The player with the preflop option: the last forced bet, who may
+    /// still check or raise after being limped to. BB, or SB when straddled.
+    pub fn preflop_option(&self) -> HUPosition {
+        if self.straddled {
+            HUPosition::SB
+        } else {
+            HUPosition::BB
+        }
+    }
+
+    /// The player who acts first preflop (SB, or BB when SB straddled).
+    pub fn first_to_act_preflop(&self) -> HUPosition {
+        self.preflop_option().opponent()
+    }
+
     /// Get the hole cards for a player.
     pub fn hand(&self, pos: HUPosition) -> Option<&HoleCards> {
         self.hands[pos.index()].as_ref()
@@ -255,11 +298,7 @@ impl PokerState {
                 }
             }
             PokerAction::Call => {
-                let call_amount = self.to_call.min(self.stacks[idx]);
-                self.stacks[idx] -= call_amount;
-                self.pot += call_amount;
-                self.invested_street[idx] += call_amount;
-                self.invested_total[idx] += call_amount;
+                self.commit_chips(idx, self.to_call);
 
                 if self.stacks[idx] <= 0.0 {
                     self.all_in[idx] = true;
@@ -271,10 +310,11 @@ impl PokerState {
                 let street_complete = if self.both_all_in() {
                     true
                 } else if self.street == Street::Preflop {
-                    // Preflop special case: SB limping doesn't close the action
-                    // BB still has the option to check or raise
-                    // Street is complete when BB calls a raise, or after BB's option
-                    pos == HUPosition::BB || (pos == HUPosition::SB && self.num_bets_street > 1)
+                    // Preflop special case: limping doesn't close the action
+                    // The option player (BB, or a straddling SB) can still check or raise
+                    // Street is complete when the option player calls, or the opener calls a raise
+                    let option = self.preflop_option();
+                    pos == option || (pos == option.opponent() && self.num_bets_street > 1)
                 } else {
                     // Postflop: a call always closes the action on this round
                     true
@@ -288,18 +328,12 @@ impl PokerState {
                         self.advance_street();
                     }
                 } else {
-                    // SB limped preflop, BB gets to act
-                    self.to_act = Some(HUPosition::BB);
+                    // Opener limped preflop, the option player gets to act
+                    self.to_act = Some(self.preflop_option());
                 }
             }
             PokerAction::Bet(amount_centi) => {
-                let amount = amount_centi as f64 / 100.0;
-                let bet_amount = amount.min(self.stacks[idx]);
-
-                self.stacks[idx] -= bet_amount;
-                self.pot += bet_amount;
-                self.invested_street[idx] += bet_amount;
-                self.invested_total[idx] += bet_amount;
+                let bet_amount = self.commit_chips(idx, amount_centi as f64 / 100.0);
 
                 self.to_call = bet_amount;
                 self.min_raise = bet_amount * 2.0; // Min raise is 2x the bet
@@ -314,12 +348,7 @@ impl PokerState {
             }
             PokerAction::Raise(amount_centi) => {
                 let raise_to = amount_centi as f64 / 100.0;
-                let additional = (raise_to - self.invested_street[idx]).min(self.stacks[idx]);
-
-                self.stacks[idx] -= additional;
-                self.pot += additional;
-                self.invested_street[idx] += additional;
-                self.invested_total[idx] += additional;
+                self.commit_chips(idx, raise_to - self.invested_street[idx]);
 
                 let raise_size = self.invested_street[idx] - self.invested_street[pos.opponent().index()];
                 self.to_call = raise_size;
@@ -334,11 +363,7 @@ impl PokerState {
                 self.to_act = Some(pos.opponent());
             }
             PokerAction::AllIn => {
-                let allin_amount = self.stacks[idx];
-                self.stacks[idx] = 0.0;
-                self.pot += allin_amount;
-                self.invested_street[idx] += allin_amount;
-                self.invested_total[idx] += allin_amount;
+                let allin_amount = self.commit_chips(idx, self.stacks[idx]);
                 self.all_in[idx] = true;
 
                 // Determine if this is a bet or raise based on to_call
@@ -364,15 +389,87 @@ impl PokerState {
                 }
             }
         }
+
+        if cfg!(debug_assertions) {
+            self.assert_invariants();
+        }
+    }
+
+    /// Check the chip accounting of this state.
+    ///
+    /// Verifies that the pot equals the total invested (uncalled chips are
+    /// only returned at payoff time), that stacks and investments are
+    /// non-negative with each street's investment within the hand's, that
+    /// all-in players have no chips behind, and that the player to act owes
+    /// exactly the difference in street investments.
+    pub fn check_invariants(&self) -> Result<(), String> {
+        const EPS: f64 = 1e-6;
+
+        if self.stacks.iter().any(|&s| s < -EPS) {
+            return Err(format!("negative stack: {:?}", self.stacks));
+        }
+        let invested = self.invested_total[0] + self.invested_total[1];
+        if (self.pot - invested).abs() > EPS {
+            return Err(format!("pot {} != total invested {}", self.pot, invested));
+        }
+        for idx in 0..2 {
+            if self.invested_street[idx] < -EPS || self.invested_street[idx] > self.invested_total[idx] + EPS {
+                return Err(format!(
+                    "street investment {:?} outside hand investment {:?}",
+                    self.invested_street, self.invested_total
+                ));
+            }
+            if self.all_in[idx] && self.stacks[idx] > EPS {
+                return Err(format!("player {} is all-in with {} behind", idx, self.stacks[idx]));
+            }
+        }
+        if let Some(pos) = self.to_act {
+            let owed = (self.invested_street[pos.opponent().index()] - self.invested_street[pos.index()]).max(0.0);
+            if (self.to_call - owed).abs() > EPS {
+                return Err(format!("to_call {} but {:?} owes {}", self.to_call, pos, owed));
+            }
+        }
+        Ok(())
+    }
+
+    /// Panic with the state if [`PokerState::check_invariants`] fails.
+    ///
+    /// Runs after every action in debug builds, so betting bugs surface at
+    /// the action that caused them.
+    pub fn assert_invariants(&self) {
+        if let Err(violation) = self.check_invariants() {
+            panic!("betting invariant violated: {}\n{:?}", violation, self);
+        }
+    }
+
+    /// Move chips from a player's stack into the pot, returning the amount moved.
+    ///
+    /// The amount is snapped to the centi-BB grid and capped at the stack, and
+    /// anything within half a centi-BB of the stack puts the player all-in.
+    /// The pot is rebuilt from the invested totals rather than accumulated, so
+    /// long raise sequences can't drift away from `sum(invested_total)`.
+    fn commit_chips(&mut self, idx: usize, amount: f64) -> f64 {
+        let stack = self.stacks[idx].max(0.0);
+        let mut amount = round_to_centi(amount).clamp(0.0, stack);
+        if stack - amount < 0.005 {
+            amount = stack;
+        }
+
+        self.stacks[idx] = if amount == stack { 0.0 } else { round_to_centi(stack - amount) };
+        self.invested_street[idx] = round_to_centi(self.invested_street[idx] + amount);
+        self.invested_total[idx] = round_to_centi(self.invested_total[idx] + amount);
+        self.pot = self.invested_total[0] + self.invested_total[1];
+
+        amount
     }
 
     /// Check if the street is complete after a check.
     fn is_street_complete_after_check(&self, checker: HUPosition) -> bool {
         match self.street {
             Street::Preflop => {
-                // BB checking preflop ends the street if SB limped
-                // After SB limp (1 action), BB check (2 actions) ends the street
-                checker == HUPosition::BB && self.num_actions_street >= 2
+                // The option player checking preflop ends the street after a limp
+                // After the limp (1 action), the check (2 actions) ends the street
+                checker == self.preflop_option() && self.num_actions_street >= 2
             }
             _ => {
                 // Postflop: check-check ends the street (need 2 checks)
@@ -417,33 +514,42 @@ impl PokerState {
         }
     }
 
+    /// Deal both players' hole cards.
+    pub fn deal_hole_cards<R: Rng>(&mut self, rng: &mut R) {
+        let mut draw = || self.deck.deal_random(rng).expect("deck ran out of cards");
+        let sb_hand = HoleCards::new(draw(), draw());
+        let bb_hand = HoleCards::new(draw(), draw());
+        self.hands = [Some(sb_hand), Some(bb_hand)];
+    }
+
     /// Deal the flop.
-    pub fn deal_flop(&mut self) {
+    pub fn deal_flop<R: Rng>(&mut self, rng: &mut R) {
         debug_assert_eq!(self.street, Street::Flop);
         debug_assert_eq!(self.board.len(), 0);
 
-        let cards = self.deck.deal_n(3);
-        for card in cards {
-            self.board.add(card);
+        for _ in 0..3 {
+            if let Some(card) = self.deck.deal_random(rng) {
+                self.board.add(card);
+            }
         }
     }
 
     /// Deal the turn.
-    pub fn deal_turn(&mut self) {
+    pub fn deal_turn<R: Rng>(&mut self, rng: &mut R) {
         debug_assert_eq!(self.street, Street::Turn);
         debug_assert_eq!(self.board.len(), 3);
 
-        if let Some(card) = self.deck.deal() {
+        if let Some(card) = self.deck.deal_random(rng) {
             self.board.add(card);
         }
     }
 
     /// Deal the river.
-    pub fn deal_river(&mut self) {
+    pub fn deal_river<R: Rng>(&mut self, rng: &mut R) {
         debug_assert_eq!(self.street, Street::River);
         debug_assert_eq!(self.board.len(), 4);
 
-        if let Some(card) = self.deck.deal() {
+        if let Some(card) = self.deck.deal_random(rng) {
             self.board.add(card);
         }
     }
@@ -506,6 +612,45 @@ mod tests {
         assert!(!state.is_terminal);
     }
 
+    #[test]
+    fn test_antes_and_straddle() {
+        let sb_hand = HoleCards::from_str("2c3d").unwrap();
+        let bb_hand = HoleCards::from_str("AhKs").unwrap();
+
+        // Antes are dead money: in the pot but not owed on the street
+        let state = PokerState::new_hu_with_antes([50.0, 50.0], 0.5, 1.0, 0.12, None)
+            .with_hands(sb_hand, bb_hand);
+        assert!((state.pot - (0.5 + 1.0 + 2.0 * 0.12)).abs() < 1e-9);
+        assert_eq!(state.invested_total, [0.62, 1.12]);
+        assert_eq!(state.stacks, [49.38, 48.88]);
+        assert_eq!(state.to_call, 0.5);
+        assert_eq!(state.to_act, Some(HUPosition::SB));
+        assert_eq!(state.check_invariants(), Ok(()));
+
+        // A folded SB loses its blind and its ante
+        use crate::cfr::game::Game;
+        let game = crate::games::preflop::SBvsBBFullGame::new();
+        let folded = state.apply(PokerAction::Fold);
+        assert!((game.get_payoff(&folded, 0) + 0.62).abs() < 1e-9);
+        assert!((game.get_payoff(&folded, 1) - 0.62).abs() < 1e-9);
+
+        // A straddling SB acts last preflop and keeps the option
+        let state = PokerState::new_hu_with_antes([50.0, 50.0], 0.5, 1.0, 0.12, Some(2.0))
+            .with_hands(sb_hand, bb_hand);
+        assert!((state.pot - (2.0 + 1.0 + 2.0 * 0.12)).abs() < 1e-9);
+        assert_eq!(state.invested_street, [2.0, 1.0]);
+        assert_eq!(state.to_call, 1.0);
+        assert_eq!(state.to_act, Some(HUPosition::BB));
+        assert_eq!(state.check_invariants(), Ok(()));
+
+        let limped = state.apply(PokerAction::Call);
+        assert_eq!(limped.street, Street::Preflop);
+        assert_eq!(limped.to_act, Some(HUPosition::SB));
+        let flop = limped.apply(PokerAction::Check);
+        assert_eq!(flop.street, Street::Flop);
+        assert!((flop.pot - (4.0 + 2.0 * 0.12)).abs() < 1e-9);
+    }
+
     #[test]
     fn test_sb_fold() {
         let sb_hand = HoleCards::from_str("2c3d").unwrap();
@@ -623,4 +768,77 @@ mod tests {
         assert!(history.contains("R300"));
         assert!(history.contains("C"));
     }
+
+    #[test]
+    fn test_invariants_catch_corruption() {
+        let sb_hand = HoleCards::from_str("AsAd").unwrap();
+        let bb_hand = HoleCards::from_str("KhKs").unwrap();
+        let state = PokerState::new_hu([50.0, 50.0], 0.5, 1.0)
+            .with_hands(sb_hand, bb_hand)
+            .apply(PokerAction::Raise(250));
+        assert_eq!(state.check_invariants(), Ok(()));
+
+        let mut double_counted = state.clone();
+        double_counted.pot += 2.5;
+        assert!(double_counted.check_invariants().unwrap_err().contains("pot"));
+
+        let mut overdrawn = state.clone();
+        overdrawn.stacks[0] = -1.0;
+        assert!(overdrawn.check_invariants().unwrap_err().contains("negative stack"));
+
+        let mut wrong_call = state.clone();
+        wrong_call.to_call = 2.5;
+        assert!(wrong_call.check_invariants().unwrap_err().contains("to_call"));
+
+        let mut phantom_all_in = state;
+        phantom_all_in.all_in[1] = true;
+        assert!(phantom_all_in.check_invariants().unwrap_err().contains("all-in"));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "betting invariant violated")]
+    fn test_apply_checks_invariants() {
+        let sb_hand = HoleCards::from_str("AsAd").unwrap();
+        let bb_hand = HoleCards::from_str("KhKs").unwrap();
+        let mut state = PokerState::new_hu([50.0, 50.0], 0.5, 1.0).with_hands(sb_hand, bb_hand);
+        // SB calls too much, so BB faces a bet while to_call says otherwise
+        state.to_call = 2.0;
+        state.apply(PokerAction::Call);
+    }
+
+    #[test]
+    fn test_deep_stack_raises_no_centi_drift() {
+        let sb_hand = HoleCards::from_str("AsAd").unwrap();
+        let bb_hand = HoleCards::from_str("KhKs").unwrap();
+
+        let mut state = PokerState::new_hu([500.0, 500.0], 0.5, 1.0)
+            .with_hands(sb_hand, bb_hand);
+
+        let on_grid = |x: f64| round_to_centi(x) == x;
+        let check = |state: &PokerState| {
+            assert_eq!(state.pot, state.invested_total[0] + state.invested_total[1]);
+            for i in 0..2 {
+                assert!(on_grid(state.stacks[i]), "stack {} off grid", state.stacks[i]);
+                assert!(on_grid(state.invested_total[i]));
+                assert_eq!(round_to_centi(state.stacks[i] + state.invested_total[i]), 500.0);
+            }
+        };
+
+        // Awkward sizes that don't round-trip cleanly through f64
+        for raise_to in [233u32, 777, 2331, 6993, 20979, 62937] {
+            state = state.apply(PokerAction::Raise(raise_to));
+            check(&state);
+        }
+
+        // Over-sized raise is capped at the stack and puts the player all-in
+        state = state.apply(PokerAction::Raise(u32::MAX));
+        check(&state);
+        assert!(state.all_in.iter().any(|&a| a));
+
+        state = state.apply(PokerAction::Call);
+        check(&state);
+        assert!(state.is_terminal);
+        assert_eq!(state.pot, 1000.0);
+    }
 }
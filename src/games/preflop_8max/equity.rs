@@ -67,6 +67,36 @@ impl EquityCalculator {
         self.equity_matrix[our_class as usize][villain_class as usize]
     }
 
+    /// Get equity of hand class vs several specific opponents.
+    ///
+    /// Built from the heads-up matrix: each player's chance of finishing best
+    /// is taken as the product of their heads-up equities against everyone
+    /// else at the table, normalized over the whole field. Heads-up this is
+    /// exactly [`equity_vs_hand`](Self::equity_vs_hand), and the shares of
+    /// every player in the same pot sum to 1.
+    pub fn equity_vs_field(&self, hero_class: u8, opponent_classes: &[u8]) -> f64 {
+        if opponent_classes.is_empty() {
+            return 1.0;
+        }
+
+        let field: Vec<u8> = std::iter::once(hero_class).chain(opponent_classes.iter().copied()).collect();
+        let beats_field = |i: usize| -> f64 {
+            field
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .map(|(_, &other)| self.equity_vs_hand(field[i], other))
+                .product()
+        };
+
+        let total: f64 = (0..field.len()).map(beats_field).sum();
+        if total > 0.0 {
+            beats_field(0) / total
+        } else {
+            1.0 / field.len() as f64
+        }
+    }
+
     /// Get equity of hand class vs a range (weighted by combos).
     pub fn equity_vs_range(&self, our_class: u8, range_weights: &[f64; 169]) -> f64 {
         let our_hand = HandClass::from_index(our_class);
@@ -255,6 +285,33 @@ mod tests {
         assert!(kk_vs_aa < 0.5);
     }
 
+    #[test]
+    fn test_equity_vs_field() {
+        let calc = EquityCalculator::default();
+        let (aa, kk, ak_suited, three_two) = (12, 11, 90, 91);
+
+        // Heads-up it matches the matrix; alone hero takes the whole pot
+        assert_eq!(calc.equity_vs_field(aa, &[three_two]), calc.equity_vs_hand(aa, three_two));
+        assert_eq!(calc.equity_vs_field(aa, &[]), 1.0);
+
+        // Every player's share of the same pot adds up to the whole pot
+        let field = [aa, kk, ak_suited, three_two];
+        let total: f64 = (0..field.len())
+            .map(|i| {
+                let opponents: Vec<u8> = field.iter().enumerate().filter(|&(j, _)| j != i).map(|(_, &c)| c).collect();
+                calc.equity_vs_field(field[i], &opponents)
+            })
+            .sum();
+        assert!((total - 1.0).abs() < 1e-12, "shares sum to {}", total);
+
+        // AA's share shrinks with every opponent added
+        let opponents = [three_two, 30, 100, kk, 60, ak_suited, 5];
+        let shares: Vec<f64> = (1..=opponents.len()).map(|n| calc.equity_vs_field(aa, &opponents[..n])).collect();
+        for pair in shares.windows(2) {
+            assert!(pair[1] < pair[0], "AA equity rose with another opponent: {:?}", shares);
+        }
+    }
+
     #[test]
     fn test_precomputed_equity() {
         use precomputed::*;
@@ -9,7 +9,9 @@ use crate::cfr::game::{Game, InfoState as InfoStateTrait};
 use super::state::{PreflopState, Position8Max, BetLevel};
 use super::action::{PreflopAction, bb_to_centi, centi_to_bb};
 use super::equity::EquityCalculator;
-use crate::games::preflop::config::PreflopConfig;
+use crate::games::preflop::action::encode_history_bytes;
+use crate::games::preflop::card::{Deck, HoleCards};
+use crate::games::preflop::config::{BetSize, ConfigError, PreflopConfig, RakeConfig};
 
 /// Configuration for the 8-max preflop game.
 #[derive(Debug, Clone)]
@@ -23,17 +25,37 @@ pub struct Preflop8MaxConfig {
     /// Ante per player.
     pub ante: f64,
 
-    /// Open raise sizing (base + per_caller).
+    /// Open raise sizing (base + per_caller) for positions without a
+    /// dedicated size.
     pub open_size: (f64, f64),
+    /// Open raise sizing from BU.
+    pub open_size_bu: (f64, f64),
     /// Open raise sizing from SB.
     pub open_size_sb: (f64, f64),
-    /// 3-bet sizing (as multiplier of open + per_caller).
+    /// BB raise over limpers.
+    pub open_size_bb: (f64, f64),
+    /// BB raise when only SB limped.
+    pub open_size_bb_vs_sb: (f64, f64),
+
+    /// 3-bet sizing (multiplier of the open + per_caller) for IP 3-bettors.
     pub threebet_size_ip: (f64, f64),
-    pub threebet_size_oop: (f64, f64),
-    /// 4-bet sizing (as % of pot).
-    pub fourbet_pot_pct: f64,
-    /// 5-bet sizing (as % of pot).
-    pub fivebet_pot_pct: f64,
+    /// 3-bet sizing for BB against an SB open.
+    pub threebet_size_bb_vs_sb: (f64, f64),
+    /// 3-bet sizing for BB against any other open.
+    pub threebet_size_bb_vs_other: (f64, f64),
+    /// 3-bet sizing for SB against a BB raise.
+    pub threebet_size_sb_vs_bb: (f64, f64),
+    /// 3-bet sizing for SB against any other open.
+    pub threebet_size_sb_vs_other: (f64, f64),
+
+    /// 4-bet sizing in position (as % of pot).
+    pub fourbet_pot_pct_ip: f64,
+    /// 4-bet sizing out of position (as % of pot).
+    pub fourbet_pot_pct_oop: f64,
+    /// 5-bet sizing in position (as % of pot).
+    pub fivebet_pot_pct_ip: f64,
+    /// 5-bet sizing out of position (as % of pot).
+    pub fivebet_pot_pct_oop: f64,
 
     /// All-in threshold (% of stack).
     pub allin_threshold_pct: f64,
@@ -44,6 +66,9 @@ pub struct Preflop8MaxConfig {
     pub allowed_flats: [u8; 5],
     /// Allow cold calls (calling without previous involvement).
     pub allow_cold_calls: bool,
+
+    /// Rake taken from the pot (unraked if `None`).
+    pub rake: Option<RakeConfig>,
 }
 
 impl Default for Preflop8MaxConfig {
@@ -54,15 +79,24 @@ impl Default for Preflop8MaxConfig {
             bb_amount: 1.0,
             ante: 0.12,
             open_size: (2.3, 1.0),
+            open_size_bu: (2.3, 1.0),
             open_size_sb: (3.5, 1.0),
+            open_size_bb: (3.5, 1.0),
+            open_size_bb_vs_sb: (3.0, 0.0),
             threebet_size_ip: (2.5, 1.0),
-            threebet_size_oop: (3.3, 1.0),
-            fourbet_pot_pct: 0.90,
-            fivebet_pot_pct: 1.20,
+            threebet_size_bb_vs_sb: (2.5, 0.0),
+            threebet_size_bb_vs_other: (3.3, 1.0),
+            threebet_size_sb_vs_bb: (2.6, 1.0),
+            threebet_size_sb_vs_other: (3.3, 1.0),
+            fourbet_pot_pct_ip: 0.90,
+            fourbet_pot_pct_oop: 1.20,
+            fivebet_pot_pct_ip: 0.90,
+            fivebet_pot_pct_oop: 1.20,
             allin_threshold_pct: 0.40,
             allin_spr_threshold: 7.0,
             allowed_flats: [0, 1, 1, 1, 0],
             allow_cold_calls: false,
+            rake: None,
         }
     }
 }
@@ -71,24 +105,151 @@ impl Preflop8MaxConfig {
     /// Create config from JSON PreflopConfig.
     pub fn from_preflop_config(config: &PreflopConfig) -> Self {
         let stack = config.hand_data.stacks.values().next().copied().unwrap_or(50.0);
+        let sizing = &config.sizing;
+        let pair = |size: &BetSize| (size.base, size.per_caller);
 
         Self {
             stack_bb: stack,
             sb_amount: config.blinds.sb,
             bb_amount: config.blinds.bb,
             ante: config.blinds.ante,
-            open_size: (config.sizing.open.others.base, config.sizing.open.others.per_caller),
-            open_size_sb: (config.sizing.open.sb.base, config.sizing.open.sb.per_caller),
-            threebet_size_ip: (config.sizing.threebet.ip.base, config.sizing.threebet.ip.per_caller),
-            threebet_size_oop: (config.sizing.threebet.bb_vs_other.base, config.sizing.threebet.bb_vs_other.per_caller),
-            fourbet_pot_pct: config.sizing.fourbet.ip.percent_pot,
-            fivebet_pot_pct: config.sizing.fivebet.ip.percent_pot,
+            open_size: pair(&sizing.open.others),
+            open_size_bu: pair(&sizing.open.bu),
+            open_size_sb: pair(&sizing.open.sb),
+            open_size_bb: pair(&sizing.open.bb),
+            open_size_bb_vs_sb: pair(&sizing.open.bb_vs_sb),
+            threebet_size_ip: pair(&sizing.threebet.ip),
+            threebet_size_bb_vs_sb: pair(&sizing.threebet.bb_vs_sb),
+            threebet_size_bb_vs_other: pair(&sizing.threebet.bb_vs_other),
+            threebet_size_sb_vs_bb: pair(&sizing.threebet.sb_vs_bb),
+            threebet_size_sb_vs_other: pair(&sizing.threebet.sb_vs_other),
+            fourbet_pot_pct_ip: sizing.fourbet.ip.percent_pot,
+            fourbet_pot_pct_oop: sizing.fourbet.oop.percent_pot,
+            fivebet_pot_pct_ip: sizing.fivebet.ip.percent_pot,
+            fivebet_pot_pct_oop: sizing.fivebet.oop.percent_pot,
             allin_threshold_pct: config.action_restrictions.preflop_allin_threshold / 100.0,
             allin_spr_threshold: config.action_restrictions.preflop_add_allin_spr,
             allowed_flats: config.action_restrictions.allowed_flats_per_raise,
             allow_cold_calls: config.action_restrictions.allow_cold_calls,
+            rake: config.equity_model.rake_config(),
         }
     }
+
+    /// Check that the sizings describe a playable tree.
+    ///
+    /// Stack and blinds must be positive, every open must exceed the big
+    /// blind, 3-bets must be larger than the open they raise, 4-/5-bet pot
+    /// fractions must be positive, per-caller increments non-negative and no
+    /// level may allow more flats than there are other players.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.stack_bb <= 0.0 {
+            return Err(ConfigError::InvalidStack { position: "all".to_string(), stack: self.stack_bb });
+        }
+        if self.bb_amount <= 0.0 || self.sb_amount <= 0.0 || self.sb_amount > self.bb_amount {
+            return Err(ConfigError::InvalidBlinds { bb: self.bb_amount, sb: self.sb_amount });
+        }
+        if self.ante < 0.0 {
+            return Err(ConfigError::InvalidAnte(self.ante));
+        }
+
+        let opens = [
+            ("open_size", self.open_size),
+            ("open_size_bu", self.open_size_bu),
+            ("open_size_sb", self.open_size_sb),
+            ("open_size_bb", self.open_size_bb),
+            ("open_size_bb_vs_sb", self.open_size_bb_vs_sb),
+        ];
+        for (field, (base, per_caller)) in opens {
+            if base <= self.bb_amount {
+                return Err(ConfigError::OpenSizeTooSmall { field: field.to_string(), size: base, bb: self.bb_amount });
+            }
+            if per_caller < 0.0 {
+                return Err(ConfigError::NegativePerCaller { field: field.to_string(), per_caller });
+            }
+        }
+
+        let threebets = [
+            ("threebet_size_ip", self.threebet_size_ip),
+            ("threebet_size_bb_vs_sb", self.threebet_size_bb_vs_sb),
+            ("threebet_size_bb_vs_other", self.threebet_size_bb_vs_other),
+            ("threebet_size_sb_vs_bb", self.threebet_size_sb_vs_bb),
+            ("threebet_size_sb_vs_other", self.threebet_size_sb_vs_other),
+        ];
+        for (field, (multiplier, per_caller)) in threebets {
+            if multiplier <= 1.0 {
+                return Err(ConfigError::RaiseTooSmall { field: field.to_string(), multiplier });
+            }
+            if per_caller < 0.0 {
+                return Err(ConfigError::NegativePerCaller { field: field.to_string(), per_caller });
+            }
+        }
+
+        let pot_fractions = [
+            ("fourbet_pot_pct_ip", self.fourbet_pot_pct_ip),
+            ("fourbet_pot_pct_oop", self.fourbet_pot_pct_oop),
+            ("fivebet_pot_pct_ip", self.fivebet_pot_pct_ip),
+            ("fivebet_pot_pct_oop", self.fivebet_pot_pct_oop),
+        ];
+        for (field, percent) in pot_fractions {
+            if percent <= 0.0 {
+                return Err(ConfigError::InvalidPotPercent { field: field.to_string(), percent });
+            }
+        }
+
+        if !(0.0..=1.0).contains(&self.allin_threshold_pct) {
+            return Err(ConfigError::InvalidAllInThreshold {
+                field: "allin_threshold_pct".to_string(),
+                value: self.allin_threshold_pct,
+            });
+        }
+        if self.allin_spr_threshold < 0.0 {
+            return Err(ConfigError::InvalidAllInThreshold {
+                field: "allin_spr_threshold".to_string(),
+                value: self.allin_spr_threshold,
+            });
+        }
+
+        // Eight seats, so at most seven players can flat a raise
+        if let Some((level, &flats)) = self.allowed_flats.iter().enumerate().find(|(_, &f)| f > 7) {
+            return Err(ConfigError::InvalidFlatCount { level, flats });
+        }
+
+        Ok(())
+    }
+
+    /// Open (or raise-over-limpers) sizing for a position.
+    ///
+    /// `only_sb_limped` selects the BB-vs-SB size when BB raises a lone SB limp.
+    pub fn open_sizing(&self, pos: Position8Max, only_sb_limped: bool) -> (f64, f64) {
+        match pos {
+            Position8Max::BU => self.open_size_bu,
+            Position8Max::SB => self.open_size_sb,
+            Position8Max::BB if only_sb_limped => self.open_size_bb_vs_sb,
+            Position8Max::BB => self.open_size_bb,
+            _ => self.open_size,
+        }
+    }
+
+    /// 3-bet sizing by matchup, mirroring [`PreflopConfig::get_3bet_sizing`].
+    pub fn threebet_sizing(&self, three_bettor: Position8Max, raiser: Position8Max) -> (f64, f64) {
+        match (three_bettor, raiser) {
+            (Position8Max::BB, Position8Max::SB) => self.threebet_size_bb_vs_sb,
+            (Position8Max::BB, _) => self.threebet_size_bb_vs_other,
+            (Position8Max::SB, Position8Max::BB) => self.threebet_size_sb_vs_bb,
+            (Position8Max::SB, _) => self.threebet_size_sb_vs_other,
+            _ => self.threebet_size_ip,
+        }
+    }
+
+    /// 4-bet sizing (% of pot) by IP/OOP, mirroring [`PreflopConfig::get_4bet_sizing`].
+    pub fn fourbet_pot_pct(&self, is_ip: bool) -> f64 {
+        if is_ip { self.fourbet_pot_pct_ip } else { self.fourbet_pot_pct_oop }
+    }
+
+    /// 5-bet sizing (% of pot) by IP/OOP.
+    pub fn fivebet_pot_pct(&self, is_ip: bool) -> f64 {
+        if is_ip { self.fivebet_pot_pct_ip } else { self.fivebet_pot_pct_oop }
+    }
 }
 
 /// Information state for 8-max preflop.
@@ -106,6 +267,15 @@ impl InfoStateTrait for PreflopInfoState {
     fn key(&self) -> String {
         format!("P{}H{}:{}", self.position.index(), self.hand_class, self.history)
     }
+
+    fn key_bytes(&self) -> Vec<u8> {
+        // Layout: position, hand class, encoded history
+        let mut bytes = Vec::with_capacity(2 + self.history.len());
+        bytes.push(self.position.index() as u8);
+        bytes.push(self.hand_class);
+        encode_history_bytes(&self.history, &mut bytes);
+        bytes
+    }
 }
 
 /// 8-max preflop poker game.
@@ -125,7 +295,13 @@ impl Preflop8MaxGame {
     }
 
     /// Create a game with custom configuration.
+    ///
+    /// # Panics
+    /// Panics if [`Preflop8MaxConfig::validate`] rejects the configuration.
     pub fn with_config(config: Preflop8MaxConfig) -> Self {
+        if let Err(e) = config.validate() {
+            panic!("invalid 8-max config: {}", e);
+        }
         Self {
             config,
             equity_calc: EquityCalculator::default(),
@@ -149,10 +325,9 @@ impl Preflop8MaxGame {
         let idx = pos.index();
         let stack = state.stacks[idx];
         let invested = state.invested[idx];
-        let to_call = state.to_call - invested + state.invested[state.last_aggressor.map(|p| p.index()).unwrap_or(7)];
-        let to_call = (to_call).max(0.0);
+        let to_call = (state.to_call - invested).max(0.0);
 
-        // Always can fold if facing a bet
+        // Can fold only when facing a bet; the BB checks its option for free
         if to_call > 0.001 {
             actions.push(PreflopAction::Fold);
         }
@@ -196,41 +371,38 @@ impl Preflop8MaxGame {
 
         match state.bet_level {
             BetLevel::Unopened => {
-                // Open raise
-                let (base, per_caller) = if pos == Position8Max::SB {
-                    self.config.open_size_sb
-                } else {
-                    self.config.open_size
-                };
+                // Open raise (or raise over limpers)
+                let only_sb_limped = pos == Position8Max::BB
+                    && state.active_players() == 2
+                    && !state.folded[Position8Max::SB.index()];
+                let (base, per_caller) = self.config.open_sizing(pos, only_sb_limped);
                 let size = base + per_caller * state.num_callers as f64;
                 sizes.push(size);
             }
             BetLevel::FacingRaise => {
-                // 3-bet
-                let is_ip = if let Some(aggressor) = state.last_aggressor {
-                    pos.is_ip_vs(&aggressor)
-                } else {
-                    false
-                };
-                let (mult, per_caller) = if is_ip {
-                    self.config.threebet_size_ip
-                } else {
-                    self.config.threebet_size_oop
+                // 3-bet, sized by the 3-bettor/opener matchup
+                let (mult, per_caller) = match state.last_aggressor {
+                    Some(raiser) => self.config.threebet_sizing(pos, raiser),
+                    None => self.config.threebet_size_ip,
                 };
                 let open_size = state.to_call;
                 let size = open_size * mult + per_caller * state.num_callers as f64;
                 sizes.push(size);
             }
+            // 4-bets and 5-bets are pot-based, so chips from callers of the
+            // previous raise are already part of `state.pot` and scale the size.
             BetLevel::Facing3Bet => {
-                // 4-bet (pot-based)
+                // 4-bet (pot-based), IP/OOP relative to the 3-bettor
+                let is_ip = state.last_aggressor.is_some_and(|agg| pos.is_ip_postflop_vs(&agg));
                 let pot_after_call = state.pot + state.to_call;
-                let size = pot_after_call * self.config.fourbet_pot_pct;
+                let size = pot_after_call * self.config.fourbet_pot_pct(is_ip);
                 sizes.push(size.max(state.to_call * 2.2));
             }
             BetLevel::Facing4Bet => {
-                // 5-bet (pot-based)
+                // 5-bet (pot-based), IP/OOP relative to the 4-bettor
+                let is_ip = state.last_aggressor.is_some_and(|agg| pos.is_ip_postflop_vs(&agg));
                 let pot_after_call = state.pot + state.to_call;
-                let size = pot_after_call * self.config.fivebet_pot_pct;
+                let size = pot_after_call * self.config.fivebet_pot_pct(is_ip);
                 sizes.push(size.max(state.to_call * 2.2));
             }
             BetLevel::Facing5Bet | BetLevel::AllIn => {
@@ -311,12 +483,16 @@ impl Preflop8MaxGame {
                 new_state.stacks[idx] -= additional;
                 new_state.invested[idx] += additional;
                 new_state.pot += additional;
+                if new_state.stacks[idx] <= 0.001 {
+                    new_state.all_in[idx] = true;
+                }
 
-                // Update betting info
+                // Update betting info; a raise that commits the stacks skips
+                // the remaining levels
                 new_state.to_call = new_state.invested[idx];
                 new_state.last_raise_size = raise_to - state.to_call;
                 new_state.last_aggressor = Some(pos);
-                new_state.bet_level = new_state.bet_level.next();
+                new_state.bet_level = new_state.bet_level_after_raise(pos);
                 new_state.num_callers = 0;
 
                 // Reset has_acted for other players
@@ -339,8 +515,11 @@ impl Preflop8MaxGame {
                 new_state.pot += allin_amount;
                 new_state.all_in[idx] = true;
 
-                // Check if this is a raise
-                if new_state.invested[idx] > state.to_call {
+                // Check if this is a raise; an all-in for no more than the
+                // current bet is a call and counts as a live caller
+                if new_state.invested[idx] <= state.to_call {
+                    new_state.num_callers += 1;
+                } else {
                     new_state.to_call = new_state.invested[idx];
                     new_state.last_aggressor = Some(pos);
                     new_state.bet_level = BetLevel::AllIn;
@@ -370,6 +549,11 @@ impl Preflop8MaxGame {
         new_state
     }
 
+    /// Rake taken from a pot of `pot` BB under the configured rake, if any.
+    fn rake(&self, pot: f64, saw_flop: bool) -> f64 {
+        self.config.rake.as_ref().map_or(0.0, |rake| rake.rake(pot, saw_flop))
+    }
+
     /// Calculate payoff for a player at a terminal state.
     fn calculate_payoff(&self, state: &PreflopState, player: usize) -> f64 {
         debug_assert!(state.is_terminal);
@@ -385,46 +569,24 @@ impl Preflop8MaxGame {
             .collect();
 
         if active.len() == 1 {
-            // Everyone else folded, player wins pot
-            return state.pot - state.invested[player];
+            // Everyone else folded, player wins pot (preflop, so nfnd applies)
+            return state.pot - self.rake(state.pot, false) - state.invested[player];
         }
 
         // Multiple players remain - use equity for expected value
         // This is the "equity realization" approach
-        let player_class = state.hand_class.unwrap_or(84); // Default to middle strength
-
-        // Calculate average equity vs opponents
-        let mut total_equity = 0.0;
-        let mut num_opponents = 0;
-
-        for &opp in &active {
-            if opp != player {
-                // Assume average hand for opponent (simplified)
-                // In real implementation, this would use range vs range equity
-                let opp_class = 84; // Middle strength assumption
-                let equity = self.equity_calc.equity_vs_hand(player_class, opp_class);
-                total_equity += equity;
-                num_opponents += 1;
-            }
-        }
-
-        let avg_equity = if num_opponents > 0 {
-            total_equity / num_opponents as f64
-        } else {
-            0.5
-        };
-
-        // For multiway, equity is lower
-        let multiway_factor = if active.len() > 2 {
-            1.0 / (active.len() - 1) as f64
-        } else {
-            1.0
-        };
-
-        let effective_equity = avg_equity * multiway_factor;
+        let player_class = state.hand_class[player].unwrap_or(84); // Default to middle strength
+        let opponent_classes: Vec<u8> = active
+            .iter()
+            .filter(|&&opp| opp != player)
+            .map(|&opp| state.hand_class[opp].unwrap_or(84))
+            .collect();
+        let effective_equity = self.equity_calc.equity_vs_field(player_class, &opponent_classes);
 
-        // Expected value = equity * pot - invested
-        (effective_equity * state.pot) - state.invested[player]
+        // Expected value = equity * pot - invested, with the pot raked as
+        // if the hand reaches the flop
+        let raked_pot = state.pot - self.rake(state.pot, true);
+        (effective_equity * raked_pot) - state.invested[player]
     }
 }
 
@@ -472,10 +634,26 @@ impl Game for Preflop8MaxGame {
         }
     }
 
+    fn check_invariants(&self, state: &Self::State) -> Result<(), String> {
+        if state.stacks.iter().any(|&s| s < -1e-9) {
+            return Err(format!("negative stack: {:?}", state.stacks));
+        }
+        let invested: f64 = state.invested.iter().sum();
+        if (state.pot - invested).abs() > 1e-6 {
+            return Err(format!("pot {} != total invested {}", state.pot, invested));
+        }
+        Ok(())
+    }
+
     fn num_players(&self) -> usize {
         8
     }
 
+    /// Eight players, and rake leaves the payoffs summing below zero.
+    fn is_zero_sum(&self) -> bool {
+        false
+    }
+
     fn available_actions(&self, state: &Self::State) -> Vec<Self::Action> {
         if self.is_terminal(state) || self.is_chance(state) {
             return vec![];
@@ -489,7 +667,8 @@ impl Game for Preflop8MaxGame {
 
     fn info_state(&self, state: &Self::State) -> Self::InfoState {
         let pos = state.to_act.unwrap_or(Position8Max::UTG);
-        let hand_class = state.hand_class.unwrap_or(0);
+        // Key on the acting seat's own hand, never another seat's
+        let hand_class = state.hand_class[pos.index()].unwrap_or(0);
 
         PreflopInfoState {
             position: pos,
@@ -499,16 +678,21 @@ impl Game for Preflop8MaxGame {
     }
 
     fn is_chance(&self, state: &Self::State) -> bool {
-        // Need to deal cards if hand_class is not set
-        state.hand_class.is_none() && state.to_act.is_some()
+        // Need to deal cards until every seat has a hand class
+        state.hand_class.iter().any(Option::is_none) && state.to_act.is_some()
     }
 
     fn sample_chance<R: Rng>(&self, state: &Self::State, rng: &mut R) -> Self::State {
         let mut new_state = state.clone();
 
-        // Sample a hand class (0-168) weighted by number of combos
-        let hand_class = sample_hand_class(rng);
-        new_state.hand_class = Some(hand_class);
+        // Deal every seat real hole cards from one deck, so the classes at the
+        // table can all be held at once (no three seats holding AA)
+        let mut deck = Deck::new();
+        for seat in new_state.hand_class.iter_mut().filter(|c| c.is_none()) {
+            let mut draw = || deck.deal_random(rng).expect("deck ran out of cards");
+            let hole_cards = HoleCards::new(draw(), draw());
+            *seat = Some(hole_cards.hand_class_index());
+        }
 
         new_state
     }
@@ -520,25 +704,35 @@ impl Game for Preflop8MaxGame {
     fn state_description(&self, state: &Self::State) -> String {
         format!("{:?}", state)
     }
-}
 
-/// Sample a random hand class weighted by number of combos.
-fn sample_hand_class<R: Rng>(rng: &mut R) -> u8 {
-    use crate::games::preflop::abstraction::HandClass;
+    fn max_depth(&self) -> Option<usize> {
+        // Deal, then up to 8 actions at each of the 5 raise levels plus all-in
+        Some(100)
+    }
 
-    // Total combos: 1326
-    let roll: u32 = rng.gen_range(0..1326);
-    let mut cumsum = 0u32;
+    fn describe_info_state(&self, info: &Self::InfoState) -> String {
+        use crate::games::preflop::abstraction::HandClass;
 
-    for class_idx in 0..169u8 {
-        let hc = HandClass::from_index(class_idx);
-        cumsum += hc.num_combos() as u32;
-        if roll < cumsum {
-            return class_idx;
+        let hand = HandClass::from_index(info.hand_class).to_string();
+        if info.history.is_empty() {
+            return format!("{} {}, unopened", info.position.name(), hand);
         }
+        let history: Vec<String> = info.history.split('-').map(describe_action_code).collect();
+        format!("{} {}, after {}", info.position.name(), hand, history.join(", "))
     }
+}
 
-    168 // Fallback (shouldn't reach)
+/// Expand a [`PreflopAction::short_code`] back into readable text.
+fn describe_action_code(code: &str) -> String {
+    match code {
+        "F" => format!("{}", PreflopAction::Fold),
+        "C" => format!("{}", PreflopAction::Call),
+        "A" => format!("{}", PreflopAction::AllIn),
+        _ => match code.strip_prefix('R').and_then(|amt| amt.parse().ok()) {
+            Some(amt) => format!("{}", PreflopAction::Raise(amt)),
+            None => code.to_string(),
+        },
+    }
 }
 
 #[cfg(test)]
@@ -553,6 +747,7 @@ mod tests {
         assert!(!game.is_terminal(&state));
         assert!(game.is_chance(&state)); // Need to deal cards
         assert_eq!(state.to_act, Some(Position8Max::UTG));
+        assert!(!game.is_zero_sum());
     }
 
     #[test]
@@ -564,7 +759,7 @@ mod tests {
         let state = game.sample_chance(&state, &mut rng);
 
         assert!(!game.is_chance(&state));
-        assert!(state.hand_class.is_some());
+        assert!(state.hand_class.iter().all(Option::is_some));
 
         let actions = game.available_actions(&state);
         assert!(!actions.is_empty());
@@ -637,4 +832,371 @@ mod tests {
         let utg_payoff = game.get_payoff(&state, Position8Max::UTG.index());
         assert!(utg_payoff < 0.0, "UTG should lose ante when folding");
     }
+
+    #[test]
+    fn test_walk_to_bb() {
+        let game = Preflop8MaxGame::new();
+        let config = Preflop8MaxConfig::default();
+
+        // Folds around to the BB with no raise: the BB wins without acting
+        let state = play(&game, &["F"; 7]);
+        assert!(game.is_terminal(&state));
+        assert_eq!(state.to_act, None);
+
+        let bb_payoff = game.get_payoff(&state, Position8Max::BB.index());
+        let dead_money = config.sb_amount + 8.0 * config.ante - config.ante;
+        assert!((bb_payoff - dead_money).abs() < 1e-9, "BB won {}, expected {}", bb_payoff, dead_money);
+        let sb_payoff = game.get_payoff(&state, Position8Max::SB.index());
+        assert!((sb_payoff + config.sb_amount + config.ante).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_limp_gives_bb_option() {
+        let game = Preflop8MaxGame::new();
+        let config = Preflop8MaxConfig::default();
+
+        // UTG limps, the rest fold: the BB still gets to check or raise
+        let state = play(&game, &["C", "F", "F", "F", "F", "F", "F"]);
+        assert!(!game.is_terminal(&state));
+        assert_eq!(state.to_act, Some(Position8Max::BB));
+        let utg = Position8Max::UTG.index();
+        let bb = Position8Max::BB.index();
+        assert!((state.invested[utg] - state.invested[bb]).abs() < 1e-9, "the limp matches the big blind");
+
+        let actions = game.available_actions(&state);
+        assert!(!actions.contains(&PreflopAction::Fold), "BB should not fold an unraised pot");
+        assert!(actions.contains(&PreflopAction::Call));
+        assert!(actions.iter().any(|a| matches!(a, PreflopAction::Raise(_))));
+
+        // Checking closes the action
+        let checked = game.apply_action(&state, &PreflopAction::Call);
+        assert!(game.is_terminal(&checked));
+        assert!((checked.pot - (2.0 * config.bb_amount + config.sb_amount + 8.0 * config.ante)).abs() < 1e-9);
+
+        // A completed small blind gives the BB the same option
+        let state = play(&game, &["F", "F", "F", "F", "F", "F", "C"]);
+        assert_eq!(state.to_act, Some(Position8Max::BB));
+        assert!(!game.available_actions(&state).contains(&PreflopAction::Fold));
+    }
+
+    /// Apply a sequence of actions (Fold/Call/raise-to-first-size) from a dealt state.
+    fn play(game: &Preflop8MaxGame, actions: &[&str]) -> PreflopState {
+        let mut state = game.initial_state();
+        state.hand_class = [Some(0); 8];
+        for code in actions {
+            let action = match *code {
+                "F" => PreflopAction::Fold,
+                "C" => PreflopAction::Call,
+                _ => game
+                    .available_actions(&state)
+                    .into_iter()
+                    .find(|a| matches!(a, PreflopAction::Raise(_)))
+                    .expect("no raise available"),
+            };
+            state = game.apply_action(&state, &action);
+        }
+        state
+    }
+
+    fn first_raise(game: &Preflop8MaxGame, state: &PreflopState) -> u32 {
+        game.available_actions(state)
+            .into_iter()
+            .find_map(|a| match a {
+                PreflopAction::Raise(size) => Some(size),
+                _ => None,
+            })
+            .expect("no raise available")
+    }
+
+    #[test]
+    fn test_config_validation() {
+        assert!(Preflop8MaxConfig::default().validate().is_ok());
+
+        let json = std::fs::read_to_string("configs/preflop_8max_50bb.json").unwrap();
+        let preflop = PreflopConfig::from_json_str(&json).unwrap();
+        assert!(Preflop8MaxConfig::from_preflop_config(&preflop).validate().is_ok());
+
+        let small_open = Preflop8MaxConfig { open_size: (0.8, 1.0), ..Default::default() };
+        assert!(matches!(
+            small_open.validate(),
+            Err(ConfigError::OpenSizeTooSmall { ref field, .. }) if field == "open_size"
+        ));
+
+        let negative_caller = Preflop8MaxConfig { threebet_size_ip: (2.5, -1.0), ..Default::default() };
+        assert!(matches!(
+            negative_caller.validate(),
+            Err(ConfigError::NegativePerCaller { ref field, .. }) if field == "threebet_size_ip"
+        ));
+
+        let shrinking_3bet = Preflop8MaxConfig { threebet_size_bb_vs_sb: (0.9, 0.0), ..Default::default() };
+        assert!(matches!(shrinking_3bet.validate(), Err(ConfigError::RaiseTooSmall { .. })));
+
+        let too_many_flats = Preflop8MaxConfig { allowed_flats: [0, 8, 1, 1, 0], ..Default::default() };
+        assert!(matches!(
+            too_many_flats.validate(),
+            Err(ConfigError::InvalidFlatCount { level: 1, flats: 8 })
+        ));
+
+        let no_stack = Preflop8MaxConfig { stack_bb: 0.0, ..Default::default() };
+        assert!(matches!(no_stack.validate(), Err(ConfigError::InvalidStack { .. })));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid 8-max config")]
+    fn test_with_config_rejects_invalid() {
+        Preflop8MaxGame::with_config(Preflop8MaxConfig { open_size_bu: (0.5, 0.0), ..Default::default() });
+    }
+
+    #[test]
+    fn test_from_preflop_config_keeps_all_sizings() {
+        let json = std::fs::read_to_string("configs/preflop_8max_50bb.json").unwrap();
+        let preflop = PreflopConfig::from_json_str(&json).unwrap();
+        let config = Preflop8MaxConfig::from_preflop_config(&preflop);
+
+        assert_eq!(config.open_size_bb_vs_sb, (3.0, 0.0));
+        assert_eq!(config.threebet_size_bb_vs_sb, (2.5, 0.0));
+        assert_eq!(config.threebet_size_sb_vs_bb, (2.6, 1.0));
+        assert_eq!(config.threebet_size_sb_vs_other, (3.3, 1.0));
+        assert_eq!(config.fourbet_pot_pct_oop, 1.20);
+        assert_eq!(config.fivebet_pot_pct_oop, 1.20);
+    }
+
+    #[test]
+    fn test_bb_vs_sb_3bet_uses_configured_size() {
+        let config = Preflop8MaxConfig {
+            threebet_size_bb_vs_sb: (2.0, 0.0),
+            threebet_size_bb_vs_other: (4.0, 0.0),
+            threebet_size_ip: (3.0, 0.0),
+            ..Default::default()
+        };
+        let game = Preflop8MaxGame::with_config(config);
+
+        // Folds to SB, SB opens, BB to act
+        let state = play(&game, &["F", "F", "F", "F", "F", "F", "R"]);
+        assert_eq!(state.to_act, Some(Position8Max::BB));
+        assert_eq!(first_raise(&game, &state), bb_to_centi(state.to_call * 2.0));
+    }
+
+    #[test]
+    fn test_oop_4bet_uses_configured_size() {
+        let config = Preflop8MaxConfig {
+            fourbet_pot_pct_ip: 0.5,
+            fourbet_pot_pct_oop: 1.5,
+            ..Default::default()
+        };
+        let game = Preflop8MaxGame::with_config(config);
+
+        // CO opens, BU 3-bets, blinds fold, CO faces the 3-bet out of position
+        let state = play(&game, &["F", "F", "F", "F", "R", "R", "F", "F"]);
+        assert_eq!(state.to_act, Some(Position8Max::CO));
+        assert_eq!(state.bet_level, BetLevel::Facing3Bet);
+
+        let expected = ((state.pot + state.to_call) * 1.5).max(state.to_call * 2.2);
+        assert_eq!(first_raise(&game, &state), bb_to_centi(expected));
+    }
+
+    #[test]
+    fn test_sizing_scales_with_callers() {
+        let game = Preflop8MaxGame::new();
+        let config = Preflop8MaxConfig::default();
+
+        // Open: a limper adds per_caller to the open size
+        let no_limp = play(&game, &["F"]);
+        let one_limp = play(&game, &["C"]);
+        assert_eq!(one_limp.num_callers, 1);
+        assert_eq!(
+            first_raise(&game, &one_limp),
+            first_raise(&game, &no_limp) + bb_to_centi(config.open_size.1)
+        );
+
+        // 3-bet: a cold-caller of the open adds per_caller
+        let no_caller = play(&game, &["R", "F"]);
+        let one_caller = play(&game, &["R", "C"]);
+        assert_eq!(one_caller.num_callers, 1);
+        assert_eq!(
+            first_raise(&game, &one_caller),
+            first_raise(&game, &no_caller) + bb_to_centi(config.threebet_size_ip.1)
+        );
+
+        // 4-bet: pot-based, so a caller of the 3-bet grows the size
+        let no_caller = play(&game, &["R", "R", "F", "F", "F", "F", "F", "F"]);
+        let one_caller = play(&game, &["R", "R", "C", "F", "F", "F", "F", "F"]);
+        assert_eq!(no_caller.to_act, Some(Position8Max::UTG));
+        assert_eq!(one_caller.to_act, Some(Position8Max::UTG));
+        assert!(first_raise(&game, &one_caller) > first_raise(&game, &no_caller));
+
+        // A raise resets the caller count for the new level
+        let reraised = play(&game, &["R", "C", "R"]);
+        assert_eq!(reraised.num_callers, 0);
+    }
+
+    #[test]
+    fn test_short_allin_call_counts_as_caller() {
+        let game = Preflop8MaxGame::new();
+        let mut state = play(&game, &["R"]);
+
+        // EP is short and can only call all-in
+        state.stacks[Position8Max::EP.index()] = 1.0;
+        let state = game.apply_action(&state, &PreflopAction::AllIn);
+
+        assert_eq!(state.bet_level, BetLevel::FacingRaise);
+        assert_eq!(state.last_aggressor, Some(Position8Max::UTG));
+        assert_eq!(state.num_callers, 1);
+    }
+
+    #[test]
+    fn test_random_playout_invariants() {
+        crate::cfr::assert_game_invariants(&Preflop8MaxGame::new(), 1_000);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn test_unraked_payoffs_sum_to_zero() {
+        // Multiway showdowns split the whole pot between the active players
+        if let Err(violation) = crate::cfr::check_zero_sum(&Preflop8MaxGame::new(), 1_000) {
+            panic!("{}", violation);
+        }
+    }
+
+    #[test]
+    fn test_describe_info_state() {
+        use crate::games::preflop::abstraction::HandClass;
+
+        let game = Preflop8MaxGame::new();
+        let aks = HandClass { rank1: 12, rank2: 11, suited: true };
+        let info = PreflopInfoState {
+            position: Position8Max::BU,
+            hand_class: aks.index(),
+            history: "R230-F-F-F-F".to_string(),
+        };
+
+        let description = game.describe_info_state(&info);
+        assert_eq!(description, "BU AKs, after Raise to 2.30bb, Fold, Fold, Fold, Fold");
+        assert!(!description.contains(&info.key()));
+
+        let info = PreflopInfoState { history: String::new(), ..info };
+        assert_eq!(game.describe_info_state(&info), "BU AKs, unopened");
+    }
+
+    #[test]
+    fn test_short_stack_raise_jumps_to_allin() {
+        let config = Preflop8MaxConfig { stack_bb: 8.0, ..Default::default() };
+        let game = Preflop8MaxGame::with_config(config);
+
+        // A limp is not a raise and leaves the pot unopened
+        let limped = play(&game, &["C"]);
+        assert_eq!(limped.bet_level, BetLevel::Unopened);
+
+        // UTG raises its whole stack: no 3bet is possible behind it
+        let mut state = game.initial_state();
+        state.hand_class = [Some(0); 8];
+        let shove = state.invested[0] + state.stacks[0];
+        let state = game.apply_action(&state, &PreflopAction::Raise(bb_to_centi(shove)));
+        assert!(state.all_in[Position8Max::UTG.index()]);
+        assert_eq!(state.bet_level, BetLevel::AllIn);
+        assert!(!game
+            .available_actions(&state)
+            .iter()
+            .any(|a| matches!(a, PreflopAction::Raise(_))));
+
+        // A normal open at the same depth still advances one level
+        let opened = play(&game, &["R"]);
+        assert_eq!(opened.bet_level, BetLevel::FacingRaise);
+    }
+
+    /// Whether some set of distinct concrete combos realizes every class.
+    fn can_hold_all(classes: &[u8], used: &mut Vec<crate::games::preflop::card::Card>) -> bool {
+        use crate::games::preflop::abstraction::HandClass;
+
+        let Some((&class, rest)) = classes.split_first() else {
+            return true;
+        };
+        HandClass::from_index(class).enumerate_combos().into_iter().any(|combo| {
+            if used.iter().any(|&c| combo.contains(c)) {
+                return false;
+            }
+            used.extend(combo.cards());
+            let held = can_hold_all(rest, used);
+            used.truncate(used.len() - 2);
+            held
+        })
+    }
+
+    #[test]
+    fn test_dealt_classes_can_be_held_together() {
+        use rand::SeedableRng;
+
+        // Three aces-pairs need six aces
+        assert!(!can_hold_all(&[12, 12, 12], &mut Vec::new()));
+        assert!(can_hold_all(&[12, 12, 11], &mut Vec::new()));
+
+        let game = Preflop8MaxGame::new();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(5);
+        for _ in 0..500 {
+            let dealt = game.sample_chance(&game.initial_state(), &mut rng);
+            let classes: Vec<u8> = dealt.hand_class.iter().flatten().copied().collect();
+            assert_eq!(classes.len(), 8);
+            assert!(can_hold_all(&classes, &mut Vec::new()), "impossible deal {:?}", classes);
+        }
+    }
+
+    #[test]
+    fn test_info_state_uses_acting_seats_hand() {
+        use rand::SeedableRng;
+
+        let game = Preflop8MaxGame::new();
+
+        // Dealing gives every seat its own class, not one shared class
+        let mut rng = rand::rngs::StdRng::seed_from_u64(3);
+        let dealt = game.sample_chance(&game.initial_state(), &mut rng);
+        let classes: std::collections::HashSet<u8> = dealt.hand_class.iter().flatten().copied().collect();
+        assert!(classes.len() > 1, "all seats dealt {:?}", dealt.hand_class);
+
+        let mut state = game.initial_state();
+        state.hand_class = [Some(168); 8];
+        state.hand_class[Position8Max::UTG.index()] = Some(12);
+        state.hand_class[Position8Max::EP.index()] = Some(0);
+        assert!(!game.is_chance(&state));
+
+        let utg = game.info_state(&state);
+        assert_eq!((utg.position, utg.hand_class), (Position8Max::UTG, 12));
+
+        let state = game.apply_action(&state, &PreflopAction::Fold);
+        let ep = game.info_state(&state);
+        assert_eq!((ep.position, ep.hand_class), (Position8Max::EP, 0));
+    }
+
+    #[test]
+    fn test_key_bytes_compact_and_unique() {
+        use std::collections::HashMap;
+
+        fn collect(game: &Preflop8MaxGame, state: &PreflopState, depth: usize, out: &mut Vec<PreflopInfoState>) {
+            if depth == 0 || game.is_terminal(state) {
+                return;
+            }
+            out.push(game.info_state(state));
+            for action in game.available_actions(state) {
+                collect(game, &game.apply_action(state, &action), depth - 1, out);
+            }
+        }
+
+        let game = Preflop8MaxGame::new();
+        let mut infos = Vec::new();
+        for class in [0, 12, 168] {
+            let mut state = game.initial_state();
+            state.hand_class = [Some(class); 8];
+            collect(&game, &state, 5, &mut infos);
+        }
+
+        // Same string key <=> same byte key
+        let mut by_key: HashMap<String, Vec<u8>> = HashMap::new();
+        let mut by_bytes: HashMap<Vec<u8>, String> = HashMap::new();
+        for info in &infos {
+            let (key, bytes) = (info.key(), info.key_bytes());
+            assert!(bytes.len() < key.len(), "{}", key);
+            assert_eq!(by_key.entry(key.clone()).or_insert_with(|| bytes.clone()), &bytes);
+            assert_eq!(by_bytes.entry(bytes).or_insert_with(|| key.clone()), &key);
+        }
+        assert!(by_key.len() > 100);
+    }
 }
@@ -69,6 +69,20 @@ impl Position8Max {
         self.index() > other.index()
     }
 
+    /// Check if this position acts after another on postflop streets.
+    ///
+    /// Unlike [`is_ip_vs`](Self::is_ip_vs), this accounts for the blinds
+    /// acting first postflop: SB is out of position against everyone and BB
+    /// is only in position against SB.
+    pub fn is_ip_postflop_vs(&self, other: &Position8Max) -> bool {
+        self.postflop_order() > other.postflop_order()
+    }
+
+    /// Postflop acting order (SB = 0, BB = 1, UTG = 2, ..., BU = 7).
+    fn postflop_order(&self) -> usize {
+        (self.index() + 2) % 8
+    }
+
     /// Check if this is a blind position.
     pub fn is_blind(&self) -> bool {
         matches!(self, Position8Max::SB | Position8Max::BB)
@@ -143,7 +157,7 @@ pub struct PreflopState {
 
     /// Current pot size.
     pub pot: f64,
-    /// Amount to call.
+    /// Total investment (antes included) each player must match to stay in.
     pub to_call: f64,
     /// Last raise size (for min-raise calculation).
     pub last_raise_size: f64,
@@ -163,8 +177,8 @@ pub struct PreflopState {
     /// Action history string for info state.
     pub action_history: String,
 
-    /// Hand class for the acting player (0-168).
-    pub hand_class: Option<u8>,
+    /// Hand class (0-168) dealt to each seat, indexed by position.
+    pub hand_class: [Option<u8>; 8],
 
     /// SB amount.
     pub sb_amount: f64,
@@ -207,7 +221,7 @@ impl PreflopState {
             all_in: [false; 8],
             has_acted: [false; 8],
             pot,
-            to_call: bb_amount,
+            to_call: bb_amount + ante,
             last_raise_size: bb_amount,
             bet_level: BetLevel::Unopened,
             last_aggressor: None,
@@ -215,7 +229,7 @@ impl PreflopState {
             to_act: Some(Position8Max::UTG),
             is_terminal: false,
             action_history: String::new(),
-            hand_class: None,
+            hand_class: [None; 8],
             sb_amount,
             bb_amount,
             ante,
@@ -267,8 +281,8 @@ impl PreflopState {
         // Then wrap around to earlier positions (for BB option, etc.)
         for i in 0..current.index() {
             if !self.folded[i] && !self.all_in[i] {
-                // Only if they haven't acted yet this round
-                if !self.has_acted[i] || (self.to_call > self.invested[i] - self.invested[current.index()].max(0.0)) {
+                // Only if they haven't acted yet this round or still owe chips
+                if !self.has_acted[i] || self.invested[i] + 0.001 < self.to_call {
                     return Position8Max::from_index(i);
                 }
             }
@@ -306,6 +320,27 @@ impl PreflopState {
         true
     }
 
+    /// Betting level after `raiser` has put a raise in.
+    ///
+    /// Normally one level up, but a raise that leaves the raiser all-in, or
+    /// that no remaining opponent has the chips to re-raise over, ends the
+    /// raising and jumps straight to `AllIn`. Call after the chips are moved.
+    pub fn bet_level_after_raise(&self, raiser: Position8Max) -> BetLevel {
+        let idx = raiser.index();
+        let can_reraise = (0..8).any(|i| {
+            i != idx
+                && !self.folded[i]
+                && !self.all_in[i]
+                && self.invested[i] + self.stacks[i] > self.invested[idx] + 0.001
+        });
+
+        if self.all_in[idx] || !can_reraise {
+            BetLevel::AllIn
+        } else {
+            self.bet_level.next()
+        }
+    }
+
     /// Get SPR (stack to pot ratio) for effective stack.
     pub fn spr(&self) -> f64 {
         self.effective_stack() / self.pot
@@ -374,6 +409,10 @@ mod tests {
         assert!(Position8Max::BB.is_ip_vs(&Position8Max::SB));
         assert!(Position8Max::BU.is_ip_vs(&Position8Max::CO));
         assert!(!Position8Max::UTG.is_ip_vs(&Position8Max::BB));
+        assert!(Position8Max::BB.is_ip_postflop_vs(&Position8Max::SB));
+        assert!(Position8Max::UTG.is_ip_postflop_vs(&Position8Max::BB));
+        assert!(!Position8Max::BB.is_ip_postflop_vs(&Position8Max::BU));
+        assert!(!Position8Max::SB.is_ip_postflop_vs(&Position8Max::CO));
     }
 
     #[test]
@@ -6,9 +6,22 @@ use std::collections::HashMap;
 use rand::Rng;
 
 use crate::cfr::game::{Game, GameState, Action, InfoState};
+use crate::games::preflop::abstraction::{sample_hand_class_weighted, HandClass, HandClassIter};
+use crate::games::preflop::config::PreflopConfig;
+use crate::games::preflop::hand::Range;
 use super::state::{PreflopRangeState, Position, Scenario, ActionType};
 use super::{HAND_NAMES, hand_class_to_grid, grid_to_hand_name};
 
+/// Who posts the ante.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AnteType {
+    /// Every player at the table posts `ante`
+    #[default]
+    Regular,
+    /// The big blind alone posts `ante` for the whole table
+    BigBlind,
+}
+
 /// Configuration for preflop range solving
 #[derive(Debug, Clone)]
 pub struct PreflopRangeConfig {
@@ -16,6 +29,10 @@ pub struct PreflopRangeConfig {
     pub sb: f64,
     pub bb: f64,
     pub ante: f64,
+    /// Who posts `ante`
+    pub ante_type: AnteType,
+    /// Players dealt in, each posting `ante` under [`AnteType::Regular`]
+    pub num_players: usize,
     pub open_size: f64,      // e.g., 2.3bb
     pub threebet_size: f64,  // e.g., 3x open
     pub fourbet_size: f64,   // e.g., 2.5x 3bet
@@ -28,6 +45,8 @@ impl Default for PreflopRangeConfig {
             sb: 0.5,
             bb: 1.0,
             ante: 0.12,
+            ante_type: AnteType::Regular,
+            num_players: 8,
             open_size: 2.3,
             threebet_size: 3.0,
             fourbet_size: 2.5,
@@ -35,6 +54,30 @@ impl Default for PreflopRangeConfig {
     }
 }
 
+impl PreflopRangeConfig {
+    /// Total antes in the pot before any action.
+    pub fn total_antes(&self) -> f64 {
+        match self.ante_type {
+            AnteType::Regular => self.ante * self.num_players as f64,
+            AnteType::BigBlind => self.ante,
+        }
+    }
+
+    /// Calculate starting pot (blinds + antes).
+    pub fn starting_pot(&self) -> f64 {
+        self.sb + self.bb + self.total_antes()
+    }
+
+    /// Pot facing the BB after `limper` completes to 1bb.
+    ///
+    /// An SB limper tops up its posted blind; any other limper adds a full
+    /// big blind and leaves the SB dead.
+    pub fn limped_pot(&self, limper: Position) -> f64 {
+        let added = if limper == Position::SB { self.bb - self.sb } else { self.bb };
+        self.starting_pot() + added
+    }
+}
+
 /// Preflop range action
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct RangeAction(pub ActionType);
@@ -66,52 +109,63 @@ pub struct PreflopRangeGame {
     pub scenario: Scenario,
     /// Equity lookup table: hand_class -> equity vs villain range
     equity_table: [f64; 169],
+    /// Hand classes dealt by the chance node; `None` deals all 169
+    hand_filter: Option<Range>,
+    /// Whether the hero may raise (open, 3bet, 4bet...) in this scenario
+    allow_raise: bool,
 }
 
 impl PreflopRangeGame {
     pub fn new(scenario: Scenario, config: PreflopRangeConfig) -> Self {
         let equity_table = compute_equity_table(&scenario);
-        Self { config, scenario, equity_table }
+        Self { config, scenario, equity_table, hand_filter: None, allow_raise: true }
+    }
+
+    /// Drop the raise from the hero's actions, e.g. to solve a defence
+    /// without 3-bets.
+    pub fn without_raise(mut self) -> Self {
+        self.allow_raise = false;
+        self
+    }
+
+    /// Only deal hand classes in `range`, still weighted by combo count.
+    ///
+    /// Hands outside the range are never reached, so they get no info set
+    /// and the solve spends all its iterations on the rest.
+    ///
+    /// # Panics
+    /// If `range` is empty.
+    pub fn with_hand_filter(mut self, range: Range) -> Self {
+        assert!(range.num_classes() > 0, "hand filter must contain at least one hand class");
+        self.hand_filter = Some(range);
+        self
+    }
+
+    /// Whether the chance node deals `hand_class`.
+    pub fn deals(&self, hand_class: u8) -> bool {
+        self.hand_filter.as_ref().is_none_or(|range| range.contains_class(hand_class))
+    }
+
+    /// Actions available to the hero in this scenario, in strategy order.
+    pub fn actions(&self) -> Vec<ActionType> {
+        self.get_actions().into_iter().map(|a| a.0).collect()
     }
 
     /// Get available actions for this scenario
     fn get_actions(&self) -> Vec<RangeAction> {
-        match &self.scenario {
-            Scenario::RFI { .. } => vec![
-                RangeAction(ActionType::Fold),
-                RangeAction(ActionType::Raise),
-            ],
-            Scenario::VsRFI { .. } => vec![
-                RangeAction(ActionType::Fold),
-                RangeAction(ActionType::Call),
-                RangeAction(ActionType::Raise), // 3bet
-            ],
-            Scenario::Vs3Bet { .. } => vec![
-                RangeAction(ActionType::Fold),
-                RangeAction(ActionType::Call),
-                RangeAction(ActionType::Raise), // 4bet
-            ],
-            Scenario::Vs4Bet { .. } => vec![
-                RangeAction(ActionType::Fold),
-                RangeAction(ActionType::Call),
-                RangeAction(ActionType::AllIn),
-            ],
-            Scenario::Vs5Bet { .. } => vec![
-                RangeAction(ActionType::Fold),
-                RangeAction(ActionType::Call),
-            ],
-            _ => vec![
-                RangeAction(ActionType::Fold),
-                RangeAction(ActionType::Raise),
-            ],
-        }
+        self.scenario
+            .actions()
+            .iter()
+            .filter(|&&a| self.allow_raise || a != ActionType::Raise)
+            .map(|&a| RangeAction(a))
+            .collect()
     }
 
     /// Calculate EV for an action
     /// Uses position-based equity vs villain's calling range (not vs random)
     fn calculate_ev(&self, state: &PreflopRangeState, action: ActionType) -> f64 {
         let raw_equity = self.equity_table[state.hand_class as usize];
-        let pot = self.config.sb + self.config.bb + self.config.ante * 8.0;
+        let pot = self.config.starting_pot();
         let open_size = self.config.open_size;
 
         match (&self.scenario, action) {
@@ -126,6 +180,7 @@ impl PreflopRangeGame {
             (Scenario::RFI { position }, ActionType::Raise) => {
                 // Position-based parameters calibrated to match HRC ranges
                 // HRC typical RFI: UTG 13%, EP 15%, MP 18%, HJ 22%, CO 28%, BU 45%, SB 35%
+                // (checked by test_rfi_widths_match_hrc; BU and SB are currently off)
                 let (fold_equity, three_bet_freq, eq_realization, equity_penalty, min_equity) = match position {
                     Position::UTG => (0.75, 0.12, 0.65, 0.22, 0.68), // ~13% range - very tight
                     Position::EP  => (0.72, 0.10, 0.68, 0.20, 0.66), // ~15% range
@@ -272,7 +327,34 @@ impl PreflopRangeGame {
 
                 fold_equity * win_pot + (1.0 - fold_equity) * called_ev
             }
+            (Scenario::BBvsLimp { limper }, ActionType::Check) => {
+                let limped_pot = self.config.limped_pot(*limper);
+                let eq_realization = if *limper == Position::SB { 0.80 } else { 0.65 };
+
+                eq_realization * raw_equity * limped_pot - self.config.bb
+            }
+            (Scenario::BBvsLimp { limper }, ActionType::Raise) => {
+                // Iso-raise to an open plus 1bb for the limper
+                let iso_size = open_size + self.config.bb;
+                let limped_pot = self.config.limped_pot(*limper);
+
+                // Only iso with hands well ahead of a limping range
+                if raw_equity < 0.62 {
+                    return -iso_size;
+                }
+
+                let fold_equity = 0.35;
+                let eq_realization = if *limper == Position::SB { 0.85 } else { 0.75 };
+
+                // Limping ranges are capped, so the equity hit when called is small
+                let called_equity = (raw_equity - 0.05).max(0.30);
+                let called_pot = limped_pot + 2.0 * (iso_size - self.config.bb);
+                let called_ev = eq_realization * called_equity * called_pot - iso_size;
+
+                fold_equity * (limped_pot - self.config.bb) + (1.0 - fold_equity) * called_ev
+            }
             (_, ActionType::Fold) => 0.0,
+            (_, ActionType::Check) => -self.config.bb,
             (_, ActionType::Call) => {
                 let pot_after = pot * 2.0;
                 0.70 * raw_equity * pot_after - pot / 4.0
@@ -292,6 +374,8 @@ impl Clone for PreflopRangeGame {
             config: self.config.clone(),
             scenario: self.scenario.clone(),
             equity_table: self.equity_table,
+            hand_filter: self.hand_filter.clone(),
+            allow_raise: self.allow_raise,
         }
     }
 }
@@ -303,7 +387,7 @@ impl Game for PreflopRangeGame {
 
     fn initial_state(&self) -> Self::State {
         // Hand class will be set by chance node
-        PreflopRangeState::new(self.scenario.clone(), 0)
+        PreflopRangeState::undealt(self.scenario.clone())
     }
 
     fn is_terminal(&self, state: &Self::State) -> bool {
@@ -322,7 +406,7 @@ impl Game for PreflopRangeGame {
     }
 
     fn current_player(&self, state: &Self::State) -> Option<usize> {
-        if state.decided || state.hand_class == 0 && !state.decided {
+        if state.decided || !state.dealt {
             None // Terminal or chance
         } else {
             Some(0)
@@ -333,6 +417,21 @@ impl Game for PreflopRangeGame {
         2
     }
 
+    fn hand_class_label(&self, info_key: &str) -> Option<String> {
+        let (scenario, class) = info_key.rsplit_once('|')?;
+        if scenario != self.scenario.name() {
+            return None;
+        }
+        let class: u8 = class.parse().ok().filter(|&c| c < 169)?;
+        Some(HandClass::from_index(class).to_string())
+    }
+
+    /// Player 1 never acts: payoffs are player 0's EV against a fixed
+    /// opponent model, so averaging in player 1's zero gain would halve it.
+    fn is_zero_sum(&self) -> bool {
+        false
+    }
+
     fn available_actions(&self, state: &Self::State) -> Vec<Self::Action> {
         if state.decided {
             vec![]
@@ -353,19 +452,52 @@ impl Game for PreflopRangeGame {
     }
 
     fn is_chance(&self, state: &Self::State) -> bool {
-        !state.decided && state.hand_class == 0
+        !state.dealt
     }
 
     fn sample_chance<R: Rng>(&self, state: &Self::State, rng: &mut R) -> Self::State {
         // Sample hand class weighted by combos
-        let hand_class = sample_hand_class_weighted(rng);
+        let hand_class = match &self.hand_filter {
+            None => sample_hand_class_weighted(rng, &[]),
+            Some(range) => {
+                let mut roll = rng.gen_range(0..range.num_combos());
+                let mut dealt = None;
+                for hc in range.iter_classes() {
+                    let combos = hc.num_combos() as usize;
+                    if roll < combos {
+                        dealt = Some(hc.index());
+                        break;
+                    }
+                    roll -= combos;
+                }
+                dealt.expect("roll is below the range's combo count")
+            }
+        };
         PreflopRangeState::new(self.scenario.clone(), hand_class)
     }
 
+    fn chance_outcomes(&self, _state: &Self::State) -> Vec<(Self::State, f64)> {
+        // Each dealt hand class, weighted by its share of the dealt combos
+        let total = self.hand_filter.as_ref().map_or(1326, Range::num_combos) as f64;
+        HandClassIter::new()
+            .enumerate()
+            .filter(|&(class_idx, _)| self.deals(class_idx as u8))
+            .map(|(class_idx, hc)| {
+                let state = PreflopRangeState::new(self.scenario.clone(), class_idx as u8);
+                (state, hc.num_combos() as f64 / total)
+            })
+            .collect()
+    }
+
     fn action_name(&self, action: &Self::Action) -> String {
         action.0.name().to_string()
     }
 
+    fn estimated_info_sets(&self) -> Option<usize> {
+        // One decision per dealt hand class in a single scenario
+        Some(self.hand_filter.as_ref().map_or(169, Range::num_classes))
+    }
+
     fn state_description(&self, state: &Self::State) -> String {
         let (row, col) = hand_class_to_grid(state.hand_class);
         let hand = grid_to_hand_name(row, col);
@@ -386,7 +518,7 @@ fn compute_equity_table(_scenario: &Scenario) -> [f64; 169] {
 
 /// Compute playability score for a hand class
 /// Higher = more profitable to open. Based on HRC ranges.
-fn compute_playability(class_idx: u8) -> f64 {
+pub(crate) fn compute_playability(class_idx: u8) -> f64 {
     let (rank1, rank2, suited) = decode_hand_class_for_playability(class_idx);
 
     // rank1 >= rank2, where A=12, K=11, Q=10, J=9, T=8, 9=7, ..., 2=0
@@ -488,53 +620,170 @@ fn decode_triangular_ranks(idx: u8) -> (u8, u8) {
     (r1, remaining)
 }
 
-/// Sample hand class weighted by number of combos
-fn sample_hand_class_weighted<R: Rng>(rng: &mut R) -> u8 {
-    // Combos: pairs=6, suited=4, offsuit=12
-    let roll: u32 = rng.gen_range(0..1326);
-
-    // Pairs: 13 * 6 = 78 combos (indices 0-12)
-    if roll < 78 {
-        return (roll / 6) as u8;
-    }
-
-    // Suited: 78 * 4 = 312 combos (indices 13-90)
-    let roll = roll - 78;
-    if roll < 312 {
-        return 13 + (roll / 4) as u8;
-    }
+/// Solve a scenario and return strategies for all 169 hands
+pub fn solve_scenario(
+    scenario: Scenario,
+    config: &PreflopRangeConfig,
+    iterations: u64,
+) -> HashMap<u8, Vec<f64>> {
+    solve_scenario_with_ev(scenario, config, iterations)
+        .into_iter()
+        .map(|(hand_class, (strategy, _))| (hand_class, strategy))
+        .collect()
+}
 
-    // Offsuit: 78 * 12 = 936 combos (indices 91-168)
-    let roll = roll - 312;
-    91 + (roll / 12) as u8
+/// Solve a scenario and return `(frequencies, action EVs)` for all 169 hands.
+///
+/// The EVs are the per-action values the solver trains against at each
+/// hand-class info set (in bb, same order as the frequencies). A mixed hand
+/// whose action EVs are close is genuinely indifferent; a mixed hand with a
+/// clear EV gap is under-solved.
+pub fn solve_scenario_with_ev(
+    scenario: Scenario,
+    config: &PreflopRangeConfig,
+    iterations: u64,
+) -> HashMap<u8, (Vec<f64>, Vec<f64>)> {
+    solve_scenario_inner(PreflopRangeGame::new(scenario, config.clone()), iterations, None)
 }
 
-/// Solve a scenario and return strategies for all 169 hands
-pub fn solve_scenario(
+/// [`solve_scenario`] restricted to the hand classes in `hands`.
+///
+/// Only hands in the range are dealt (see
+/// [`PreflopRangeGame::with_hand_filter`]), and only they appear in the
+/// result, e.g. `Range::from_notation("22+,A2s+,KTs+")` to iterate on the
+/// pairs and strong suited hands.
+pub fn solve_scenario_filtered(
     scenario: Scenario,
     config: &PreflopRangeConfig,
     iterations: u64,
+    hands: &Range,
 ) -> HashMap<u8, Vec<f64>> {
+    let game = PreflopRangeGame::new(scenario, config.clone()).with_hand_filter(hands.clone());
+    solve_scenario_inner(game, iterations, None)
+        .into_iter()
+        .map(|(hand_class, (strategy, _))| (hand_class, strategy))
+        .collect()
+}
+
+fn solve_scenario_inner(
+    game: PreflopRangeGame,
+    iterations: u64,
+    seed: Option<u64>,
+) -> HashMap<u8, (Vec<f64>, Vec<f64>)> {
     use crate::cfr::{CFRConfig, CFRSolver};
 
-    let game = PreflopRangeGame::new(scenario, config.clone());
-    let cfr_config = CFRConfig::default()
+    let mut cfr_config = CFRConfig::default()
         .with_cfr_plus(true)
         .with_linear_cfr(true);
+    if let Some(seed) = seed {
+        cfr_config = cfr_config.with_seed(seed);
+    }
 
     let mut solver = CFRSolver::new(game.clone(), cfr_config);
     solver.train(iterations);
 
-    // Extract strategies for each hand class
-    let mut strategies = HashMap::new();
+    // Extract strategies and action values for each hand class
+    let mut results = HashMap::new();
     let actions = game.get_actions();
     let num_actions = actions.len();
 
-    for hand_class in 0..169u8 {
+    for hand_class in (0..169u8).filter(|&hc| game.deals(hc)) {
         let key = format!("{}|{}", game.scenario.name(), hand_class);
         let strategy = solver.get_average_strategy(&key, num_actions);
-        strategies.insert(hand_class, strategy);
+
+        let state = PreflopRangeState::new(game.scenario.clone(), hand_class);
+        let action_evs = actions
+            .iter()
+            .map(|action| game.get_payoff(&game.apply_action(&state, action), 0))
+            .collect();
+
+        results.insert(hand_class, (strategy, action_evs));
+    }
+
+    results
+}
+
+/// Scenarios covering every spot in `spots.spots_to_solve()`, each paired
+/// with whether the hero may raise in it.
+///
+/// Each opener contributes its RFI scenario once, and each `(rfi, defender)`
+/// spot contributes the defender's vs-RFI scenario. The spot's flags shape
+/// the rest: with `include_3bet` the defender may 3-bet and the opener's
+/// vs-3bet scenario is added, and with `include_4bet_plus` the opener may
+/// 4-bet there. Spots naming a position this solver doesn't model are
+/// skipped.
+pub fn scenarios_for_spots(spots: &PreflopConfig) -> Vec<(Scenario, bool)> {
+    let mut scenarios = Vec::new();
+    for (rfi, defender) in spots.spots_to_solve() {
+        let (Some(villain), Some(hero)) = (Position::from_name(&rfi), Position::from_name(&defender)) else {
+            continue;
+        };
+        let spot = spots.scenarios.as_ref().and_then(|filter| filter.get_spot(&rfi, &defender));
+        let include_3bet = spot.is_none_or(|spot| spot.include_3bet);
+        let include_4bet_plus = spot.is_none_or(|spot| spot.include_4bet_plus);
+
+        let open = (Scenario::RFI { position: villain }, true);
+        if !scenarios.contains(&open) {
+            scenarios.push(open);
+        }
+        scenarios.push((Scenario::VsRFI { hero, villain }, include_3bet));
+        if include_3bet {
+            scenarios.push((Scenario::Vs3Bet { hero: villain, villain: hero }, include_4bet_plus));
+        }
     }
+    scenarios
+}
+
+/// Solve every scenario from [`scenarios_for_spots`] in parallel, keyed by scenario name.
+///
+/// Each solve is seeded from its scenario name, so the result does not depend
+/// on thread scheduling and matches [`solve_all_spots`] exactly.
+pub fn solve_all_spots_parallel(
+    spots: &PreflopConfig,
+    config: &PreflopRangeConfig,
+    iterations: u64,
+) -> HashMap<String, HashMap<u8, Vec<f64>>> {
+    use rayon::prelude::*;
+
+    scenarios_for_spots(spots)
+        .into_par_iter()
+        .map(|(scenario, allow_raise)| solve_seeded(scenario, allow_raise, config, iterations))
+        .collect()
+}
+
+/// Serial counterpart of [`solve_all_spots_parallel`].
+pub fn solve_all_spots(
+    spots: &PreflopConfig,
+    config: &PreflopRangeConfig,
+    iterations: u64,
+) -> HashMap<String, HashMap<u8, Vec<f64>>> {
+    scenarios_for_spots(spots)
+        .into_iter()
+        .map(|(scenario, allow_raise)| solve_seeded(scenario, allow_raise, config, iterations))
+        .collect()
+}
+
+fn solve_seeded(
+    scenario: Scenario,
+    allow_raise: bool,
+    config: &PreflopRangeConfig,
+    iterations: u64,
+) -> (String, HashMap<u8, Vec<f64>>) {
+    let name = scenario.name();
+    let mut game = PreflopRangeGame::new(scenario, config.clone());
+    if !allow_raise {
+        game = game.without_raise();
+    }
+    let strategies = solve_scenario_inner(game, iterations, Some(scenario_seed(&name)))
+        .into_iter()
+        .map(|(hand_class, (strategy, _))| (hand_class, strategy))
+        .collect();
+    (name, strategies)
+}
 
-    strategies
+/// Stable per-scenario seed (FNV-1a of the scenario name).
+fn scenario_seed(name: &str) -> u64 {
+    name.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
 }
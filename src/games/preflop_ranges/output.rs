@@ -3,8 +3,10 @@
 use std::collections::HashMap;
 use std::fs::File;
 use std::io::Write;
+use std::path::Path;
 use serde::{Serialize, Deserialize};
 
+use super::game::{PreflopRangeConfig, PreflopRangeGame, solve_scenario};
 use super::state::{Scenario, ActionType};
 use super::{HAND_NAMES, hand_class_to_grid, grid_to_hand_name};
 
@@ -13,6 +15,9 @@ use super::{HAND_NAMES, hand_class_to_grid, grid_to_hand_name};
 pub struct HandStrategy {
     pub hand: String,
     pub fold: f64,
+    /// Check frequency (only for scenarios with a free check)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub check: Option<f64>,
     pub call: f64,
     pub raise: f64,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -28,6 +33,9 @@ pub struct ScenarioRange {
     pub hands: HashMap<String, HandStrategy>,
     /// 13x13 grid for easy visualization
     pub grid: Vec<Vec<HandStrategy>>,
+    /// Actions the scenario offers, in strategy order
+    #[serde(default)]
+    pub actions: Vec<ActionType>,
 }
 
 impl ScenarioRange {
@@ -36,6 +44,7 @@ impl ScenarioRange {
         let mut grid = vec![vec![HandStrategy {
             hand: String::new(),
             fold: 0.0,
+            check: None,
             call: 0.0,
             raise: 0.0,
             allin: None,
@@ -43,6 +52,7 @@ impl ScenarioRange {
 
         // Map action indices
         let fold_idx = actions.iter().position(|a| *a == ActionType::Fold);
+        let check_idx = actions.iter().position(|a| *a == ActionType::Check);
         let call_idx = actions.iter().position(|a| *a == ActionType::Call);
         let raise_idx = actions.iter().position(|a| *a == ActionType::Raise);
         let allin_idx = actions.iter().position(|a| *a == ActionType::AllIn);
@@ -54,6 +64,7 @@ impl ScenarioRange {
             let strat = strategies.get(&hand_class).cloned().unwrap_or_else(|| vec![0.0; actions.len()]);
 
             let fold = fold_idx.map(|i| strat.get(i).copied().unwrap_or(0.0)).unwrap_or(0.0);
+            let check = check_idx.map(|i| strat.get(i).copied().unwrap_or(0.0));
             let call = call_idx.map(|i| strat.get(i).copied().unwrap_or(0.0)).unwrap_or(0.0);
             let raise = raise_idx.map(|i| strat.get(i).copied().unwrap_or(0.0)).unwrap_or(0.0);
             let allin = allin_idx.map(|i| strat.get(i).copied().unwrap_or(0.0));
@@ -61,6 +72,7 @@ impl ScenarioRange {
             let hand_strat = HandStrategy {
                 hand: hand_name.clone(),
                 fold,
+                check,
                 call,
                 raise,
                 allin,
@@ -75,6 +87,23 @@ impl ScenarioRange {
             display_name: scenario.display_name(),
             hands,
             grid,
+            actions: actions.to_vec(),
+        }
+    }
+
+    /// Whether the scenario offers `action`.
+    ///
+    /// Ranges saved before the action set was recorded fall back to the
+    /// optional check and all-in frequencies, counting fold, call and raise
+    /// as always offered.
+    pub fn offers(&self, action: ActionType) -> bool {
+        if !self.actions.is_empty() {
+            return self.actions.contains(&action);
+        }
+        match action {
+            ActionType::Check => self.hands.values().any(|h| h.check.is_some()),
+            ActionType::AllIn => self.hands.values().any(|h| h.allin.is_some()),
+            ActionType::Fold | ActionType::Call | ActionType::Raise => true,
         }
     }
 
@@ -84,9 +113,9 @@ impl ScenarioRange {
         total / 169.0
     }
 
-    /// Get total call frequency
+    /// Get total passive (call or check) frequency
     pub fn total_call_freq(&self) -> f64 {
-        let total: f64 = self.hands.values().map(|h| h.call).sum();
+        let total: f64 = self.hands.values().map(|h| h.call + h.check.unwrap_or(0.0)).sum();
         total / 169.0
     }
 
@@ -222,32 +251,36 @@ pub fn generate_html(output: &RangeOutput) -> String {
 
     for scenario in &output.scenarios {
         html.push_str(&format!(r#"
-    <div class="scenario">
+    <div class="scenario" data-scenario="{}">
         <h2>{}</h2>
         <div class="legend">
             <div class="legend-item"><div class="legend-color" style="background: #2ecc71;"></div>Raise</div>
-            <div class="legend-item"><div class="legend-color" style="background: #3498db;"></div>Call</div>
+            <div class="legend-item"><div class="legend-color" style="background: #3498db;"></div>Call / Check</div>
             <div class="legend-item"><div class="legend-color" style="background: #444;"></div>Fold</div>
         </div>
         <div class="grid">
-"#, scenario.display_name));
+"#, scenario.scenario, scenario.display_name));
 
         for row in 0..13 {
             for col in 0..13 {
                 let h = &scenario.grid[row][col];
-                let raise_pct = (h.raise * 100.0).round() as u32;
-                let call_pct = (h.call * 100.0).round() as u32;
+                // All-in counts as aggressive so Vs4Bet charts aren't drawn as folds
+                let aggressive = h.raise + h.allin.unwrap_or(0.0);
+                let raise_pct = (aggressive * 100.0).round() as u32;
+                // Checking the BB option is drawn like a call
+                let passive = h.call + h.check.unwrap_or(0.0);
+                let call_pct = (passive * 100.0).round() as u32;
 
                 let (class, bg) = if raise_pct >= 80 {
-                    ("raise", format!("background: rgba(46, 204, 113, {});", h.raise))
+                    ("raise", format!("background: rgba(46, 204, 113, {});", aggressive))
                 } else if call_pct >= 80 {
-                    ("call", format!("background: rgba(52, 152, 219, {});", h.call))
+                    ("call", format!("background: rgba(52, 152, 219, {});", passive))
                 } else if raise_pct + call_pct < 20 {
                     ("fold", String::new())
                 } else {
                     // Mixed
-                    let r = (h.raise * 255.0) as u8;
-                    let g = (h.call * 255.0) as u8;
+                    let r = (aggressive * 255.0) as u8;
+                    let g = (passive * 255.0) as u8;
                     ("mixed", format!("background: rgb({}, {}, 100);", 46 + r/2, 100 + g/2))
                 };
 
@@ -277,3 +310,58 @@ pub fn generate_html(output: &RangeOutput) -> String {
     html.push_str("</div>\n</body>\n</html>");
     html
 }
+
+/// Generate a CSV of a scenario's frequencies, one row per hand.
+///
+/// Columns are `hand,fold,check,call,raise,allin` and rows follow the
+/// 13x13 grid row by row (AA, AKs, ..., 22). Actions the scenario doesn't
+/// offer are left empty, so they read as "not available" rather than 0%.
+pub fn generate_csv(range: &ScenarioRange) -> String {
+    let mut csv = String::from("hand,fold,check,call,raise,allin\n");
+    let cell = |offered: bool, freq: Option<f64>| match (offered, freq) {
+        (true, Some(freq)) => format!("{:.6}", freq),
+        _ => String::new(),
+    };
+
+    for row in 0..13 {
+        for col in 0..13 {
+            let h = &range.grid[row][col];
+            debug_assert_eq!(h.hand, grid_to_hand_name(row, col));
+            csv.push_str(&format!(
+                "{},{},{},{},{},{}\n",
+                grid_to_hand_name(row, col),
+                cell(range.offers(ActionType::Fold), Some(h.fold)),
+                cell(range.offers(ActionType::Check), h.check),
+                cell(range.offers(ActionType::Call), Some(h.call)),
+                cell(range.offers(ActionType::Raise), Some(h.raise)),
+                cell(range.offers(ActionType::AllIn), h.allin),
+            ));
+        }
+    }
+    csv
+}
+
+/// Solve each scenario and write the resulting range charts as HTML.
+///
+/// This is the one-shot "give me charts" workflow: every scenario is solved
+/// with [`solve_scenario`], converted to a [`ScenarioRange`], rendered with
+/// [`generate_html`] and written to `out_path`. The assembled output is
+/// returned so callers can also save it as JSON.
+pub fn solve_and_write_html<P: AsRef<Path>>(
+    scenarios: &[Scenario],
+    config: &PreflopRangeConfig,
+    iterations: u64,
+    out_path: P,
+) -> std::io::Result<RangeOutput> {
+    let mut output = RangeOutput::new(&format!("{}bb", config.stack_bb), config.stack_bb, iterations);
+
+    for scenario in scenarios {
+        let actions = PreflopRangeGame::new(scenario.clone(), config.clone()).actions();
+        let strategies = solve_scenario(scenario.clone(), config, iterations);
+        output.add_scenario(ScenarioRange::new(scenario, &strategies, &actions));
+    }
+
+    let mut file = File::create(out_path)?;
+    file.write_all(generate_html(&output).as_bytes())?;
+    Ok(output)
+}
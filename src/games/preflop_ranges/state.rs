@@ -1,6 +1,9 @@
 //! Preflop range game state.
 
 use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
 
 /// Position in poker (8-max)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -27,6 +30,14 @@ impl Position {
           Position::CO, Position::BU, Position::SB, Position::BB]
     }
 
+    /// Look up a position by its short name (case-insensitive).
+    pub fn from_name(name: &str) -> Option<Position> {
+        Position::all()
+            .iter()
+            .copied()
+            .find(|p| p.name().eq_ignore_ascii_case(name))
+    }
+
     pub fn index(&self) -> usize {
         match self {
             Position::UTG => 0,
@@ -64,6 +75,11 @@ pub enum Scenario {
     FourBet { hero: Position, villain: Position },
     /// Facing 5bet (usually just call or fold)
     Vs5Bet { hero: Position, villain: Position },
+    /// BB option after a limp - check for free or raise, never fold
+    BBvsLimp {
+        /// Player who limped in (SB completing, or an earlier position)
+        limper: Position,
+    },
 }
 
 impl Scenario {
@@ -76,6 +92,7 @@ impl Scenario {
             Scenario::FourBet { hero, villain } => format!("{}_4bet_vs_{}", hero.name(), villain.name()),
             Scenario::Vs4Bet { hero, villain } => format!("{}_vs_{}_4bet", hero.name(), villain.name()),
             Scenario::Vs5Bet { hero, villain } => format!("{}_vs_{}_5bet", hero.name(), villain.name()),
+            Scenario::BBvsLimp { limper } => format!("BB_vs_{}_limp", limper.name()),
         }
     }
 
@@ -88,14 +105,92 @@ impl Scenario {
             Scenario::FourBet { hero, villain } => format!("{} 4-Bet vs {}", hero.name(), villain.name()),
             Scenario::Vs4Bet { hero, villain } => format!("{} vs {} 4-Bet", hero.name(), villain.name()),
             Scenario::Vs5Bet { hero, villain } => format!("{} vs {} 5-Bet", hero.name(), villain.name()),
+            Scenario::BBvsLimp { limper } => format!("BB vs {} Limp", limper.name()),
+        }
+    }
+
+    /// Legal hero actions in this scenario, in strategy order.
+    pub fn actions(&self) -> &'static [ActionType] {
+        match self {
+            Scenario::RFI { .. } => &[ActionType::Fold, ActionType::Raise],
+            Scenario::VsRFI { .. } => &[ActionType::Fold, ActionType::Call, ActionType::Raise], // 3bet
+            Scenario::Vs3Bet { .. } => &[ActionType::Fold, ActionType::Call, ActionType::Raise], // 4bet
+            Scenario::Vs4Bet { .. } => &[ActionType::Fold, ActionType::Call, ActionType::AllIn],
+            Scenario::Vs5Bet { .. } => &[ActionType::Fold, ActionType::Call],
+            // Checking is free, so folding is never offered
+            Scenario::BBvsLimp { .. } => &[ActionType::Check, ActionType::Raise], // Iso-raise
+            _ => &[ActionType::Fold, ActionType::Raise],
+        }
+    }
+}
+
+/// Error returned when a scenario string can't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScenarioParseError {
+    /// A position name that isn't one of UTG, EP, MP, HJ, CO, BU, SB, BB.
+    UnknownPosition(String),
+    /// The string doesn't match any scenario notation.
+    InvalidFormat(String),
+}
+
+impl fmt::Display for ScenarioParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScenarioParseError::UnknownPosition(name) => write!(f, "Unknown position '{}'", name),
+            ScenarioParseError::InvalidFormat(s) => write!(f, "Invalid scenario notation '{}'", s),
+        }
+    }
+}
+
+impl std::error::Error for ScenarioParseError {}
+
+impl FromStr for Scenario {
+    type Err = ScenarioParseError;
+
+    /// Parse the notation produced by [`Scenario::name`] (e.g. `CO_RFI`,
+    /// `BB_vs_BU_RFI`, `BU_vs_BB_3bet`, `BB_3bet_vs_BU`). The bare
+    /// `BB_vs_BU` is accepted as shorthand for facing an open.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let position = |name: &str| {
+            Position::from_name(name).ok_or_else(|| ScenarioParseError::UnknownPosition(name.to_ascii_uppercase()))
+        };
+        let parts: Vec<String> = s.split('_').map(|p| p.to_ascii_lowercase()).collect();
+        let parts: Vec<&str> = parts.iter().map(String::as_str).collect();
+
+        match parts.as_slice() {
+            [pos, "rfi"] => Ok(Scenario::RFI { position: position(pos)? }),
+            ["bb", "vs", limper, "limp"] => Ok(Scenario::BBvsLimp { limper: position(limper)? }),
+            [hero, "vs", villain] | [hero, "vs", villain, "rfi"] => {
+                Ok(Scenario::VsRFI { hero: position(hero)?, villain: position(villain)? })
+            }
+            [hero, "vs", villain, level] => {
+                let (hero, villain) = (position(hero)?, position(villain)?);
+                match *level {
+                    "3bet" => Ok(Scenario::Vs3Bet { hero, villain }),
+                    "4bet" => Ok(Scenario::Vs4Bet { hero, villain }),
+                    "5bet" => Ok(Scenario::Vs5Bet { hero, villain }),
+                    _ => Err(ScenarioParseError::InvalidFormat(s.to_string())),
+                }
+            }
+            [hero, level, "vs", villain] => {
+                let (hero, villain) = (position(hero)?, position(villain)?);
+                match *level {
+                    "3bet" => Ok(Scenario::ThreeBet { hero, villain }),
+                    "4bet" => Ok(Scenario::FourBet { hero, villain }),
+                    _ => Err(ScenarioParseError::InvalidFormat(s.to_string())),
+                }
+            }
+            _ => Err(ScenarioParseError::InvalidFormat(s.to_string())),
         }
     }
 }
 
 /// Action type for preflop
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ActionType {
     Fold,
+    /// BB option when the action is limped to it
+    Check,
     Call,   // or Limp for RFI from SB
     Raise,  // Open, 3bet, 4bet, 5bet depending on context
     AllIn,
@@ -105,6 +200,7 @@ impl ActionType {
     pub fn name(&self) -> &'static str {
         match self {
             ActionType::Fold => "Fold",
+            ActionType::Check => "Check",
             ActionType::Call => "Call",
             ActionType::Raise => "Raise",
             ActionType::AllIn => "All-In",
@@ -117,8 +213,10 @@ impl ActionType {
 pub struct PreflopRangeState {
     /// Current scenario being solved
     pub scenario: Scenario,
-    /// Hand class (0-168)
+    /// Hand class (0-168); only meaningful once `dealt`
     pub hand_class: u8,
+    /// Whether the chance node has dealt the hand class
+    pub dealt: bool,
     /// Whether we've made a decision
     pub decided: bool,
     /// The action taken (if decided)
@@ -130,12 +228,32 @@ impl PreflopRangeState {
         Self {
             scenario,
             hand_class,
+            dealt: true,
             decided: false,
             action: None,
         }
     }
 
+    /// Root state before the chance node deals a hand class.
+    pub fn undealt(scenario: Scenario) -> Self {
+        Self {
+            dealt: false,
+            ..Self::new(scenario, 0)
+        }
+    }
+
+    /// Decide the hand with `action`.
+    ///
+    /// # Panics
+    /// If `action` is not one of the scenario's legal actions; the EV model
+    /// has no meaningful value for it.
     pub fn with_action(mut self, action: ActionType) -> Self {
+        assert!(
+            self.scenario.actions().contains(&action),
+            "{} is not a legal action in {}",
+            action.name(),
+            self.scenario.name()
+        );
         self.decided = true;
         self.action = Some(action);
         self
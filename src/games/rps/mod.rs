@@ -0,0 +1,267 @@
+//! Rock-Paper-Scissors as a matrix game for sanity-checking regret matching.
+//!
+//! The smallest game the solver can handle: one decision per player and no
+//! chance. Both players pick simultaneously, which is modelled by letting
+//! Player 1 move first and giving Player 2 an info set that does not reveal
+//! that move, so each player has exactly one info set.
+//!
+//! ## Payoffs
+//!
+//! The game is zero-sum and driven by a 3x3 matrix holding Player 1's payoff
+//! for each pair of moves (rows are Player 1's move, columns Player 2's, both
+//! in Rock, Paper, Scissors order). The standard matrix pays 1 for a win.
+//!
+//! ## Known Nash Equilibrium
+//!
+//! - **Standard**: both players play each move with probability 1/3
+//! - **Biased** ([`RockPaperScissors::biased`], a win with Rock pays `w`):
+//!   both players play Rock and Scissors with probability 1/(2+w) and Paper
+//!   with w/(2+w), e.g. 1/4, 1/2, 1/4 when Rock pays double
+//!
+//! **Expected Value**: 0 for both players, since the matrix is antisymmetric
+
+use std::fmt;
+
+use crate::cfr::game::{Action, Game, GameState, InfoState};
+
+/// Player 1's payoff for every (Player 1 move, Player 2 move) pair.
+pub type PayoffMatrix = [[f64; 3]; 3];
+
+/// Moves in Rock-Paper-Scissors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RpsAction {
+    /// Rock, beats Scissors
+    Rock,
+    /// Paper, beats Rock
+    Paper,
+    /// Scissors, beats Paper
+    Scissors,
+}
+
+impl RpsAction {
+    /// All moves, in payoff-matrix order.
+    pub const ALL: [RpsAction; 3] = [RpsAction::Rock, RpsAction::Paper, RpsAction::Scissors];
+
+    /// Row/column of this move in a [`PayoffMatrix`].
+    pub fn index(self) -> usize {
+        match self {
+            RpsAction::Rock => 0,
+            RpsAction::Paper => 1,
+            RpsAction::Scissors => 2,
+        }
+    }
+}
+
+impl Action for RpsAction {
+    fn to_string(&self) -> String {
+        match self {
+            RpsAction::Rock => "r".to_string(),
+            RpsAction::Paper => "p".to_string(),
+            RpsAction::Scissors => "s".to_string(),
+        }
+    }
+}
+
+impl fmt::Display for RpsAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RpsAction::Rock => write!(f, "Rock"),
+            RpsAction::Paper => write!(f, "Paper"),
+            RpsAction::Scissors => write!(f, "Scissors"),
+        }
+    }
+}
+
+/// Information state in Rock-Paper-Scissors.
+///
+/// Neither player sees the other's move, so all a player knows is who they are.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RpsInfoState {
+    /// The acting player (0 or 1)
+    pub player: usize,
+}
+
+impl InfoState for RpsInfoState {
+    fn key(&self) -> String {
+        self.player.to_string()
+    }
+}
+
+/// Complete game state in Rock-Paper-Scissors.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RpsState {
+    /// Moves made so far, Player 1's first
+    pub moves: Vec<RpsAction>,
+}
+
+impl GameState for RpsState {}
+
+/// Rock-Paper-Scissors with a configurable payoff matrix.
+#[derive(Debug, Clone)]
+pub struct RockPaperScissors {
+    payoffs: PayoffMatrix,
+}
+
+impl Default for RockPaperScissors {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RockPaperScissors {
+    /// Standard Rock-Paper-Scissors: a win pays 1, a tie 0.
+    pub fn new() -> Self {
+        Self::biased(1.0)
+    }
+
+    /// Rock-Paper-Scissors where a win with Rock pays `rock_win` and every
+    /// other win pays 1.
+    pub fn biased(rock_win: f64) -> Self {
+        Self::with_payoffs([
+            [0.0, -1.0, rock_win],
+            [1.0, 0.0, -1.0],
+            [-rock_win, 1.0, 0.0],
+        ])
+    }
+
+    /// Game with an arbitrary matrix of Player 1 payoffs; Player 2 receives
+    /// the negation.
+    pub fn with_payoffs(payoffs: PayoffMatrix) -> Self {
+        Self { payoffs }
+    }
+
+    /// The payoff matrix.
+    pub fn payoffs(&self) -> &PayoffMatrix {
+        &self.payoffs
+    }
+}
+
+impl Game for RockPaperScissors {
+    type State = RpsState;
+    type Action = RpsAction;
+    type InfoState = RpsInfoState;
+
+    fn initial_state(&self) -> Self::State {
+        RpsState::default()
+    }
+
+    fn is_terminal(&self, state: &Self::State) -> bool {
+        state.moves.len() == 2
+    }
+
+    fn get_payoff(&self, state: &Self::State, player: usize) -> f64 {
+        debug_assert!(self.is_terminal(state), "get_payoff called on non-terminal state");
+
+        let p0_payoff = self.payoffs[state.moves[0].index()][state.moves[1].index()];
+        if player == 0 {
+            p0_payoff
+        } else {
+            -p0_payoff
+        }
+    }
+
+    fn current_player(&self, state: &Self::State) -> Option<usize> {
+        if self.is_terminal(state) {
+            None
+        } else {
+            Some(state.moves.len())
+        }
+    }
+
+    fn num_players(&self) -> usize {
+        2
+    }
+
+    fn available_actions(&self, state: &Self::State) -> Vec<Self::Action> {
+        if self.is_terminal(state) {
+            return vec![];
+        }
+        RpsAction::ALL.to_vec()
+    }
+
+    fn apply_action(&self, state: &Self::State, action: &Self::Action) -> Self::State {
+        let mut new_state = state.clone();
+        new_state.moves.push(*action);
+        new_state
+    }
+
+    fn info_state(&self, state: &Self::State) -> Self::InfoState {
+        // Player 2's info set deliberately ignores Player 1's move
+        RpsInfoState {
+            player: self.current_player(state).unwrap_or(0),
+        }
+    }
+
+    fn max_depth(&self) -> Option<usize> {
+        Some(2)
+    }
+
+    fn estimated_info_sets(&self) -> Option<usize> {
+        Some(2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cfr::{assert_game_invariants, CFRConfig, CFRSolver};
+
+    /// Average strategies of both players after `iterations` full-tree iterations.
+    fn solve(game: RockPaperScissors, iterations: u64) -> (CFRSolver<RockPaperScissors>, [Vec<f64>; 2]) {
+        let mut solver = CFRSolver::new(game, CFRConfig::default());
+        for _ in 0..iterations {
+            solver.run_iteration_vanilla();
+        }
+        let strategies = [solver.get_average_strategy("0", 3), solver.get_average_strategy("1", 3)];
+        (solver, strategies)
+    }
+
+    fn assert_close(actual: &[f64], expected: &[f64], tolerance: f64) {
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < tolerance, "strategy {:?}, expected {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_payoffs() {
+        let game = RockPaperScissors::biased(2.0);
+        assert_game_invariants(&game, 100);
+
+        let play = |a: RpsAction, b: RpsAction| {
+            let state = game.apply_action(&game.apply_action(&game.initial_state(), &a), &b);
+            assert!(game.is_terminal(&state));
+            (game.get_payoff(&state, 0), game.get_payoff(&state, 1))
+        };
+        assert_eq!(play(RpsAction::Rock, RpsAction::Scissors), (2.0, -2.0));
+        assert_eq!(play(RpsAction::Scissors, RpsAction::Paper), (1.0, -1.0));
+        assert_eq!(play(RpsAction::Paper, RpsAction::Paper), (0.0, 0.0));
+
+        // Player 2 cannot see Player 1's move
+        let root = game.initial_state();
+        let after_rock = game.apply_action(&root, &RpsAction::Rock);
+        let after_paper = game.apply_action(&root, &RpsAction::Paper);
+        assert_eq!(game.info_state(&after_rock), game.info_state(&after_paper));
+        assert_ne!(game.info_state(&root), game.info_state(&after_rock));
+    }
+
+    #[test]
+    fn test_converges_to_uniform() {
+        let (solver, strategies) = solve(RockPaperScissors::new(), 2_000);
+        for strategy in &strategies {
+            assert_close(strategy, &[1.0 / 3.0; 3], 0.01);
+        }
+        assert!(solver.calculate_exploitability_exact() < 20.0);
+    }
+
+    #[test]
+    fn test_biased_equilibrium_shifts() {
+        // Rock wins pay w: indifference gives Rock = Scissors = 1/(2+w), Paper = w/(2+w)
+        let w = 2.0;
+        let expected = [1.0 / (2.0 + w), w / (2.0 + w), 1.0 / (2.0 + w)];
+        let (solver, strategies) = solve(RockPaperScissors::biased(w), 2_000);
+        for strategy in &strategies {
+            assert_close(strategy, &expected, 0.01);
+        }
+        assert!(solver.calculate_exploitability_exact() < 20.0);
+    }
+}
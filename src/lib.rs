@@ -30,7 +30,7 @@
 //! ## Modules
 //!
 //! - [`cfr`]: Core CFR algorithm and solver
-//! - [`games`]: Example game implementations (Kuhn Poker, etc.)
+//! - [`games`]: Example game implementations (Kuhn Poker, Leduc Hold'em, etc.)
 //!
 //! ## Architecture
 //!